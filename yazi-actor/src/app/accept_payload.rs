@@ -1,10 +1,12 @@
+use std::borrow::Cow;
+
 use anyhow::Result;
 use mlua::IntoLua;
 use tracing::error;
 use yazi_actor::lives::Lives;
 use yazi_binding::runtime_scope;
-use yazi_dds::{LOCAL, Payload, REMOTE};
-use yazi_macro::succ;
+use yazi_dds::{ID, LOCAL, Payload, REMOTE, ember::Ember};
+use yazi_macro::{render, succ};
 use yazi_plugin::LUA;
 use yazi_shared::data::Data;
 
@@ -18,6 +20,13 @@ impl Actor for AcceptPayload {
 	const NAME: &str = "accept_payload";
 
 	fn act(cx: &mut Ctx, payload: Payload) -> Result<Data> {
+		if let Ember::Mark(b) = &payload.body
+			&& payload.sender != *ID
+			&& cx.mgr.marks.apply(b.key, b.url.clone().map(Cow::into_owned))
+		{
+			render!();
+		}
+
 		let kind = payload.body.kind();
 		let lock = if payload.receiver == 0 || payload.receiver != payload.sender {
 			REMOTE.read()
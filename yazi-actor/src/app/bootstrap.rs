@@ -1,9 +1,11 @@
 use anyhow::Result;
 use yazi_actor::Ctx;
-use yazi_boot::BOOT;
-use yazi_core::mgr::CdSource;
-use yazi_macro::{act, succ};
+use yazi_boot::{ARGS, BOOT};
+use yazi_config::popup::ConfirmCfg;
+use yazi_core::mgr::{CdSource, Session};
+use yazi_macro::{act, confirm, succ};
 use yazi_parser::VoidForm;
+use yazi_scheduler::Pending;
 use yazi_shared::{data::Data, strand::StrandLike, url::UrlLike};
 
 use crate::Actor;
@@ -16,17 +18,66 @@ impl Actor for Bootstrap {
 	const NAME: &str = "bootstrap";
 
 	fn act(cx: &mut Ctx, _: Self::Form) -> Result<Data> {
-		cx.mgr.tabs.resize_with(BOOT.files.len(), Default::default);
+		if !Self::restore(cx)? {
+			cx.mgr.tabs.resize_with(BOOT.files.len(), Default::default);
 
-		for (i, file) in BOOT.files.iter().enumerate().rev() {
+			for (i, file) in BOOT.files.iter().enumerate().rev() {
+				cx.tab = i;
+				if file.is_empty() {
+					act!(mgr:cd, cx, (BOOT.cwds[i].clone(), CdSource::Tab))?;
+				} else if let Ok(u) = BOOT.cwds[i].try_join(file) {
+					act!(mgr:reveal, cx, (u, CdSource::Tab))?;
+				}
+			}
+		}
+
+		let backlog = Pending::claim();
+		if !backlog.tasks.is_empty() {
+			let titles = backlog.tasks.iter().take(11).map(Pending::title).collect();
+			let confirm = confirm!(cx, ConfirmCfg::unfinished(backlog.tasks.len(), titles))?;
+
+			let scheduler = cx.tasks.scheduler.clone();
+			tokio::spawn(async move {
+				if confirm.future().await {
+					scheduler.resubmit(backlog);
+				}
+			});
+		}
+
+		succ!();
+	}
+}
+
+impl Bootstrap {
+	/// Restores the tabs of the session, unless any entry is given explicitly.
+	fn restore(cx: &mut Ctx) -> Result<bool> {
+		let Some(session) =
+			BOOT.session.as_deref().filter(|_| ARGS.entries.is_empty()).and_then(Session::load)
+		else {
+			return Ok(false);
+		};
+
+		let len = session.tabs.len();
+		cx.mgr.tabs.resize_with(len, Default::default);
+
+		for (i, saved) in session.tabs.into_iter().enumerate().rev() {
 			cx.tab = i;
-			if file.is_empty() {
-				act!(mgr:cd, cx, (BOOT.cwds[i].clone(), CdSource::Tab))?;
-			} else if let Ok(u) = BOOT.cwds[i].try_join(file) {
+
+			let tab = cx.tab_mut();
+			tab.pref = saved.pref;
+			tab.backstack = saved.backstack;
+			tab.selected.add_many(&saved.selected);
+
+			if let Some(u) = saved.hovered {
 				act!(mgr:reveal, cx, (u, CdSource::Tab))?;
+			} else {
+				act!(mgr:cd, cx, (saved.cwd, CdSource::Tab))?;
 			}
 		}
 
-		succ!();
+		let idx = session.cursor.min(len - 1);
+		cx.mgr.tabs.set_idx(idx);
+		cx.tab = idx;
+		Ok(true)
 	}
 }
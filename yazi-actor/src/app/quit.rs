@@ -1,5 +1,6 @@
 use anyhow::Result;
-use yazi_boot::ARGS;
+use yazi_boot::{ARGS, BOOT};
+use yazi_core::mgr::Session;
 use yazi_fs::provider::{Provider, local::Local};
 use yazi_parser::app::QuitForm;
 use yazi_shared::{data::Data, strand::{StrandBuf, StrandLike, ToStrand}};
@@ -15,6 +16,10 @@ impl Actor for Quit {
 	const NAME: &str = "quit";
 
 	fn act(cx: &mut Ctx, Self::Form { opt }: Self::Form) -> Result<Data> {
+		if let Some(name) = &BOOT.session {
+			Session::from(&cx.mgr.tabs).save(name);
+		}
+
 		cx.tasks.shutdown();
 		cx.mgr.shutdown();
 
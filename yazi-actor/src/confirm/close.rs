@@ -13,8 +13,22 @@ impl Actor for Close {
 	const NAME: &str = "close";
 
 	fn act(cx: &mut Ctx, form: Self::Form) -> Result<Data> {
-		cx.confirm.token.complete(form.submit);
-		cx.confirm.visible = false;
+		let confirm = &mut cx.confirm;
+		let choice = match form.choice {
+			Some(s) => match confirm.choices.iter().find(|&&(name, _)| name == s) {
+				Some(&(name, _)) => Some(name),
+				None => succ!(),
+			},
+			None if form.submit => confirm.choices.first().map(|&(name, _)| name),
+			None => None,
+		};
+
+		if let (Some(tx), Some(name)) = (confirm.replier.take(), choice) {
+			tx.send((name, form.all)).ok();
+		}
+
+		confirm.token.complete(form.submit || choice.is_some());
+		confirm.visible = false;
 		succ!(render!());
 	}
 }
@@ -19,6 +19,8 @@ impl Actor for Show {
 		confirm.title = form.cfg.title;
 		confirm.body = form.cfg.body;
 		confirm.list = form.cfg.list;
+		confirm.choices = form.cfg.choices;
+		confirm.replier = form.replier;
 
 		confirm.position = form.cfg.position;
 		confirm.offset = 0;
@@ -99,6 +99,12 @@ impl UserData for File {
 				core.mgr.mimetype.get(&me.url).map(|s| lua.create_string(s)).transpose()
 			})?
 		});
+		methods.add_method("git", |_, me, ()| {
+			Ok(me.folder.files.git.get(&me.urn()).map(|&s| <&str>::from(s)))
+		});
+		methods.add_method("compared", |_, me, ()| {
+			Ok(me.tab.compare.as_ref().and_then(|c| c.get(&me.folder, me.urn())).map(<&str>::from))
+		});
 		methods.add_method("prefix", |lua, me, ()| {
 			if !me.url.has_trail() {
 				return Ok(None);
@@ -137,6 +143,23 @@ impl UserData for File {
 				_ => 0u8,
 			})
 		});
+		methods.add_method("trashed", |lua, me, ()| {
+			use yazi_fs::provider::local::Trash;
+			if !me.url.is_search() {
+				return Ok(None);
+			}
+
+			let Some(trashed) = me.url.as_local().and_then(Trash::lookup) else {
+				return Ok(None);
+			};
+
+			let deleted = trashed.deleted.and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok());
+			Some(lua.create_table_from([
+				("origin", yazi_binding::Url::new(trashed.origin).into_lua(lua)?),
+				("deleted", deleted.map(|d| d.as_secs_f64()).into_lua(lua)?),
+			]))
+			.transpose()
+		});
 		methods.add_method("is_selected", |_, me, ()| Ok(me.tab.selected.contains(&me.url)));
 		methods.add_method("found", |lua, me, ()| {
 			lua.named_registry_value::<AnyUserData>("cx")?.borrow_scoped(|core: &yazi_core::Core| {
@@ -42,7 +42,7 @@ impl UserData for Preference {
 		fields.add_field_method_get("show_hidden", |_, me| Ok(me.show_hidden));
 
 		// Sorting
-		cached_field!(fields, sort_by, |_, me| Ok(me.sort_by.into_str()));
+		cached_field!(fields, sort_by, |_, me| Ok(me.sort_by.to_string()));
 		fields.add_field_method_get("sort_sensitive", |_, me| Ok(me.sort_sensitive));
 		fields.add_field_method_get("sort_reverse", |_, me| Ok(me.sort_reverse));
 		fields.add_field_method_get("sort_dir_first", |_, me| Ok(me.sort_dir_first));
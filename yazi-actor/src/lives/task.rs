@@ -30,6 +30,7 @@ impl UserData for TaskSnap {
 		cached_field!(fields, title, |lua, me| lua.create_string(&me.title));
 		cached_field!(fields, prog, |lua, me| lua.to_value_with(&me.prog, SER_OPT));
 
+		fields.add_field_method_get("paused", |_, me| Ok(me.paused));
 		fields.add_field_method_get("running", |_, me| Ok(me.prog.running()));
 		fields.add_field_method_get("cooked", |_, me| Ok(me.prog.cooked()));
 		fields.add_field_method_get("success", |_, me| Ok(me.prog.success()));
@@ -11,6 +11,7 @@ pub(super) struct Tasks {
 
 	v_snaps:   Option<Value>,
 	v_summary: Option<Value>,
+	v_journal: Option<Value>,
 }
 
 impl Deref for Tasks {
@@ -26,6 +27,7 @@ impl Tasks {
 
 			v_snaps:   None,
 			v_summary: None,
+			v_journal: None,
 		})
 	}
 }
@@ -43,5 +45,8 @@ impl UserData for Tasks {
 		});
 
 		cached_field!(fields, summary, |lua, me| lua.to_value_with(&me.summary, SER_OPT));
+		cached_field!(fields, journal, |lua, me| {
+			lua.to_value_with(&me.scheduler.journal.snaps(), SER_OPT)
+		});
 	}
 }
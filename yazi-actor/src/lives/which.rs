@@ -28,6 +28,7 @@ impl UserData for Which {
 	fn add_fields<F: UserDataFields<Self>>(fields: &mut F) {
 		cached_field!(fields, tx, |_, me| Ok(me.tx.clone().map(yazi_binding::MpscUnboundedTx)));
 		fields.add_field_method_get("times", |_, me| Ok(me.inner.times));
+		fields.add_field_method_get("count", |_, me| Ok(me.inner.count));
 		cached_field!(fields, cands, |lua, me| {
 			lua.create_sequence_from(me.inner.cands.iter().cloned().map(yazi_binding::ChordCow))
 		});
@@ -12,7 +12,7 @@ use yazi_fs::{File, FilesOp, Splatter, max_common_root, path::skip_url, provider
 use yazi_macro::{err, succ};
 use yazi_parser::VoidForm;
 use yazi_proxy::TasksProxy;
-use yazi_scheduler::{AppProxy, NotifyProxy};
+use yazi_scheduler::{AppProxy, NotifyProxy, journal::{Journal, Record}};
 use yazi_shared::{data::Data, path::PathDyn, strand::{AsStrand, AsStrandJoin, Strand, StrandBuf, StrandLike}, terminal_clear, url::{AsUrl, UrlBuf, UrlCow, UrlLike}};
 use yazi_term::YIELD_TO_SUBPROCESS;
 use yazi_tty::TTY;
@@ -44,6 +44,7 @@ impl Actor for BulkRename {
 
 		let cwd = cx.cwd().clone();
 		let batcher = cx.core.mgr.batcher.clone();
+		let journal = cx.tasks.scheduler.journal.clone();
 		tokio::spawn(async move {
 			let tmp = YAZI.preview.tmpfile("bulk");
 
@@ -86,7 +87,7 @@ impl Actor for BulkRename {
 				.collect();
 
 			let decision = batcher.drain(&tmp);
-			Self::r#do(root, old, new, selected, decision).await
+			Self::r#do(root, old, new, selected, decision, &journal).await
 		});
 		succ!();
 	}
@@ -99,6 +100,7 @@ impl BulkRename {
 		new: Vec<Tuple>,
 		selected: Vec<UrlBuf>,
 		decision: Option<bool>,
+		journal: &Journal,
 	) -> Result<()> {
 		terminal_clear(TTY.writer())?;
 		if old.len() != new.len() {
@@ -122,6 +124,7 @@ impl BulkRename {
 
 		let permit = WATCHER.acquire().await.unwrap();
 		let (mut failed, mut succeeded) = (Vec::new(), HashMap::with_capacity(todo.len()));
+		let mut records = Vec::with_capacity(todo.len());
 		for (o, n) in todo {
 			let (Ok(old), Ok(new)) =
 				(Self::replace_url(&selected[o.0], root, &o), Self::replace_url(&selected[n.0], root, &n))
@@ -134,7 +137,8 @@ impl BulkRename {
 				failed.push((o, n, anyhow!("Destination already exists")));
 			} else if let Err(e) = provider::rename(&old, &new).await {
 				failed.push((o, n, e.into()));
-			} else if let Ok(f) = File::new(new).await {
+			} else if let Ok(f) = File::new(&new).await {
+				records.push(Record::Rename { from: old.clone(), to: new });
 				succeeded.insert(old, f);
 			} else {
 				failed.push((o, n, anyhow!("Failed to retrieve file info")));
@@ -144,6 +148,7 @@ impl BulkRename {
 		if !succeeded.is_empty() {
 			let it = succeeded.iter().map(|(o, n)| (o.as_url(), n.url.as_url()));
 			err!(Pubsub::pub_after_bulk(it));
+			journal.push(records);
 			FilesOp::rename(succeeded);
 		}
 		drop(permit);
@@ -0,0 +1,47 @@
+use anyhow::{Result, bail};
+use yazi_core::{mgr::CompareOpt, tab};
+use yazi_macro::succ;
+use yazi_parser::mgr::CompareForm;
+use yazi_proxy::MgrProxy;
+use yazi_shared::data::Data;
+
+use crate::{Actor, Ctx};
+
+pub struct Compare;
+
+impl Actor for Compare {
+	type Form = CompareForm;
+
+	const NAME: &str = "compare";
+
+	fn act(cx: &mut Ctx, form: Self::Form) -> Result<Data> {
+		let tabs = cx.tabs();
+		let idx = form.tab.unwrap_or((tabs.cursor + 1) % tabs.len().max(1));
+
+		let Some(other) = tabs.get(idx).filter(|_| idx != tabs.cursor) else {
+			bail!("Compare requires another tab to compare with");
+		};
+
+		let me = tabs.active();
+		if me.current.stage.is_loading() || other.current.stage.is_loading() {
+			bail!("Cannot compare directories that are still loading");
+		}
+
+		// Only the visible entries are compared, so the hidden and filtered-out
+		// files stay out of the way like they do elsewhere
+		let (left, right) = (me.current.files.to_vec(), other.current.files.to_vec());
+		let (l, r) = ((me.id, me.cwd().clone()), (other.id, other.cwd().clone()));
+		let (content, select) = (form.content, form.select);
+
+		tokio::spawn(async move {
+			let (ls, rs) = tab::Compare::classify(left, right, content).await;
+			MgrProxy::compare_do(CompareOpt {
+				left: tab::Compare { peer: r.0, cwd: l.1, statuses: ls },
+				right: tab::Compare { peer: l.0, cwd: r.1, statuses: rs },
+				select,
+			});
+		});
+
+		succ!();
+	}
+}
@@ -0,0 +1,46 @@
+use anyhow::Result;
+use yazi_macro::{render, succ};
+use yazi_parser::mgr::CompareDoForm;
+use yazi_scheduler::NotifyProxy;
+use yazi_shared::{data::Data, url::UrlLike};
+
+use crate::{Actor, Ctx};
+
+pub struct CompareDo;
+
+impl Actor for CompareDo {
+	type Form = CompareDoForm;
+
+	const NAME: &str = "compare_do";
+
+	fn act(cx: &mut Ctx, Self::Form { opt }: Self::Form) -> Result<Data> {
+		let tabs = cx.tabs_mut();
+		let (l, r) = (opt.right.peer, opt.left.peer);
+
+		// Either tab may have been closed or moved elsewhere in the meantime
+		let Some(li) = tabs.iter().position(|t| t.id == l && *t.cwd() == opt.left.cwd) else { succ!() };
+		let Some(ri) = tabs.iter().position(|t| t.id == r && *t.cwd() == opt.right.cwd) else {
+			succ!()
+		};
+
+		let differences: Vec<_> =
+			opt.left.differences().filter_map(|urn| opt.left.cwd.try_join(urn).ok()).collect();
+
+		tabs[li].compare = Some(opt.left);
+		tabs[ri].compare = Some(opt.right);
+		render!();
+
+		if !opt.select {
+			succ!();
+		}
+
+		let tab = &mut tabs[li];
+		if differences.len() != tab.selected.add_many(&differences) {
+			NotifyProxy::push_warn(
+				"Compare",
+				"Some files cannot be selected, due to path nesting conflict.",
+			);
+		}
+		succ!();
+	}
+}
@@ -2,7 +2,8 @@ use anyhow::Result;
 use yazi_core::mgr::CdSource;
 use yazi_macro::{act, succ};
 use yazi_parser::VoidForm;
-use yazi_shared::{data::Data, url::UrlLike};
+use yazi_shared::{data::Data, url::{AsUrl, UrlLike}};
+use yazi_vfs::provider::archive::Archive;
 
 use crate::{Actor, Ctx};
 
@@ -14,9 +15,15 @@ impl Actor for Enter {
 	const NAME: &str = "enter";
 
 	fn act(cx: &mut Ctx, _: Self::Form) -> Result<Data> {
-		let Some(h) = cx.hovered().filter(|h| h.is_dir()) else { succ!() };
+		let Some(h) = cx.hovered() else { succ!() };
 
-		let url = if h.url.is_search() { h.url.to_regular()? } else { h.url.clone() };
+		let url = if h.is_dir() {
+			if h.url.is_search() { h.url.to_regular()? } else { h.url.clone() }
+		} else if let Some(portal) = Archive::portal(h.url.as_url()) {
+			portal
+		} else {
+			succ!();
+		};
 
 		act!(mgr:cd, cx, (url, CdSource::Enter))
 	}
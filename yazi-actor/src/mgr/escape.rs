@@ -19,7 +19,8 @@ impl Actor for Escape {
 				|| act!(mgr:escape_visual, cx)? != false
 				|| act!(mgr:escape_filter, cx)? != false
 				|| act!(mgr:escape_select, cx)? != false
-				|| act!(mgr:escape_search, cx)? != false;
+				|| act!(mgr:escape_search, cx)? != false
+				|| act!(mgr:escape_compare, cx)? != false;
 			succ!();
 		}
 
@@ -38,6 +39,9 @@ impl Actor for Escape {
 		if form.contains(EscapeForm::SEARCH) {
 			act!(mgr:escape_search, cx)?;
 		}
+		if form.contains(EscapeForm::COMPARE) {
+			act!(mgr:escape_compare, cx)?;
+		}
 		succ!();
 	}
 }
@@ -144,3 +148,24 @@ impl Actor for EscapeSearch {
 		succ!(render_and!(b));
 	}
 }
+
+// --- Compare
+pub struct EscapeCompare;
+
+impl Actor for EscapeCompare {
+	type Form = VoidForm;
+
+	const NAME: &str = "escape_compare";
+
+	fn act(cx: &mut Ctx, _: Self::Form) -> Result<Data> {
+		let Some(compare) = cx.tab_mut().compare.take() else { succ!(false) };
+
+		// Leave the compare mode on both sides
+		if let Some(peer) = cx.tabs_mut().iter_mut().find(|t| t.id == compare.peer) {
+			peer.compare = None;
+		}
+
+		render!();
+		succ!(true);
+	}
+}
@@ -31,7 +31,13 @@ impl Actor for Filter {
 				let done = event.is_submit();
 				let (InputEvent::Submit(s) | InputEvent::Type(s)) = event else { continue };
 
-				MgrProxy::filter_do(FilterOpt { query: s.into(), case: opt.case, done });
+				MgrProxy::filter_do(FilterOpt {
+					query: s.into(),
+					case: opt.case,
+					fuzzy: opt.fuzzy,
+					rank: opt.rank,
+					done,
+				});
 			}
 		});
 		succ!();
@@ -14,7 +14,13 @@ impl Actor for FilterDo {
 	const NAME: &str = "filter_do";
 
 	fn act(cx: &mut Ctx, Self::Form { opt }: Self::Form) -> Result<Data> {
-		let filter = if opt.query.is_empty() { None } else { Some(Filter::new(&opt.query, opt.case)?) };
+		let filter = if opt.query.is_empty() {
+			None
+		} else if opt.fuzzy {
+			Some(Filter::fuzzy(&opt.query, opt.case, opt.rank))
+		} else {
+			Some(Filter::new(&opt.query, opt.case)?)
+		};
 
 		let hovered = cx.hovered().map(|f| f.urn().into());
 		cx.current_mut().files.set_filter(filter);
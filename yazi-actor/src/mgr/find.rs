@@ -28,7 +28,12 @@ impl Actor for Find {
 			pin!(rx);
 
 			while let Some(InputEvent::Submit(s) | InputEvent::Type(s)) = rx.next().await {
-				MgrProxy::find_do(FindDoOpt { query: s.into(), prev: form.prev, case: form.case });
+				MgrProxy::find_do(FindDoOpt {
+					query: s.into(),
+					prev:  form.prev,
+					case:  form.case,
+					fuzzy: form.fuzzy,
+				});
 			}
 		});
 		succ!();
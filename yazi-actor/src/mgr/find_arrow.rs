@@ -17,14 +17,20 @@ impl Actor for FindArrow {
 		let Some(finder) = &mut tab.finder else { succ!() };
 
 		render!(finder.catchup(&tab.current));
-		let offset = if form.prev {
-			finder.prev(&tab.current.files, tab.current.cursor, false)
-		} else {
-			finder.next(&tab.current.files, tab.current.cursor, false)
-		};
-
-		if let Some(offset) = offset {
-			act!(mgr:arrow, cx, offset)?;
+		let (files, cursor) = (&tab.current.files, tab.current.cursor);
+
+		let mut pos = cursor;
+		for _ in 0..form.count.max(1) {
+			let offset =
+				if form.prev { finder.prev(files, pos, false) } else { finder.next(files, pos, false) };
+			match offset {
+				Some(off) => pos = pos.saturating_add_signed(off),
+				None => break,
+			}
+		}
+
+		if pos != cursor {
+			act!(mgr:arrow, cx, pos as isize - cursor as isize)?;
 		}
 		succ!();
 	}
@@ -18,7 +18,7 @@ impl Actor for FindDo {
 			return act!(mgr:escape_find, cx);
 		}
 
-		let finder = Finder::new(&opt.query, opt.case)?;
+		let finder = Finder::new(&opt.query, opt.case, opt.fuzzy)?;
 		if matches!(&cx.tab().finder, Some(f) if f.filter == finder.filter) {
 			succ!();
 		}
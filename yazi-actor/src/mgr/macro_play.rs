@@ -0,0 +1,27 @@
+use anyhow::{Result, bail};
+use yazi_macro::{emit, succ};
+use yazi_parser::mgr::MacroPlayForm;
+use yazi_shared::{data::Data, event::ActionCow};
+
+use crate::{Actor, Ctx};
+
+pub struct MacroPlay;
+
+impl Actor for MacroPlay {
+	type Form = MacroPlayForm;
+
+	const NAME: &str = "macro_play";
+
+	fn act(cx: &mut Ctx, form: Self::Form) -> Result<Data> {
+		let Some(actions) = cx.mgr.macros.get(form.reg) else {
+			bail!("Macro `{}` is not recorded", form.reg);
+		};
+
+		let count = form.count.unwrap_or(1).max(1);
+		let seq: Vec<ActionCow> =
+			(0..count).flat_map(|_| actions.iter().rev().cloned().map(Into::into)).collect();
+
+		emit!(Seq(seq));
+		succ!();
+	}
+}
@@ -0,0 +1,23 @@
+use anyhow::{Result, bail};
+use yazi_macro::succ;
+use yazi_parser::mgr::MacroRecordForm;
+use yazi_shared::data::Data;
+
+use crate::{Actor, Ctx};
+
+pub struct MacroRecord;
+
+impl Actor for MacroRecord {
+	type Form = MacroRecordForm;
+
+	const NAME: &str = "macro_record";
+
+	fn act(cx: &mut Ctx, form: Self::Form) -> Result<Data> {
+		if let Some(reg) = cx.mgr.macros.recording() {
+			bail!("Already recording into register `{reg}`");
+		}
+
+		cx.mgr.macros.record(form.reg);
+		succ!();
+	}
+}
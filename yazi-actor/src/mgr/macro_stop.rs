@@ -0,0 +1,22 @@
+use anyhow::{Result, bail};
+use yazi_macro::succ;
+use yazi_parser::VoidForm;
+use yazi_shared::data::Data;
+
+use crate::{Actor, Ctx};
+
+pub struct MacroStop;
+
+impl Actor for MacroStop {
+	type Form = VoidForm;
+
+	const NAME: &str = "macro_stop";
+
+	fn act(cx: &mut Ctx, _: Self::Form) -> Result<Data> {
+		if cx.mgr.macros.stop().is_none() {
+			bail!("No macro is being recorded");
+		}
+
+		succ!();
+	}
+}
@@ -0,0 +1,85 @@
+use std::time::Duration;
+
+use anyhow::{Result, bail};
+use futures::future::join_all;
+use tokio::time::timeout;
+use yazi_config::{YAZI, popup::{InputCfg, PickCfg}};
+use yazi_core::mgr::{CdSource, Marks};
+use yazi_macro::{act, input, render, succ};
+use yazi_parser::mgr::{MarkForm, MarkFormAction};
+use yazi_proxy::{MgrProxy, PickProxy};
+use yazi_shared::{data::Data, url::UrlLike};
+use yazi_widgets::input::InputEvent;
+
+use crate::{Actor, Ctx};
+
+pub struct Mark;
+
+impl Actor for Mark {
+	type Form = MarkForm;
+
+	const NAME: &str = "mark";
+
+	fn act(cx: &mut Ctx, form: Self::Form) -> Result<Data> {
+		let Some(key) = form.key else {
+			return Self::interactive(cx, form.action);
+		};
+
+		match form.action {
+			MarkFormAction::Set => {
+				let cwd = cx.cwd().clone();
+				if cwd.is_search() {
+					bail!("Cannot set a mark in search results");
+				}
+				cx.mgr.marks.set(key, cwd);
+			}
+			MarkFormAction::Jump => {
+				let Some(url) = cx.mgr.marks.get(&key).cloned() else {
+					bail!("Mark `{key}` is not set");
+				};
+				act!(mgr:cd, cx, (url, CdSource::Mark))?;
+			}
+			MarkFormAction::Delete => {
+				cx.mgr.marks.remove(key);
+			}
+		}
+		succ!(render!());
+	}
+}
+
+impl Mark {
+	fn interactive(cx: &mut Ctx, action: MarkFormAction) -> Result<Data> {
+		if action == MarkFormAction::Set {
+			let mut input = input!(cx, InputCfg::mark())?;
+			tokio::spawn(async move {
+				if let Some(InputEvent::Submit(s)) = input.recv().await
+					&& let mut it = s.chars()
+					&& let (Some(key), None) = (it.next(), it.next())
+				{
+					MgrProxy::mark(action.as_str(), key);
+				}
+			});
+			succ!();
+		}
+
+		let marks = (*cx.mgr.marks).clone();
+		if marks.is_empty() {
+			bail!("No marks have been set");
+		}
+
+		tokio::spawn(async move {
+			// Slow directories, e.g. on a remote that's gone, are left without a preview
+			let max = YAZI.pick.open_offset.height.saturating_sub(YAZI.pick.border()) as usize;
+			let previews = join_all(marks.values().map(async |url| {
+				timeout(Duration::from_millis(500), Marks::preview(url, max)).await.unwrap_or_default()
+			}))
+			.await;
+
+			let keys: Vec<_> = marks.keys().copied().collect();
+			if let Some(choice) = PickProxy::show(PickCfg::mark(&marks, previews)).await {
+				MgrProxy::mark(action.as_str(), keys[choice]);
+			}
+		});
+		succ!();
+	}
+}
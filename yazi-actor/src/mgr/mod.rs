@@ -5,6 +5,8 @@ yazi_macro::mod_flat!(
 	bulk_rename
 	cd
 	close
+	compare
+	compare_do
 	copy
 	create
 	displace
@@ -25,11 +27,16 @@ yazi_macro::mod_flat!(
 	leave
 	linemode
 	link
+	macro_play
+	macro_record
+	macro_stop
+	mark
 	open
 	open_do
 	paste
 	peek
 	quit
+	redo
 	refresh
 	remove
 	rename
@@ -41,6 +48,7 @@ yazi_macro::mod_flat!(
 	spot
 	stash
 	suspend
+	sync
 	tab_close
 	tab_create
 	tab_rename
@@ -48,6 +56,8 @@ yazi_macro::mod_flat!(
 	tab_switch
 	toggle
 	toggle_all
+	trash
+	undo
 	unyank
 	update_files
 	update_mimes
@@ -18,12 +18,12 @@ impl Actor for Paste {
 
 		let dest = tab.cwd();
 		if mgr.yanked.cut {
-			cx.core.tasks.file_cut(&mgr.yanked, dest, form.force);
+			cx.core.tasks.file_cut(&mgr.yanked, dest, form.conflict(), form.verify);
 
 			mgr.tabs.iter_mut().for_each(|t| _ = t.selected.remove_many(&*mgr.yanked));
 			act!(mgr:unyank, cx)
 		} else {
-			succ!(cx.core.tasks.file_copy(&mgr.yanked, dest, form.force, form.follow));
+			succ!(cx.core.tasks.file_copy(&mgr.yanked, dest, form.conflict(), form.follow, form.verify));
 		}
 	}
 }
@@ -0,0 +1,25 @@
+use anyhow::Result;
+use yazi_macro::succ;
+use yazi_parser::VoidForm;
+use yazi_scheduler::NotifyProxy;
+use yazi_shared::data::Data;
+
+use crate::{Actor, Ctx};
+
+pub struct Redo;
+
+impl Actor for Redo {
+	type Form = VoidForm;
+
+	const NAME: &str = "redo";
+
+	fn act(cx: &mut Ctx, _: Self::Form) -> Result<Data> {
+		let scheduler = &cx.tasks.scheduler;
+		scheduler.behavior.reset();
+
+		if !scheduler.redo() {
+			NotifyProxy::push_warn("Redo", "Nothing to redo");
+		}
+		succ!();
+	}
+}
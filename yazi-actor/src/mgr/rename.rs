@@ -5,6 +5,7 @@ use yazi_fs::{File, FilesOp};
 use yazi_macro::{act, err, input, ok_or_not_found, succ};
 use yazi_parser::mgr::RenameForm;
 use yazi_proxy::{ConfirmProxy, MgrProxy};
+use yazi_scheduler::journal::{Journal, Record};
 use yazi_shared::{Id, data::Data, url::{UrlBuf, UrlLike}};
 use yazi_vfs::{VfsFile, maybe_exists, provider};
 use yazi_watcher::WATCHER;
@@ -42,6 +43,7 @@ impl Actor for Rename {
 		};
 
 		let (tab, old) = (cx.tab().id, hovered.url_owned());
+		let journal = cx.tasks.scheduler.journal.clone();
 		let mut input = input!(cx, InputCfg::rename().with_value(name).with_cursor(cursor))?;
 
 		tokio::spawn(async move {
@@ -55,9 +57,9 @@ impl Actor for Rename {
 			};
 
 			if form.force || !maybe_exists(&new).await || provider::must_identical(&old, &new).await {
-				Self::r#do(tab, old, new).await.ok();
+				Self::r#do(tab, old, new, &journal).await.ok();
 			} else if ConfirmProxy::show(ConfirmCfg::overwrite(&new)).await {
-				Self::r#do(tab, old, new).await.ok();
+				Self::r#do(tab, old, new, &journal).await.ok();
 			}
 		});
 		succ!();
@@ -65,7 +67,7 @@ impl Actor for Rename {
 }
 
 impl Rename {
-	async fn r#do(tab: Id, old: UrlBuf, new: UrlBuf) -> Result<()> {
+	async fn r#do(tab: Id, old: UrlBuf, new: UrlBuf, journal: &Journal) -> Result<()> {
 		let Some((old_p, old_n)) = old.pair() else { return Ok(()) };
 		let Some(_) = new.pair() else { return Ok(()) };
 		let _permit = WATCHER.acquire().await.unwrap();
@@ -94,6 +96,7 @@ impl Rename {
 
 		MgrProxy::reveal(&new);
 		err!(Pubsub::pub_after_rename(tab, &old, &new));
+		journal.push(vec![Record::Rename { from: old, to: new }]);
 		Ok(())
 	}
 
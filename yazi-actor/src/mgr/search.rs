@@ -1,17 +1,17 @@
-use std::{borrow::Cow, time::Duration};
+use std::{borrow::Cow, io::{self, ErrorKind::NotFound}, time::Duration};
 
 use anyhow::Result;
-use tokio::pin;
+use tokio::{pin, sync::mpsc::UnboundedReceiver};
 use tokio_stream::{StreamExt, wrappers::UnboundedReceiverStream};
 use yazi_config::popup::InputCfg;
 use yazi_core::mgr::{CdSource, SearchVia};
-use yazi_fs::{FilesOp, cha::Cha};
+use yazi_fs::{File, FilesOp, FsUrl, cha::Cha};
 use yazi_macro::{act, input, succ};
 use yazi_parser::{VoidForm, mgr::SearchForm};
 use yazi_plugin::external;
 use yazi_proxy::MgrProxy;
 use yazi_scheduler::NotifyProxy;
-use yazi_shared::{data::Data, url::{AsUrl, UrlLike}};
+use yazi_shared::{data::Data, url::{AsUrl, UrlBuf, UrlLike}};
 use yazi_widgets::input::InputEvent;
 
 use crate::{Actor, Ctx};
@@ -55,31 +55,29 @@ impl Actor for SearchDo {
 		}
 
 		let hidden = tab.pref.show_hidden;
-		let r#in = opt.r#in.as_ref().map_or_else(|| tab.cwd().as_url(), |u| u.as_url());
-		let Ok(cwd) = r#in.to_search(&opt.subject) else {
-			succ!(NotifyProxy::push_warn("Search", "Only local filesystem searches are supported"));
+		let root = opt.r#in.clone().unwrap_or_else(|| tab.cwd().clone());
+		let Some(cwd) = Self::folder(&root, &opt.subject) else {
+			succ!(NotifyProxy::push_warn("Search", format!("Cannot search in {}", root.display())));
 		};
 
 		tab.search = Some(tokio::spawn(async move {
-			let rx = match opt.via {
-				SearchVia::Rg => external::rg(external::RgOpt {
-					cwd: cwd.clone(),
-					hidden,
-					subject: opt.subject.into_owned(),
-					args: opt.args,
-				}),
-				SearchVia::Rga => external::rga(external::RgaOpt {
-					cwd: cwd.clone(),
-					hidden,
-					subject: opt.subject.into_owned(),
-					args: opt.args,
-				}),
-				SearchVia::Fd => external::fd(external::FdOpt {
-					cwd: cwd.clone(),
-					hidden,
-					subject: opt.subject.into_owned(),
-					args: opt.args,
-				}),
+			let subject = opt.subject.into_owned();
+			let rx = if root.kind().is_local() {
+				match Self::spawn(opt.via, &cwd, &root, hidden, subject.clone(), opt.args) {
+					Err(e) if e.downcast_ref::<io::Error>().is_some_and(|e| e.kind() == NotFound) => {
+						let via: &str = opt.via.into();
+						NotifyProxy::push_warn(
+							"Search",
+							format!("`{via}` not found, falling back to the built-in search without its arguments"),
+						);
+						Self::spawn(SearchVia::Builtin, &cwd, &root, hidden, subject, Self::args(opt.via, vec![]))
+					}
+					r => r,
+				}
+			} else {
+				// External programs can't reach into non-local directories
+				let args = Self::args(opt.via, opt.args);
+				Self::spawn(SearchVia::Builtin, &cwd, &root, hidden, subject, args)
 			}?;
 
 			let rx = UnboundedReceiverStream::new(rx).chunks_timeout(5000, Duration::from_millis(500));
@@ -98,6 +96,46 @@ impl Actor for SearchDo {
 	}
 }
 
+impl SearchDo {
+	/// The `search://` folder results are streamed into. Search URLs are backed
+	/// by local paths only, so non-local directories are shadowed by their cache.
+	fn folder(root: &UrlBuf, subject: &str) -> Option<UrlBuf> {
+		if root.kind().is_local() {
+			root.as_url().to_search(subject).ok()
+		} else {
+			UrlBuf::from(root.as_url().cache()?).into_search(subject).ok()
+		}
+	}
+
+	/// Arguments of the built-in search standing in for `via`.
+	fn args(via: SearchVia, args: Vec<String>) -> Vec<String> {
+		match via {
+			SearchVia::Rg | SearchVia::Rga => vec!["--content".to_owned()],
+			SearchVia::Fd => vec![],
+			SearchVia::Builtin => args,
+		}
+	}
+
+	fn spawn(
+		via: SearchVia,
+		cwd: &UrlBuf,
+		root: &UrlBuf,
+		hidden: bool,
+		subject: String,
+		args: Vec<String>,
+	) -> Result<UnboundedReceiver<File>> {
+		let cwd = cwd.clone();
+		match via {
+			SearchVia::Rg => external::rg(external::RgOpt { cwd, hidden, subject, args }),
+			SearchVia::Rga => external::rga(external::RgaOpt { cwd, hidden, subject, args }),
+			SearchVia::Fd => external::fd(external::FdOpt { cwd, hidden, subject, args }),
+			SearchVia::Builtin => {
+				external::builtin(external::BuiltinOpt { cwd, root: root.clone(), hidden, subject, args })
+			}
+		}
+	}
+}
+
 // --- Stop
 pub struct SearchStop;
 
@@ -1,9 +1,11 @@
 use anyhow::Result;
 use yazi_core::tab::Folder;
-use yazi_fs::{FilesSorter, FolderStage};
+use yazi_fs::{FilesOp, FilesSorter, FolderStage, SortBy};
 use yazi_macro::{act, render, render_and, succ};
 use yazi_parser::{mgr::SortForm, spark::SparkKind};
-use yazi_shared::{Source, data::Data};
+use yazi_runner::{RUNNER, sorter::SortJob};
+use yazi_scheduler::NotifyProxy;
+use yazi_shared::{Source, data::Data, path::PathBufDyn};
 
 use crate::{Actor, Ctx};
 
@@ -16,7 +18,7 @@ impl Actor for Sort {
 
 	fn act(cx: &mut Ctx, form: Self::Form) -> Result<Data> {
 		let pref = &mut cx.tab_mut().pref;
-		pref.sort_by = form.by.unwrap_or(pref.sort_by);
+		pref.sort_by = form.by.unwrap_or_else(|| pref.sort_by.clone());
 		pref.sort_reverse = form.reverse.unwrap_or(pref.sort_reverse);
 		pref.sort_dir_first = form.dir_first.unwrap_or(pref.sort_dir_first);
 		pref.sort_sensitive = form.sensitive.unwrap_or(pref.sort_sensitive);
@@ -24,13 +26,17 @@ impl Actor for Sort {
 		pref.sort_fallback = form.fallback.unwrap_or(pref.sort_fallback);
 
 		let sorter = FilesSorter::from(&*pref);
+		if sorter.by.contains(SortBy::Mime) {
+			Self::attach_mimes(cx);
+		}
+
 		let hovered = cx.hovered().map(|f| f.urn().to_owned());
 		let apply = |f: &mut Folder| {
 			if f.stage == FolderStage::Loading {
 				render!();
 				false
 			} else {
-				f.files.set_sorter(sorter);
+				f.files.set_sorter(sorter.clone());
 				render_and!(f.files.catchup_revision())
 			}
 		};
@@ -55,6 +61,9 @@ impl Actor for Sort {
 			act!(mgr:watch, cx)?;
 		}
 
+		if sorter.by.plugin().is_some() {
+			Self::request_ranks(cx);
+		}
 		succ!();
 	}
 
@@ -66,3 +75,47 @@ impl Actor for Sort {
 		}
 	}
 }
+
+impl Sort {
+	/// Copies the known MIME types into the folders for sorting by MIME type.
+	fn attach_mimes(cx: &mut Ctx) {
+		let (tab, mimetype) = (&mut cx.core.mgr.tabs[cx.tab], &cx.core.mgr.mimetype);
+		let attach = |f: &mut Folder| {
+			let mimes =
+				f.files.iter().filter_map(|file| Some((file.urn().into(), mimetype.owned(&file.url)?)));
+			f.files.update_mimes(mimes.collect());
+		};
+
+		attach(&mut tab.current);
+		tab.parent.as_mut().map(attach);
+		tab.hovered_folder_mut().map(attach);
+	}
+
+	/// Ranks the files of loaded folders with the sort plugin in the background,
+	/// once per folder revision.
+	fn request_ranks(cx: &mut Ctx) {
+		let tab = &mut cx.core.mgr.tabs[cx.tab];
+		let request = |f: &mut Folder| {
+			if f.stage == FolderStage::Loading {
+				return;
+			}
+			let Some((plugin, revision)) = f.files.request_ranks() else { return };
+
+			let (url, files) = (f.url.clone(), f.files.to_vec());
+			tokio::spawn(async move {
+				let job = SortJob { plugin: plugin.clone(), files: files.clone() };
+				match RUNNER.sort(job).await {
+					Ok(ranks) => {
+						let ranks = files.into_iter().zip(ranks).map(|(f, r)| (PathBufDyn::from(f.urn()), r));
+						FilesOp::Rank(url, plugin, revision, ranks.collect()).emit();
+					}
+					Err(e) => NotifyProxy::push_error("Sort", format!("Failed to sort with `{plugin}`: {e}")),
+				}
+			});
+		};
+
+		request(&mut tab.current);
+		tab.parent.as_mut().map(request);
+		tab.hovered_folder_mut().map(request);
+	}
+}
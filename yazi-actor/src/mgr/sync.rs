@@ -0,0 +1,30 @@
+use anyhow::{Result, bail};
+use yazi_macro::succ;
+use yazi_parser::mgr::SyncForm;
+use yazi_shared::{data::Data, url::UrlLike};
+
+use crate::{Actor, Ctx};
+
+pub struct Sync;
+
+impl Actor for Sync {
+	type Form = SyncForm;
+
+	const NAME: &str = "sync";
+
+	fn act(cx: &mut Ctx, form: Self::Form) -> Result<Data> {
+		let tabs = cx.tabs();
+		let idx = form.tab.unwrap_or((tabs.cursor + 1) % tabs.len().max(1));
+
+		let Some(other) = tabs.get(idx).filter(|_| idx != tabs.cursor) else {
+			bail!("Sync requires another tab to sync into");
+		};
+
+		let (from, to) = (tabs.active().cwd(), other.cwd());
+		if from.is_search() || to.is_search() {
+			bail!("Cannot sync from or into search results");
+		}
+
+		succ!(cx.core.tasks.file_sync(from, to, form.delete, form.dry_run, form.verify));
+	}
+}
@@ -15,7 +15,10 @@ impl Actor for TabSwitch {
 	fn act(cx: &mut Ctx, form: Self::Form) -> Result<Data> {
 		let tabs = cx.tabs_mut();
 		let idx = if form.relative {
-			form.step.saturating_add_unsigned(tabs.cursor).rem_euclid(tabs.len() as _) as _
+			let step = form.step.saturating_mul(form.count.unwrap_or(1) as isize);
+			step.saturating_add_unsigned(tabs.cursor).rem_euclid(tabs.len() as _) as _
+		} else if let Some(n) = form.count {
+			n.saturating_sub(1)
 		} else {
 			form.step as usize
 		};
@@ -15,15 +15,21 @@ impl Actor for Toggle {
 
 	fn act(cx: &mut Ctx, form: Self::Form) -> Result<Data> {
 		let tab = cx.tab_mut();
-		let Some(url) = form.url.or_else(|| tab.hovered().map(|h| h.url.clone())) else {
-			succ!();
+		let urls: Vec<_> = if let Some(url) = form.url {
+			vec![url]
+		} else {
+			let files = tab.current.files.iter().skip(tab.current.cursor);
+			files.take(form.count.max(1)).map(|f| f.url.clone()).collect()
 		};
 
-		let b = match form.state {
-			Some(true) => render_and!(tab.selected.add(&url)),
-			Some(false) => render_and!(tab.selected.remove(&url)) | true,
-			None => render_and!(tab.selected.remove(&url) || tab.selected.add(&url)),
-		};
+		let mut b = true;
+		for url in &urls {
+			b &= match form.state {
+				Some(true) => render_and!(tab.selected.add(url)),
+				Some(false) => render_and!(tab.selected.remove(url)) | true,
+				None => render_and!(tab.selected.remove(url) || tab.selected.add(url)),
+			};
+		}
 
 		if !b {
 			NotifyProxy::push_warn(
@@ -0,0 +1,106 @@
+use anyhow::Result;
+use yazi_config::popup::ConfirmCfg;
+use yazi_core::mgr::CdSource;
+use yazi_fs::{File, FilesOp, cha::Cha, provider::local::Trash as LocalTrash};
+use yazi_macro::{act, confirm, succ};
+use yazi_parser::VoidForm;
+use yazi_proxy::MgrProxy;
+use yazi_scheduler::NotifyProxy;
+use yazi_shared::data::Data;
+use yazi_vfs::VfsFile;
+
+use crate::{Actor, Ctx};
+
+pub struct Trash;
+
+impl Actor for Trash {
+	type Form = VoidForm;
+
+	const NAME: &str = "trash";
+
+	fn act(cx: &mut Ctx, _: Self::Form) -> Result<Data> {
+		let Some(view) = LocalTrash::view() else {
+			succ!(NotifyProxy::push_warn("Trash", "Browsing the trash is not supported on this OS"));
+		};
+
+		let tab = cx.tab_mut();
+		if let Some(handle) = tab.search.take() {
+			handle.abort();
+		}
+
+		tab.search = Some(tokio::spawn(async move {
+			let items = match LocalTrash::list().await {
+				Ok(items) => items,
+				Err(e) => {
+					NotifyProxy::push_error("Trash", format!("Failed to list the trash: {e}"));
+					return Ok(());
+				}
+			};
+
+			let ((), ticket) = (MgrProxy::cd(&view, CdSource::Trash), FilesOp::prepare(&view));
+
+			let mut files = Vec::with_capacity(items.len());
+			for item in items {
+				let Some(url) = item.url() else { continue };
+				if let Ok(file) = File::new(url).await {
+					files.push(file);
+				}
+			}
+
+			FilesOp::Part(view.clone(), files, ticket).emit();
+			FilesOp::Done(view, Cha::default(), ticket).emit();
+			Ok(())
+		}));
+
+		succ!();
+	}
+}
+
+// --- Restore
+pub struct TrashRestore;
+
+impl Actor for TrashRestore {
+	type Form = VoidForm;
+
+	const NAME: &str = "trash_restore";
+
+	fn act(cx: &mut Ctx, _: Self::Form) -> Result<Data> {
+		if LocalTrash::view().as_ref() != Some(cx.cwd()) {
+			succ!(NotifyProxy::push_warn("Restore", "Only items in the trash can be restored"));
+		}
+
+		act!(mgr:escape_visual, cx)?;
+
+		let targets: Vec<_> = cx.tab().selected_or_hovered().cloned().collect();
+		cx.tab_mut().selected.remove_many(&targets);
+
+		cx.tasks.file_restore(targets);
+		succ!();
+	}
+}
+
+// --- Empty
+pub struct TrashEmpty;
+
+impl Actor for TrashEmpty {
+	type Form = VoidForm;
+
+	const NAME: &str = "trash_empty";
+
+	fn act(cx: &mut Ctx, _: Self::Form) -> Result<Data> {
+		let Some(view) = LocalTrash::view() else {
+			succ!(NotifyProxy::push_warn("Trash", "Emptying the trash is not supported on this OS"));
+		};
+
+		let confirm = confirm!(cx, ConfirmCfg::empty())?;
+		let scheduler = cx.tasks.scheduler.clone();
+
+		tokio::spawn(async move {
+			if confirm.future().await {
+				scheduler.behavior.reset();
+				scheduler.file_empty(view);
+			}
+		});
+		succ!();
+	}
+}
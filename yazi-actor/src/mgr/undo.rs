@@ -0,0 +1,39 @@
+use anyhow::Result;
+use yazi_config::popup::ConfirmCfg;
+use yazi_macro::{confirm, succ};
+use yazi_parser::VoidForm;
+use yazi_proxy::MgrProxy;
+use yazi_scheduler::NotifyProxy;
+use yazi_shared::data::Data;
+
+use crate::{Actor, Ctx};
+
+pub struct Undo;
+
+impl Actor for Undo {
+	type Form = VoidForm;
+
+	const NAME: &str = "undo";
+
+	fn act(cx: &mut Ctx, _: Self::Form) -> Result<Data> {
+		let scheduler = &cx.tasks.scheduler;
+		scheduler.behavior.reset();
+
+		let Some(deletes) = scheduler.undo() else {
+			NotifyProxy::push_warn("Undo", "Nothing to undo");
+			succ!();
+		};
+		if deletes.is_empty() {
+			succ!();
+		}
+
+		// Copies on remote destinations can't be trashed, so they're deleted permanently
+		let confirm = confirm!(cx, ConfirmCfg::delete(&deletes))?;
+		tokio::spawn(async move {
+			if confirm.future().await {
+				MgrProxy::remove_do(deletes, true);
+			}
+		});
+		succ!();
+	}
+}
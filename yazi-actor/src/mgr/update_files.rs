@@ -72,7 +72,10 @@ impl UpdateFiles {
 	}
 
 	fn update_current(cx: &mut Ctx, op: FilesOp) -> Result<Data> {
-		let calc = !matches!(op, FilesOp::Size(..) | FilesOp::Deleting(..));
+		let calc = !matches!(
+			op,
+			FilesOp::Size(..) | FilesOp::Rank(..) | FilesOp::Git(..) | FilesOp::Deleting(..)
+		);
 
 		let id = cx.tab().id;
 		if !cx.current_mut().update_pub(id, op) {
@@ -1,5 +1,6 @@
 use anyhow::Result;
 use hashbrown::HashMap;
+use yazi_fs::SortBy;
 use yazi_macro::{act, render, succ};
 use yazi_parser::mgr::UpdateMimesForm;
 use yazi_shared::{data::Data, pool::InternStr, url::{AsUrl, UrlCov}};
@@ -47,6 +48,9 @@ impl Actor for UpdateMimes {
 		if repeek {
 			act!(mgr:peek, cx)?;
 		}
+		if cx.current().files.sorter().by.contains(SortBy::Mime) {
+			act!(mgr:sort, cx)?;
+		}
 		cx.tasks.fetch_paged(&affected, &cx.mgr.mimetype);
 		cx.tasks.preload_paged(&affected, &cx.mgr.mimetype);
 
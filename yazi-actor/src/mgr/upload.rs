@@ -13,9 +13,7 @@ impl Actor for Upload {
 	const NAME: &str = "upload";
 
 	fn act(cx: &mut Ctx, form: Self::Form) -> Result<Data> {
-		for url in form.urls {
-			cx.tasks.scheduler.file_upload(url);
-		}
+		cx.tasks.file_upload(form.urls);
 		succ!();
 	}
 }
@@ -18,6 +18,7 @@ impl Actor for Show {
 		let pick = &mut cx.pick;
 		pick.title = form.cfg.title;
 		pick.items = form.cfg.items;
+		pick.previews = form.cfg.previews;
 		pick.position = form.cfg.position;
 
 		pick.callback = Some(form.tx);
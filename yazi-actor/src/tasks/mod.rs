@@ -1 +1 @@
-yazi_macro::mod_flat!(arrow cancel close inspect open_shell_compat process_open show spawn update_succeed);
+yazi_macro::mod_flat!(arrow cancel close inspect open_shell_compat pause process_open resume show spawn throttle update_succeed);
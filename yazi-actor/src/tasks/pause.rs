@@ -0,0 +1,26 @@
+use anyhow::Result;
+use yazi_macro::{render, succ};
+use yazi_parser::VoidForm;
+use yazi_shared::data::Data;
+
+use crate::{Actor, Ctx};
+
+pub struct Pause;
+
+impl Actor for Pause {
+	type Form = VoidForm;
+
+	const NAME: &str = "pause";
+
+	fn act(cx: &mut Ctx, _: Self::Form) -> Result<Data> {
+		let tasks = &mut cx.tasks;
+
+		let id = tasks.scheduler.ongoing.lock().get_id(tasks.cursor);
+		if id.map(|id| tasks.scheduler.pause(id)) != Some(true) {
+			succ!();
+		}
+
+		tasks.snaps = tasks.paginate();
+		succ!(render!());
+	}
+}
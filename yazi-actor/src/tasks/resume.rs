@@ -0,0 +1,26 @@
+use anyhow::Result;
+use yazi_macro::{render, succ};
+use yazi_parser::VoidForm;
+use yazi_shared::data::Data;
+
+use crate::{Actor, Ctx};
+
+pub struct Resume;
+
+impl Actor for Resume {
+	type Form = VoidForm;
+
+	const NAME: &str = "resume";
+
+	fn act(cx: &mut Ctx, _: Self::Form) -> Result<Data> {
+		let tasks = &mut cx.tasks;
+
+		let id = tasks.scheduler.ongoing.lock().get_id(tasks.cursor);
+		if id.map(|id| tasks.scheduler.resume(id)) != Some(true) {
+			succ!();
+		}
+
+		tasks.snaps = tasks.paginate();
+		succ!(render!());
+	}
+}
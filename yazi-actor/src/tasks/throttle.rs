@@ -0,0 +1,97 @@
+use anyhow::Result;
+use yazi_config::popup::InputCfg;
+use yazi_macro::{input, succ};
+use yazi_parser::VoidForm;
+use yazi_scheduler::{NotifyProxy, file::Rates};
+use yazi_shared::data::Data;
+use yazi_widgets::input::InputEvent;
+
+use crate::{Actor, Ctx};
+
+const UNITS: [(char, u64); 3] = [('G', 1 << 30), ('M', 1 << 20), ('K', 1 << 10)];
+
+pub struct Throttle;
+
+impl Actor for Throttle {
+	type Form = VoidForm;
+
+	const NAME: &str = "throttle";
+
+	fn act(cx: &mut Ctx, _: Self::Form) -> Result<Data> {
+		let tasks = &cx.tasks;
+		let rate = tasks.scheduler.ongoing.lock().values().nth(tasks.cursor).and_then(Rates::of);
+
+		let Some((kind, rate)) = rate else {
+			succ!(NotifyProxy::push_warn("Throttle", "The hovered task doesn't transfer any data"));
+		};
+
+		let cfg = InputCfg::throttle(kind).with_value(Self::format(rate.get()));
+		let mut input = input!(cx, cfg)?;
+
+		tokio::spawn(async move {
+			let Some(InputEvent::Submit(s)) = input.recv().await else { return };
+			match Self::parse(&s) {
+				Some(limit) => rate.set(limit),
+				None => NotifyProxy::push_warn("Throttle", format!("Invalid rate `{s}`")),
+			}
+		});
+		succ!();
+	}
+}
+
+impl Throttle {
+	/// Parses a rate like `512K` or `10M`, an empty string means unlimited.
+	fn parse(s: &str) -> Option<u64> {
+		let s = s.trim().trim_end_matches("/s").trim_end_matches(['B', 'b']);
+		if s.is_empty() {
+			return Some(0);
+		}
+
+		let upper = s.to_ascii_uppercase();
+		for (unit, n) in UNITS {
+			if let Some(num) = upper.strip_suffix(unit) {
+				return num.trim().parse::<f64>().ok().filter(|f| *f >= 0.0).map(|f| (f * n as f64) as u64);
+			}
+		}
+		s.parse().ok()
+	}
+
+	fn format(limit: u64) -> String {
+		match UNITS.iter().find(|&&(_, n)| limit >= n && limit.is_multiple_of(n)) {
+			_ if limit == 0 => String::new(),
+			Some((unit, n)) => format!("{}{unit}", limit / n),
+			None => limit.to_string(),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_parse() {
+		assert_eq!(Throttle::parse(""), Some(0));
+		assert_eq!(Throttle::parse(" 100 "), Some(100));
+		assert_eq!(Throttle::parse("512K"), Some(512 << 10));
+		assert_eq!(Throttle::parse("10mb/s"), Some(10 << 20));
+		assert_eq!(Throttle::parse("1.5G"), Some(3 << 29));
+
+		assert_eq!(Throttle::parse("-1M"), None);
+		assert_eq!(Throttle::parse("1T"), None);
+		assert_eq!(Throttle::parse("fast"), None);
+	}
+
+	#[test]
+	fn test_format() {
+		assert_eq!(Throttle::format(0), "");
+		assert_eq!(Throttle::format(100), "100");
+		assert_eq!(Throttle::format(1536), "1536");
+		assert_eq!(Throttle::format(10 << 20), "10M");
+		assert_eq!(Throttle::format(3 << 30), "3G");
+
+		for limit in [0, 100, 1536, 512 << 10, 10 << 20, 3 << 30] {
+			assert_eq!(Throttle::parse(&Throttle::format(limit)), Some(limit));
+		}
+	}
+}
@@ -14,7 +14,7 @@ impl Actor for Activate {
 	const NAME: &str = "activate";
 
 	fn act(cx: &mut Ctx, Self::Form { mut opt }: Self::Form) -> Result<Data> {
-		opt.cands.retain(|c| c.on.len() > opt.times);
+		opt.cands.retain(|c| c.on.len() > opt.times && cx.core.satisfies(c));
 		WhichSorter::default().sort(&mut opt.cands);
 
 		if opt.cands.is_empty() {
@@ -18,6 +18,10 @@ pub struct Args {
 	#[arg(long)]
 	pub chooser_file: Option<PathBuf>,
 
+	/// Restore tabs from the named session, and save them back on exit
+	#[arg(long, value_parser = parse_session)]
+	pub session: Option<String>,
+
 	/// Clear the cache directory
 	#[arg(long)]
 	pub clear_cache: bool,
@@ -40,3 +44,11 @@ pub struct Args {
 	#[arg(short = 'V', long)]
 	pub version: bool,
 }
+
+fn parse_session(s: &str) -> Result<String, String> {
+	if s.is_empty() || s.starts_with('.') || s.contains(['/', '\\']) {
+		Err("must be a non-empty file name".to_owned())
+	} else {
+		Ok(s.to_owned())
+	}
+}
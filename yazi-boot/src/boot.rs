@@ -1,5 +1,6 @@
 use futures::executor::block_on;
 use hashbrown::HashSet;
+use yazi_config::YAZI;
 use yazi_fs::{CWD, path::clean_url};
 use yazi_shared::{strand::StrandBuf, url::{UrlBuf, UrlLike}};
 use yazi_vfs::provider;
@@ -9,6 +10,8 @@ pub struct Boot {
 	pub cwds:  Vec<UrlBuf>,
 	pub files: Vec<StrandBuf>,
 
+	pub session: Option<String>,
+
 	pub local_events:  HashSet<String>,
 	pub remote_events: HashSet<String>,
 }
@@ -58,6 +61,9 @@ impl From<&crate::Args> for Boot {
 			.map(|s| s.split(',').map(|s| s.to_owned()).collect())
 			.unwrap_or_default();
 
-		Self { cwds, files, local_events, remote_events }
+		let session =
+			args.session.clone().or_else(|| YAZI.mgr.session.get().then(|| "default".to_owned()));
+
+		Self { cwds, files, session, local_events, remote_events }
 	}
 }
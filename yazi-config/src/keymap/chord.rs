@@ -3,9 +3,9 @@ use std::{borrow::Cow, hash::{Hash, Hasher}, sync::OnceLock};
 use regex::Regex;
 use serde::{Deserialize, Deserializer, de};
 use serde_with::{DeserializeAs, DisplayFromStr, OneOrMany};
-use yazi_shared::{Layer, Source, event::Action};
+use yazi_shared::{Condition, Layer, Source, event::Action};
 
-use super::Key;
+use super::{Key, Term};
 use crate::{Mixable, Platform};
 
 static RE: OnceLock<Regex> = OnceLock::new();
@@ -20,6 +20,9 @@ pub struct Chord<const L: u8 = { Layer::App as u8 }> {
 	pub desc:  String,
 	#[serde(default)]
 	pub r#for: Platform,
+	/// Only matches when the condition holds, e.g. `hovered.is_dir & !visual`.
+	#[serde(default, deserialize_with = "deserialize_when")]
+	pub when:  Option<Condition>,
 }
 
 impl<const L: u8> PartialEq for Chord<L> {
@@ -96,3 +99,14 @@ where
 
 	Ok(actions)
 }
+
+fn deserialize_when<'de, D>(deserializer: D) -> Result<Option<Condition>, D::Error>
+where
+	D: Deserializer<'de>,
+{
+	let Some(cond) = Option::<Condition>::deserialize(deserializer)? else { return Ok(None) };
+	for term in cond.terms() {
+		term.parse::<Term>().map_err(de::Error::custom)?;
+	}
+	Ok(Some(cond))
+}
@@ -40,4 +40,44 @@ impl<const L: u8> ChordCow<L> {
 			Self::Borrowed(c) => c.run.iter().rev().map(Into::into).collect(),
 		}
 	}
+
+	/// Like [`Self::into_seq`], but passes the count prefix to each action.
+	pub fn into_counted_seq(self, count: usize) -> Vec<ActionCow> {
+		if count == 0 {
+			return self.into_seq();
+		}
+
+		let count = count as i64;
+		match self {
+			Self::Owned(c) => c.run.into_iter().rev().map(|a| a.with("count", count).into()).collect(),
+			Self::Borrowed(c) => {
+				c.run.iter().rev().map(|a| a.clone().with("count", count).into()).collect()
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use yazi_shared::event::Action;
+
+	use super::*;
+
+	#[test]
+	fn test_into_counted_seq() {
+		let chord = Chord::<{ Layer::Mgr as u8 }> {
+			run: vec![Action::new_relay("toggle"), Action::new_relay("arrow").with("count", 9)],
+			..Default::default()
+		};
+
+		// Popped from the end
+		let seq = ChordCow::from(chord.clone()).into_counted_seq(3);
+		assert_eq!(seq.iter().map(|a| &*a.name).collect::<Vec<_>>(), ["arrow", "toggle"]);
+		assert!(seq.iter().all(|a| a.get::<i64>("count").unwrap() == 3));
+
+		// No count given
+		let seq = ChordCow::from(chord).into_counted_seq(0);
+		assert!(seq[1].get::<i64>("count").is_err());
+		assert_eq!(seq[0].get::<i64>("count").unwrap(), 9);
+	}
 }
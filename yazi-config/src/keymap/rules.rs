@@ -39,8 +39,10 @@ impl<const L: u8> DeserializeOverHook for KeymapRules<L> {
 			[on.first().copied().unwrap_or_default(), on.get(1).copied().unwrap_or_default()]
 		}
 
-		let a_seen: HashSet<_> = self.prepend_keymap.iter().map(on).collect();
-		let b_seen: HashSet<_> = self.keymap.iter().map(on).collect();
+		// Conditional chords only shadow the others when they match, at runtime
+		let a_seen: HashSet<_> =
+			self.prepend_keymap.iter().filter(|c| c.when.is_none()).map(on).collect();
+		let b_seen: HashSet<_> = self.keymap.iter().filter(|c| c.when.is_none()).map(on).collect();
 
 		let keymap = mix(
 			self.prepend_keymap,
@@ -0,0 +1,94 @@
+use std::{cmp::Ordering, str::FromStr};
+
+use anyhow::{Result, bail};
+use yazi_shared::scheme::SchemeKind;
+
+use crate::Pattern;
+
+/// A term in the `when` condition of a chord.
+pub enum Term {
+	Hovered,
+	HoveredIsDir,
+	HoveredIsFile,
+	HoveredIsLink,
+	HoveredIsOrphan,
+	HoveredIsHidden,
+	Mime(Pattern),
+	Scheme(SchemeKind),
+	Tabs(Ordering, usize),
+	Selected,
+	Visual,
+	Yanked,
+	Finding,
+	Trash,
+}
+
+impl FromStr for Term {
+	type Err = anyhow::Error;
+
+	fn from_str(s: &str) -> Result<Self> {
+		if let Some(p) = s.strip_prefix("mime:") {
+			return Ok(Self::Mime(p.parse()?));
+		} else if let Some(k) = s.strip_prefix("scheme.") {
+			return Ok(Self::Scheme(SchemeKind::try_from(k.as_bytes())?));
+		} else if let Some(t) = s.strip_prefix("tabs") {
+			let (ord, n) = match t.split_at_checked(1) {
+				Some((">", n)) => (Ordering::Greater, n),
+				Some(("<", n)) => (Ordering::Less, n),
+				Some(("=", n)) => (Ordering::Equal, n),
+				_ => bail!("invalid term `{s}`, expected `tabs>N`, `tabs<N` or `tabs=N`"),
+			};
+			return Ok(Self::Tabs(ord, n.parse()?));
+		}
+
+		Ok(match s {
+			"hovered" => Self::Hovered,
+			"hovered.is_dir" => Self::HoveredIsDir,
+			"hovered.is_file" => Self::HoveredIsFile,
+			"hovered.is_link" => Self::HoveredIsLink,
+			"hovered.is_orphan" => Self::HoveredIsOrphan,
+			"hovered.is_hidden" => Self::HoveredIsHidden,
+			"selected" => Self::Selected,
+			"visual" => Self::Visual,
+			"yanked" => Self::Yanked,
+			"finding" => Self::Finding,
+			"trash" => Self::Trash,
+			_ => bail!("unknown term `{s}` in `when` condition"),
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_parse() {
+		assert!(matches!("tabs>1".parse(), Ok(Term::Tabs(Ordering::Greater, 1))));
+		assert!(matches!("tabs=3".parse(), Ok(Term::Tabs(Ordering::Equal, 3))));
+		assert!(matches!("scheme.sftp".parse(), Ok(Term::Scheme(SchemeKind::Sftp))));
+		assert!(matches!("trash".parse(), Ok(Term::Trash)));
+
+		let Ok(Term::Mime(p)) = "mime:image/*".parse() else { panic!() };
+		assert!(p.match_mime("image/png") && !p.match_mime("text/plain"));
+
+		for s in ["tabs", "tabs>x", "tabs>=1", "scheme.ftp", "hovered.is_big", "trahs"] {
+			assert!(s.parse::<Term>().is_err(), "{s}");
+		}
+	}
+
+	#[test]
+	fn test_chord() {
+		yazi_shared::init_tests();
+		let chord = |when: &str| {
+			toml::from_str::<super::super::Chord>(&format!("on = 'R'\nrun = 'noop'\nwhen = '{when}'"))
+		};
+
+		assert!(chord("trash & !visual").is_ok());
+		assert!(chord("tabs>1 | mime:image/*").is_ok());
+
+		// Rejected at load instead of never matching
+		assert!(chord("trahs").is_err());
+		assert!(chord("trash & hovered.is_big").is_err());
+	}
+}
@@ -2,7 +2,7 @@ use anyhow::Result;
 use arc_swap::ArcSwap;
 use serde::{Deserialize, Deserializer, de};
 use yazi_codegen::{DeserializeOver, DeserializeOver2};
-use yazi_fs::{SortBy, SortFallback};
+use yazi_fs::{SortFallback, SortSpec};
 use yazi_shim::{arc_swap::IntoPointee, cell::SyncCell};
 
 use super::{MgrRatio, MouseEvents};
@@ -12,7 +12,7 @@ pub struct Mgr {
 	pub ratio: SyncCell<MgrRatio>,
 
 	// Sorting
-	pub sort_by:        SyncCell<SortBy>,
+	pub sort_by:        ArcSwap<SortSpec>,
 	pub sort_sensitive: SyncCell<bool>,
 	pub sort_reverse:   SyncCell<bool>,
 	pub sort_dir_first: SyncCell<bool>,
@@ -26,6 +26,9 @@ pub struct Mgr {
 	pub show_symlink: SyncCell<bool>,
 	pub scrolloff:    SyncCell<u8>,
 	pub mouse_events: SyncCell<MouseEvents>,
+
+	// Session
+	pub session: SyncCell<bool>,
 }
 
 fn deserialize_linemode<'de, D>(deserializer: D) -> Result<ArcSwap<String>, D::Error>
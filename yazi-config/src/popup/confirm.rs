@@ -16,6 +16,19 @@ pub struct Confirm {
 	pub delete_origin: Origin,
 	pub delete_offset: Offset,
 
+	// empty
+	pub empty_title:  String,
+	pub empty_body:   String,
+	pub empty_origin: Origin,
+	pub empty_offset: Offset,
+
+	// conflict
+	pub conflict_title:  String,
+	pub conflict_body:   String,
+	pub conflict_labels: [String; 5],
+	pub conflict_origin: Origin,
+	pub conflict_offset: Offset,
+
 	// overwrite
 	pub overwrite_title:  String,
 	pub overwrite_body:   String,
@@ -27,6 +40,12 @@ pub struct Confirm {
 	pub quit_body:   String,
 	pub quit_origin: Origin,
 	pub quit_offset: Offset,
+
+	// unfinished
+	pub unfinished_title:  String,
+	pub unfinished_body:   String,
+	pub unfinished_origin: Origin,
+	pub unfinished_offset: Offset,
 }
 
 impl Confirm {
@@ -40,6 +59,14 @@ impl Confirm {
 		Position::new(self.delete_origin, self.delete_offset)
 	}
 
+	pub const fn empty_position(&self) -> Position {
+		Position::new(self.empty_origin, self.empty_offset)
+	}
+
+	pub const fn conflict_position(&self) -> Position {
+		Position::new(self.conflict_origin, self.conflict_offset)
+	}
+
 	pub const fn overwrite_position(&self) -> Position {
 		Position::new(self.overwrite_origin, self.overwrite_offset)
 	}
@@ -47,4 +74,8 @@ impl Confirm {
 	pub const fn quit_position(&self) -> Position {
 		Position::new(self.quit_origin, self.quit_offset)
 	}
+
+	pub const fn unfinished_position(&self) -> Position {
+		Position::new(self.unfinished_origin, self.unfinished_offset)
+	}
 }
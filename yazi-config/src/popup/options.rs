@@ -1,5 +1,7 @@
+use std::collections::BTreeMap;
+
 use ratatui::{text::{Line, Text}, widgets::{Paragraph, Wrap}};
-use yazi_shared::{scheme::Encode as EncodeScheme, strand::ToStrand, url::{Url, UrlBuf}};
+use yazi_shared::{scheme::Encode as EncodeScheme, strand::ToStrand, url::{Url, UrlBuf, UrlLike}};
 
 use super::{Offset, Position};
 use crate::{YAZI, popup::Origin};
@@ -19,6 +21,8 @@ pub struct InputCfg {
 pub struct PickCfg {
 	pub title:    String,
 	pub items:    Vec<String>,
+	/// Lines shown beside the list for the item under the cursor, if any.
+	pub previews: Vec<Vec<String>>,
 	pub position: Position,
 }
 
@@ -28,6 +32,9 @@ pub struct ConfirmCfg {
 	pub title:    Line<'static>,
 	pub body:     Paragraph<'static>,
 	pub list:     Paragraph<'static>,
+	/// Named choices as `(name, label)` replacing the yes/no buttons,
+	/// the first one is picked on submit.
+	pub choices:  Vec<(&'static str, &'static str)>,
 }
 
 impl InputCfg {
@@ -91,6 +98,19 @@ impl InputCfg {
 		}
 	}
 
+	pub fn mark() -> Self {
+		Self {
+			title: "Mark as (a single character):".to_owned(),
+			position: Position::new(Origin::TopCenter, Offset {
+				x:      0,
+				y:      2,
+				width:  50,
+				height: 3,
+			}),
+			..Default::default()
+		}
+	}
+
 	pub fn tab_rename() -> Self {
 		Self {
 			title: "Rename tab:".to_owned(),
@@ -104,6 +124,19 @@ impl InputCfg {
 		}
 	}
 
+	pub fn throttle(kind: &str) -> Self {
+		Self {
+			title: format!("Limit {kind} tasks to (e.g. 10M/s, empty for unlimited):"),
+			position: Position::new(Origin::TopCenter, Offset {
+				x:      0,
+				y:      2,
+				width:  60,
+				height: 3,
+			}),
+			..Default::default()
+		}
+	}
+
 	#[inline]
 	pub fn with_value(mut self, value: impl Into<String>) -> Self {
 		self.value = value.into();
@@ -129,6 +162,7 @@ impl ConfirmCfg {
 			title: Line::raw(title),
 			body: body.map(|b| Paragraph::new(b).wrap(Wrap { trim: false })).unwrap_or_default(),
 			list: list.map(|l| Paragraph::new(l).wrap(Wrap { trim: false })).unwrap_or_default(),
+			choices: vec![],
 		}
 	}
 
@@ -150,6 +184,30 @@ impl ConfirmCfg {
 		)
 	}
 
+	pub fn empty() -> Self {
+		Self::new(
+			YAZI.confirm.empty_title.clone(),
+			YAZI.confirm.empty_position(),
+			Some(Text::raw(&YAZI.confirm.empty_body)),
+			None,
+		)
+	}
+
+	pub fn conflict(url: &UrlBuf) -> Self {
+		Self {
+			choices: ["overwrite", "skip", "rename", "newer", "larger"]
+				.into_iter()
+				.zip(YAZI.confirm.conflict_labels.iter().map(String::as_str))
+				.collect(),
+			..Self::new(
+				YAZI.confirm.conflict_title.clone(),
+				YAZI.confirm.conflict_position(),
+				Some(Text::raw(&YAZI.confirm.conflict_body)),
+				Some(url.to_strand().into_string_lossy().into()),
+			)
+		}
+	}
+
 	pub fn overwrite(url: &UrlBuf) -> Self {
 		Self::new(
 			YAZI.confirm.overwrite_title.clone(),
@@ -168,6 +226,15 @@ impl ConfirmCfg {
 		)
 	}
 
+	pub fn unfinished(len: usize, names: Vec<String>) -> Self {
+		Self::new(
+			Self::replace_number(&YAZI.confirm.unfinished_title, len),
+			YAZI.confirm.unfinished_position(),
+			Some(Text::raw(&YAZI.confirm.unfinished_body)),
+			Self::truncate_list(names, len, 10),
+		)
+	}
+
 	fn replace_number(tpl: &str, n: usize) -> String {
 		tpl.replace("{n}", &n.to_string()).replace("{s}", if n > 1 { "s" } else { "" })
 	}
@@ -194,11 +261,28 @@ impl PickCfg {
 		YAZI.pick.open_offset.height.min(YAZI.pick.border().saturating_add(len as u16))
 	}
 
+	pub fn mark(marks: &BTreeMap<char, UrlBuf>, previews: Vec<Vec<String>>) -> Self {
+		let lines = previews.iter().map(Vec::len).max().unwrap_or(0);
+		let max_height = Self::max_height(marks.len().max(lines));
+		Self {
+			title: "Marks:".to_owned(),
+			items: marks.iter().map(|(key, url)| format!("{key}  {}", url.display())).collect(),
+			previews,
+			position: Position::new(Origin::TopCenter, Offset {
+				x:      0,
+				y:      2,
+				width:  100,
+				height: max_height,
+			}),
+		}
+	}
+
 	pub fn open(items: Vec<String>) -> Self {
 		let max_height = Self::max_height(items.len());
 		Self {
 			title: YAZI.pick.open_title.clone(),
 			items,
+			previews: vec![],
 			position: Position::new(YAZI.pick.open_origin, Offset {
 				height: max_height,
 				..YAZI.pick.open_offset
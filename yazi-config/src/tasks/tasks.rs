@@ -13,6 +13,10 @@ pub struct Tasks {
 
 	pub bizarre_retry: NonZeroU8,
 
+	pub file_rate:     u64,
+	pub download_rate: u64,
+	pub upload_rate:   u64,
+
 	pub image_alloc: u32,
 	pub image_bound: [u16; 2],
 
@@ -28,6 +28,8 @@ pub struct Theme {
 	pub cmp:       Cmp,
 	pub tasks:     Tasks,
 	pub help:      Help,
+	pub git:       Git,
+	pub compare:   Compare,
 
 	// File-specific styles
 	pub filetype: Filetype,
@@ -229,6 +231,30 @@ pub struct Help {
 	pub footer:  SyncCell<Style>,
 }
 
+#[derive(Deserialize, DeserializeOver, DeserializeOver2, Overlay)]
+pub struct Git {
+	pub modified:   SyncCell<Style>,
+	pub staged:     SyncCell<Style>,
+	pub untracked:  SyncCell<Style>,
+	pub ignored:    SyncCell<Style>,
+	pub conflicted: SyncCell<Style>,
+
+	pub modified_sign:   ArcSwap<String>,
+	pub staged_sign:     ArcSwap<String>,
+	pub untracked_sign:  ArcSwap<String>,
+	pub ignored_sign:    ArcSwap<String>,
+	pub conflicted_sign: ArcSwap<String>,
+}
+
+#[derive(Deserialize, DeserializeOver, DeserializeOver2, Overlay)]
+pub struct Compare {
+	pub unique:    SyncCell<Style>,
+	pub identical: SyncCell<Style>,
+	pub newer:     SyncCell<Style>,
+	pub older:     SyncCell<Style>,
+	pub differing: SyncCell<Style>,
+}
+
 impl Theme {
 	pub(crate) fn read() -> Result<String> {
 		let p = Xdg::config_dir().join("theme.toml");
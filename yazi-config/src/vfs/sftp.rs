@@ -19,6 +19,8 @@ pub struct ServiceSftp {
 	pub no_cert_verify: bool,
 	#[serde(default = "default_identity_agent", deserialize_with = "deserialize_identity_agent")]
 	pub identity_agent: PathBuf,
+	#[serde(default)]
+	pub poll_interval:  u16,
 }
 
 fn deserialize_path<'de, D>(deserializer: D) -> Result<PathBuf, D::Error>
@@ -1,4 +1,5 @@
 use ratatui::{text::Line, widgets::Paragraph};
+use tokio::sync::mpsc;
 use yazi_config::popup::Position;
 use yazi_shared::CompletionToken;
 
@@ -8,6 +9,9 @@ pub struct Confirm {
 	pub body:  Paragraph<'static>,
 	pub list:  Paragraph<'static>,
 
+	pub choices: Vec<(&'static str, &'static str)>,
+	pub replier: Option<mpsc::UnboundedSender<(&'static str, bool)>>,
+
 	pub position: Position,
 	pub offset:   usize,
 
@@ -1,18 +1,20 @@
-use std::{io::{BufRead, BufReader, Cursor, Seek}, path::PathBuf, sync::OnceLock};
+use std::{io::{BufRead, BufReader, Cursor, Seek}, sync::OnceLock};
 
 use anyhow::{Result, anyhow, bail};
 use ratatui::{layout::Size, text::{Line, Span, Text}};
 use syntect::{LoadingError, dumps, easy::HighlightLines, highlighting::{self, Theme, ThemeSet}, parsing::{SyntaxReference, SyntaxSet}};
+use tokio_util::io::SyncIoBridge;
 use yazi_config::{THEME, YAZI};
 use yazi_runner::previewer::PeekError;
-use yazi_shared::{Id, Ids, replace_to_printable};
+use yazi_shared::{Id, Ids, replace_to_printable, url::{UrlBuf, UrlLike}};
 use yazi_shim::ratatui::LineIter;
+use yazi_vfs::provider::{self, RwFile};
 
 static INCR: Ids = Ids::new();
 
 pub struct Highlighter {
-	path:   PathBuf,
-	reader: BufReader<std::fs::File>,
+	url:    UrlBuf,
+	reader: BufReader<SyncIoBridge<RwFile>>,
 
 	skip:   usize,
 	size:   Size,
@@ -25,26 +27,27 @@ pub struct Highlighter {
 }
 
 impl Highlighter {
-	pub async fn oneshot<P>(path: P, skip: usize, size: Size) -> Result<Text<'static>, PeekError>
+	pub async fn oneshot<U>(url: U, skip: usize, size: Size) -> Result<Text<'static>, PeekError>
 	where
-		P: Into<PathBuf>,
+		U: Into<UrlBuf>,
 	{
-		let path = path.into();
-		tokio::task::spawn_blocking(move || Self::make(path, skip, size)?.highlight()).await?
+		// Read through the provider, so that remote files only need to be fetched
+		// as far as the lines being previewed
+		let url = url.into();
+		let file = provider::open(&url).await.map_err(anyhow::Error::from)?;
+		let reader = BufReader::with_capacity(64 * 1024, SyncIoBridge::new(file));
+
+		tokio::task::spawn_blocking(move || Self::make(url, reader, skip, size).highlight()).await?
 	}
 
-	fn make<P>(path: P, skip: usize, size: Size) -> Result<Self>
-	where
-		P: Into<PathBuf>,
-	{
+	fn make(url: UrlBuf, reader: BufReader<SyncIoBridge<RwFile>>, skip: usize, size: Size) -> Self {
 		static CACHE: OnceLock<(Theme, SyntaxSet)> = OnceLock::new();
 
-		let path = path.into();
 		let (theme, syntaxes) = CACHE.get_or_init(Self::load);
 
-		Ok(Self {
-			reader: BufReader::new(std::fs::File::open(&path)?),
-			path,
+		Self {
+			url,
+			reader,
 
 			skip,
 			size,
@@ -54,7 +57,7 @@ impl Highlighter {
 			syntaxes,
 			inner: None,
 			syntax: None,
-		})
+		}
 	}
 
 	pub fn abort() { INCR.next(); }
@@ -155,13 +158,13 @@ impl Highlighter {
 	}
 
 	fn load_syntax(&mut self) -> Result<()> {
-		let name = self.path.file_name().map(|n| n.to_string_lossy()).unwrap_or_default();
+		let name = self.url.name().map(|n| n.to_string_lossy()).unwrap_or_default();
 		if let Some(s) = self.syntaxes.find_syntax_by_extension(&name) {
 			self.syntax = Some(s);
 			return Ok(());
 		}
 
-		let ext = self.path.extension().map(|e| e.to_string_lossy()).unwrap_or_default();
+		let ext = self.url.ext().map(|e| e.to_string_lossy()).unwrap_or_default();
 		if let Some(s) = self.syntaxes.find_syntax_by_extension(&ext) {
 			self.syntax = Some(s);
 			return Ok(());
@@ -14,10 +14,12 @@ pub enum CdSource {
 
 	Follow,
 	Search,
+	Trash,
 	Escape,
 
 	Forward,
 	Back,
+	Mark,
 
 	Tab,
 	Displace,
@@ -0,0 +1,9 @@
+use crate::tab::Compare;
+
+#[derive(Clone, Debug)]
+pub struct CompareOpt {
+	pub left:   Compare,
+	pub right:  Compare,
+	/// Whether to select the differences in the left tab.
+	pub select: bool,
+}
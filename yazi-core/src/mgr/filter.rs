@@ -5,6 +5,8 @@ use yazi_shared::{SStr, event::ActionCow};
 pub struct FilterOpt {
 	pub query: SStr,
 	pub case:  FilterCase,
+	pub fuzzy: bool,
+	pub rank:  bool,
 	pub done:  bool,
 }
 
@@ -15,6 +17,8 @@ impl TryFrom<ActionCow> for FilterOpt {
 		Ok(Self {
 			query: a.take_first().unwrap_or_default(),
 			case:  FilterCase::from(&*a),
+			fuzzy: a.bool("fuzzy"),
+			rank:  a.bool("rank"),
 			done:  a.bool("done"),
 		})
 	}
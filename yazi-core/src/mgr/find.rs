@@ -7,6 +7,7 @@ pub struct FindDoOpt {
 	pub query: SStr,
 	pub prev:  bool,
 	pub case:  FilterCase,
+	pub fuzzy: bool,
 }
 
 impl TryFrom<ActionCow> for FindDoOpt {
@@ -17,6 +18,11 @@ impl TryFrom<ActionCow> for FindDoOpt {
 			bail!("Invalid 'query' in FindDoOpt");
 		};
 
-		Ok(Self { query, prev: a.bool("previous"), case: FilterCase::from(&*a) })
+		Ok(Self {
+			query,
+			prev: a.bool("previous"),
+			case: FilterCase::from(&*a),
+			fuzzy: a.bool("fuzzy"),
+		})
 	}
 }
@@ -0,0 +1,133 @@
+use std::{collections::BTreeMap, path::PathBuf};
+
+use hashbrown::HashMap;
+use serde::{Deserialize, Serialize};
+use tracing::error;
+use yazi_fs::Xdg;
+use yazi_shared::{Layer, SStr, Source, data::{Data, DataKey}, event::{Action, Cmd}};
+
+/// Actions dispatched by keybindings, recorded into single-character
+/// registers and stored in the state directory to be replayed later.
+#[derive(Debug, Default)]
+pub struct Macros {
+	recording: Option<(char, Vec<Action>)>,
+	registers: BTreeMap<char, Vec<Action>>,
+}
+
+impl Macros {
+	pub fn load() -> Self {
+		let Ok(b) = std::fs::read(Self::path()) else { return Self::default() };
+
+		match serde_json::from_slice::<BTreeMap<char, Vec<MacroAction>>>(&b) {
+			Ok(saved) => Self {
+				recording: None,
+				registers: saved
+					.into_iter()
+					.map(|(reg, actions)| (reg, actions.into_iter().map(Into::into).collect()))
+					.collect(),
+			},
+			Err(e) => {
+				error!("Failed to load macros: {e:?}");
+				Self::default()
+			}
+		}
+	}
+
+	#[inline]
+	pub fn recording(&self) -> Option<char> { self.recording.as_ref().map(|&(reg, _)| reg) }
+
+	#[inline]
+	pub fn get(&self, reg: char) -> Option<&[Action]> { self.registers.get(&reg).map(Vec::as_slice) }
+
+	pub fn record(&mut self, reg: char) { self.recording = Some((reg, vec![])); }
+
+	/// Records the action if it was dispatched by a keybinding while a recording
+	/// is in progress, except for the macro actions themselves.
+	pub fn push(&mut self, action: &Action) {
+		let Some((_, actions)) = &mut self.recording else { return };
+		if !action.source.is_key() || action.name.starts_with("macro_") {
+			return;
+		}
+
+		actions.push(action.clone());
+	}
+
+	/// Stops the recording, and saves it into its register.
+	pub fn stop(&mut self) -> Option<char> {
+		let (reg, actions) = self.recording.take()?;
+		if actions.is_empty() {
+			self.registers.remove(&reg);
+		} else {
+			self.registers.insert(reg, actions);
+		}
+
+		self.save();
+		Some(reg)
+	}
+
+	fn save(&self) {
+		let saved: BTreeMap<_, Vec<_>> = self
+			.registers
+			.iter()
+			.map(|(&reg, actions)| (reg, actions.iter().map(MacroAction::from).collect()))
+			.collect();
+
+		let result = serde_json::to_vec(&saved).map_err(Into::into).and_then(|json| {
+			std::fs::create_dir_all(Xdg::state_dir())?;
+			std::fs::write(Self::path(), json)
+		});
+
+		if let Err(e) = result {
+			error!("Failed to save macros: {e:?}");
+		}
+	}
+
+	#[inline]
+	fn path() -> PathBuf { Xdg::state_dir().join("macros.json") }
+}
+
+// --- Persistence
+#[derive(Deserialize, Serialize)]
+struct MacroAction {
+	layer: Layer,
+	name:  SStr,
+	#[serde(default, skip_serializing_if = "HashMap::is_empty")]
+	args:  HashMap<DataKey, Data>,
+}
+
+impl From<&Action> for MacroAction {
+	fn from(action: &Action) -> Self {
+		Self { layer: action.layer, name: action.name.clone(), args: action.args.clone() }
+	}
+}
+
+impl From<MacroAction> for Action {
+	fn from(value: MacroAction) -> Self {
+		Self {
+			cmd:    Cmd { name: value.name, args: value.args },
+			layer:  value.layer,
+			source: Source::Key,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_macro_action_serde() {
+		let mut action: Action = "arrow 5 --relative".parse().unwrap();
+		action.layer = Layer::Mgr;
+		action.source = Source::Key;
+
+		let json = serde_json::to_vec(&MacroAction::from(&action)).unwrap();
+		let action: Action = serde_json::from_slice::<MacroAction>(&json).unwrap().into();
+
+		assert_eq!(action.layer, Layer::Mgr);
+		assert_eq!(action.name, "arrow");
+		assert!(action.source.is_key());
+		assert_eq!(action.get::<i64>(0).unwrap(), 5);
+		assert!(action.bool("relative"));
+	}
+}
@@ -0,0 +1,152 @@
+use std::{collections::BTreeMap, ops::Deref, path::PathBuf};
+
+use tracing::error;
+use yazi_dds::Pubsub;
+use yazi_fs::{Xdg, provider::{DirReader, FileHolder}};
+use yazi_macro::err;
+use yazi_shared::url::UrlBuf;
+use yazi_vfs::provider;
+
+/// Directories bookmarked under a single character, stored in the state
+/// directory and shared with other instances over DDS.
+#[derive(Debug, Default)]
+pub struct Marks {
+	inner: BTreeMap<char, UrlBuf>,
+}
+
+impl Deref for Marks {
+	type Target = BTreeMap<char, UrlBuf>;
+
+	fn deref(&self) -> &Self::Target { &self.inner }
+}
+
+impl Marks {
+	pub fn load() -> Self {
+		let Ok(b) = std::fs::read(Self::path()) else { return Self::default() };
+
+		match serde_json::from_slice(&b) {
+			Ok(inner) => Self { inner },
+			Err(e) => {
+				error!("Failed to load marks: {e:?}");
+				Self::default()
+			}
+		}
+	}
+
+	pub fn set(&mut self, key: char, url: UrlBuf) -> bool {
+		if !self.apply(key, Some(url)) {
+			return false;
+		}
+
+		self.commit();
+		err!(Pubsub::pub_after_mark(key, self.inner.get(&key)));
+		true
+	}
+
+	pub fn remove(&mut self, key: char) -> bool {
+		if !self.apply(key, None) {
+			return false;
+		}
+
+		self.commit();
+		err!(Pubsub::pub_after_mark(key, None));
+		true
+	}
+
+	/// Sets the mark `key` to `url`, or deletes it if `url` is `None`, without
+	/// saving, as done for the changes made by another instance, which has
+	/// already saved them.
+	pub fn apply(&mut self, key: char, url: Option<UrlBuf>) -> bool {
+		match url {
+			Some(url) if self.inner.get(&key) == Some(&url) => false,
+			Some(url) => {
+				self.inner.insert(key, url);
+				true
+			}
+			None => self.inner.remove(&key).is_some(),
+		}
+	}
+
+	/// Names in the directory `url` for previewing it, directories first with a
+	/// trailing slash, up to `max` lines.
+	pub async fn preview(url: &UrlBuf, max: usize) -> Vec<String> {
+		let mut it = match provider::read_dir(url).await {
+			Ok(it) => it,
+			Err(e) => return vec![e.to_string()],
+		};
+
+		let mut names = vec![];
+		while let Ok(Some(entry)) = it.next().await {
+			let dir = entry.file_type().await.is_ok_and(|t| t.is_dir());
+			names.push((!dir, entry.name().into_string_lossy()));
+		}
+		names.sort_unstable();
+
+		let len = names.len();
+		let mut lines: Vec<_> = names
+			.into_iter()
+			.take(if len > max { max.saturating_sub(1) } else { max })
+			.map(|(file, name)| if file { name } else { name + "/" })
+			.collect();
+		if len > max {
+			lines.push(format!("... and {} more", len - lines.len()));
+		}
+		lines
+	}
+
+	fn commit(&self) {
+		let result = serde_json::to_vec(&self.inner).map_err(Into::into).and_then(|json| {
+			std::fs::create_dir_all(Xdg::state_dir())?;
+			std::fs::write(Self::path(), json)
+		});
+
+		if let Err(e) = result {
+			error!("Failed to save marks: {e:?}");
+		}
+	}
+
+	#[inline]
+	fn path() -> PathBuf { Xdg::state_dir().join("marks.json") }
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[tokio::test]
+	async fn test_preview() {
+		yazi_shared::init_tests();
+		let dir = tempfile::tempdir().unwrap();
+		for name in ["b", "a", "c"] {
+			std::fs::write(dir.path().join(name), "").unwrap();
+		}
+		std::fs::create_dir(dir.path().join("z")).unwrap();
+
+		let url: UrlBuf = dir.path().into();
+		assert_eq!(Marks::preview(&url, 10).await, ["z/", "a", "b", "c"]);
+		assert_eq!(Marks::preview(&url, 4).await, ["z/", "a", "b", "c"]);
+		assert_eq!(Marks::preview(&url, 3).await, ["z/", "a", "... and 2 more"]);
+
+		let absent: UrlBuf = dir.path().join("absent").into();
+		assert_eq!(Marks::preview(&absent, 3).await.len(), 1);
+	}
+
+	#[test]
+	fn test_apply() {
+		yazi_shared::init_tests();
+		let (a, b): (UrlBuf, UrlBuf) = ("/a".parse().unwrap(), "/b".parse().unwrap());
+
+		let mut marks = Marks::default();
+		assert!(marks.apply('a', Some(a.clone())));
+		assert!(!marks.apply('a', Some(a.clone())));
+		assert!(marks.apply('b', Some(b.clone())));
+
+		// Only the changed key is touched
+		assert!(marks.apply('a', Some(b.clone())));
+		assert_eq!(marks.iter().collect::<Vec<_>>(), [(&'a', &b), (&'b', &b)]);
+
+		assert!(marks.apply('b', None));
+		assert!(!marks.apply('b', None));
+		assert_eq!(marks.keys().collect::<Vec<_>>(), [&'a']);
+	}
+}
@@ -7,12 +7,14 @@ use yazi_fs::Splatable;
 use yazi_shared::url::{AsUrl, Url, UrlBuf};
 use yazi_watcher::Watcher;
 
-use super::{Batcher, Mimetype, Tabs, Yanked};
+use super::{Batcher, Macros, Marks, Mimetype, Tabs, Yanked};
 use crate::tab::{Folder, Tab};
 
 pub struct Mgr {
 	pub tabs:   Tabs,
 	pub yanked: Yanked,
+	pub marks:  Marks,
+	pub macros: Macros,
 
 	pub batcher:  Batcher,
 	pub watcher:  Watcher,
@@ -24,6 +26,8 @@ impl Mgr {
 		Self {
 			tabs:   Default::default(),
 			yanked: Default::default(),
+			marks:  Marks::load(),
+			macros: Macros::load(),
 
 			batcher:  Default::default(),
 			watcher:  Watcher::serve(),
@@ -1 +1 @@
-yazi_macro::mod_flat!(batcher cd displace filter find mgr mimetype open search tabs yanked);
+yazi_macro::mod_flat!(batcher cd compare displace filter find macros mgr marks mimetype open search session tabs yanked);
@@ -45,4 +45,5 @@ pub enum SearchVia {
 	Rg,
 	Rga,
 	Fd,
+	Builtin,
 }
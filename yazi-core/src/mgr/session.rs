@@ -0,0 +1,81 @@
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+use tracing::error;
+use yazi_fs::Xdg;
+use yazi_shared::url::{UrlBuf, UrlLike};
+
+use super::Tabs;
+use crate::tab::{Backstack, Preference, Tab};
+
+/// Tabs saved on exit, which can be restored on the next start.
+#[derive(Default, Deserialize, Serialize)]
+pub struct Session {
+	pub cursor: usize,
+	pub tabs:   Vec<SessionTab>,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct SessionTab {
+	pub cwd:       UrlBuf,
+	pub hovered:   Option<UrlBuf>,
+	pub pref:      Preference,
+	pub backstack: Backstack,
+	pub selected:  Vec<UrlBuf>,
+}
+
+impl Session {
+	pub fn load(name: &str) -> Option<Self> {
+		let b = std::fs::read(Self::path(name)).ok()?;
+
+		match serde_json::from_slice::<Self>(&b) {
+			Ok(s) if !s.tabs.is_empty() => Some(s),
+			Ok(_) => None,
+			Err(e) => {
+				error!("Failed to load session `{name}`: {e:?}");
+				None
+			}
+		}
+	}
+
+	pub fn save(&self, name: &str) {
+		let result = serde_json::to_vec(self).map_err(Into::into).and_then(|json| {
+			std::fs::create_dir_all(Self::dir())?;
+			std::fs::write(Self::path(name), json)
+		});
+
+		if let Err(e) = result {
+			error!("Failed to save session `{name}`: {e:?}");
+		}
+	}
+
+	#[inline]
+	fn dir() -> PathBuf { Xdg::state_dir().join("sessions") }
+
+	#[inline]
+	fn path(name: &str) -> PathBuf { Self::dir().join(format!("{name}.json")) }
+}
+
+impl From<&Tabs> for Session {
+	fn from(tabs: &Tabs) -> Self {
+		Self { cursor: tabs.cursor, tabs: tabs.iter().map(Into::into).collect() }
+	}
+}
+
+impl From<&Tab> for SessionTab {
+	fn from(tab: &Tab) -> Self {
+		// Search results can't be restored, fall back to the directory searched in
+		let (cwd, hovered) = match tab.cwd().to_regular() {
+			Ok(u) if tab.cwd().is_search() => (u, None),
+			_ => (tab.cwd().clone(), tab.hovered().map(|f| f.url.clone())),
+		};
+
+		Self {
+			cwd,
+			hovered,
+			pref: tab.pref.clone(),
+			backstack: tab.backstack.clone(),
+			selected: tab.selected.values().cloned().collect(),
+		}
+	}
+}
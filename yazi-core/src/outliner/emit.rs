@@ -0,0 +1,174 @@
+use std::fmt;
+
+use ratatui::text::Span;
+use serde::{Deserializer, de::{self, DeserializeSeed, EnumAccess, IgnoredAny, MapAccess, SeqAccess, VariantAccess, Visitor}};
+
+use super::{Node, Outliner, TOML_DATETIME};
+
+/// Renders a value into the outline while it's being deserialized, so that
+/// documents are never held in memory, and parsing stops with an error as soon
+/// as the window is filled.
+pub(super) struct Emit<'a> {
+	pub(super) me:    &'a mut Outliner,
+	pub(super) depth: usize,
+	pub(super) spans: Vec<Span<'static>>,
+}
+
+impl<'de> DeserializeSeed<'de> for Emit<'_> {
+	type Value = ();
+
+	fn deserialize<D>(self, deserializer: D) -> Result<(), D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		deserializer.deserialize_any(self)
+	}
+}
+
+impl Emit<'_> {
+	#[inline]
+	fn expand(&self) -> bool { self.depth < self.me.depth }
+
+	fn child(&mut self, spans: Vec<Span<'static>>) -> Emit<'_> {
+		Emit { me: self.me, depth: self.depth + 1, spans }
+	}
+
+	/// Pushes the line, returns its index if it's within the window.
+	fn push<E: de::Error>(&mut self, span: Span<'static>) -> Result<Option<usize>, E> {
+		let mut spans = std::mem::take(&mut self.spans);
+		spans.insert(0, Span::raw("  ".repeat(self.depth)));
+		spans.push(span);
+
+		let at = self.me.lines.len();
+		match self.me.push(spans) {
+			Ok(true) => Ok((self.me.lines.len() > at).then_some(at)),
+			Ok(false) => Err(E::custom("outline window filled")),
+			Err(e) => Err(E::custom(e)),
+		}
+	}
+
+	fn scalar<E: de::Error>(mut self, node: Node) -> Result<(), E> {
+		self.push(Outliner::scalar(&node)).map(drop)
+	}
+
+	/// Fills in the size of an expanded container once it's known.
+	fn close(&mut self, at: Option<usize>, seq: bool, len: usize) {
+		if let Some(span) = at.and_then(|i| self.me.lines[i].spans.last_mut()) {
+			*span = Outliner::summary(seq, Some(len), true);
+		}
+	}
+}
+
+impl<'de> Visitor<'de> for Emit<'_> {
+	type Value = ();
+
+	fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+		formatter.write_str("any value")
+	}
+
+	fn visit_bool<E: de::Error>(self, v: bool) -> Result<(), E> { self.scalar(Node::Bool(v)) }
+
+	fn visit_i64<E: de::Error>(self, v: i64) -> Result<(), E> { self.scalar(Node::Int(v.into())) }
+
+	fn visit_i128<E: de::Error>(self, v: i128) -> Result<(), E> { self.scalar(Node::Int(v)) }
+
+	fn visit_u64<E: de::Error>(self, v: u64) -> Result<(), E> { self.scalar(Node::Int(v.into())) }
+
+	fn visit_u128<E: de::Error>(self, v: u128) -> Result<(), E> {
+		self.scalar(i128::try_from(v).map_or(Node::Float(v as f64), Node::Int))
+	}
+
+	fn visit_f64<E: de::Error>(self, v: f64) -> Result<(), E> { self.scalar(Node::Float(v)) }
+
+	fn visit_str<E: de::Error>(self, v: &str) -> Result<(), E> { self.scalar(Node::Str(v.to_owned())) }
+
+	fn visit_string<E: de::Error>(self, v: String) -> Result<(), E> { self.scalar(Node::Str(v)) }
+
+	fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<(), E> {
+		self.scalar(Node::Str(String::from_utf8_lossy(v).into_owned()))
+	}
+
+	fn visit_none<E: de::Error>(self) -> Result<(), E> { self.scalar(Node::Null) }
+
+	fn visit_unit<E: de::Error>(self) -> Result<(), E> { self.scalar(Node::Null) }
+
+	fn visit_some<D>(self, deserializer: D) -> Result<(), D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		self.deserialize(deserializer)
+	}
+
+	fn visit_newtype_struct<D>(self, deserializer: D) -> Result<(), D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		self.deserialize(deserializer)
+	}
+
+	fn visit_seq<A>(mut self, mut seq: A) -> Result<(), A::Error>
+	where
+		A: SeqAccess<'de>,
+	{
+		let mut len = 0;
+		if !self.expand() {
+			while seq.next_element::<IgnoredAny>()?.is_some() {
+				len += 1;
+			}
+			return self.push(Outliner::summary(true, Some(len), false)).map(drop);
+		}
+
+		let at = self.push(Outliner::summary(true, None, true))?;
+		while seq.next_element_seed(self.child(vec![Span::styled("- ", Outliner::DIM)]))?.is_some() {
+			len += 1;
+		}
+		self.close(at, true, len);
+		Ok(())
+	}
+
+	fn visit_map<A>(mut self, mut map: A) -> Result<(), A::Error>
+	where
+		A: MapAccess<'de>,
+	{
+		let Some(first) = map.next_key::<Node>()? else {
+			return self.push(Outliner::summary(false, Some(0), true)).map(drop);
+		};
+		if matches!(&first, Node::Str(k) if k == TOML_DATETIME) {
+			return self.scalar(map.next_value()?);
+		}
+
+		let mut len = 1;
+		if !self.expand() {
+			map.next_value::<IgnoredAny>()?;
+			while map.next_entry::<IgnoredAny, IgnoredAny>()?.is_some() {
+				len += 1;
+			}
+			return self.push(Outliner::summary(false, Some(len), false)).map(drop);
+		}
+
+		let at = self.push(Outliner::summary(false, None, true))?;
+		let mut key = Some(first);
+		while let Some(k) = key {
+			map.next_value_seed(self.child(vec![Outliner::key(&k), Span::styled(": ", Outliner::DIM)]))?;
+			key = map.next_key()?;
+			len += key.is_some() as usize;
+		}
+		self.close(at, false, len);
+		Ok(())
+	}
+
+	// Tagged values in YAML, e.g. `!Tag value`
+	fn visit_enum<A>(mut self, data: A) -> Result<(), A::Error>
+	where
+		A: EnumAccess<'de>,
+	{
+		let (tag, variant) = data.variant::<Node>()?;
+		if !self.expand() {
+			variant.newtype_variant::<IgnoredAny>()?;
+			return self.push(Outliner::summary(false, Some(1), false)).map(drop);
+		}
+
+		self.push(Outliner::summary(false, Some(1), true))?;
+		variant.newtype_variant_seed(self.child(vec![Outliner::key(&tag), Span::styled(": ", Outliner::DIM)]))
+	}
+}
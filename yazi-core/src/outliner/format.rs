@@ -0,0 +1,50 @@
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(super) enum Format {
+	Json,
+	Ndjson,
+	Yaml,
+	Toml,
+}
+
+impl Format {
+	/// Picks the format by the MIME type, falling back to the extension for
+	/// generic ones like `text/plain`.
+	pub(super) fn new(mime: &str, ext: Option<&str>) -> Self {
+		let mime = mime.to_ascii_lowercase();
+		let (_, sub) = mime.split_once('/').unwrap_or_default();
+		match sub.trim_start_matches("x-") {
+			"json" => return Self::Json,
+			"ndjson" | "jsonl" | "json-seq" => return Self::Ndjson,
+			"yaml" => return Self::Yaml,
+			"toml" => return Self::Toml,
+			_ => {}
+		}
+
+		match ext.map(str::to_ascii_lowercase).as_deref() {
+			Some("jsonl" | "ndjson") => Self::Ndjson,
+			Some("yaml" | "yml") => Self::Yaml,
+			Some("toml") => Self::Toml,
+			_ => Self::Json,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_new() {
+		let none = None;
+		assert_eq!(Format::new("application/json", none), Format::Json);
+		assert_eq!(Format::new("application/x-ndjson", none), Format::Ndjson);
+		assert_eq!(Format::new("application/ndjson", Some("json")), Format::Ndjson);
+		assert_eq!(Format::new("application/yaml", none), Format::Yaml);
+		assert_eq!(Format::new("text/x-toml", none), Format::Toml);
+
+		// Generic ones go by the extension
+		assert_eq!(Format::new("text/plain", Some("YML")), Format::Yaml);
+		assert_eq!(Format::new("text/plain", Some("jsonl")), Format::Ndjson);
+		assert_eq!(Format::new("", none), Format::Json);
+	}
+}
@@ -0,0 +1,127 @@
+use std::fmt;
+
+use serde::{Deserialize, Deserializer, de::{EnumAccess, MapAccess, SeqAccess, VariantAccess, Visitor}};
+
+/// The key `toml` uses to pass a datetime through serde.
+pub(super) const TOML_DATETIME: &str = "$__toml_private_datetime";
+
+/// A document node that keeps its keys in their original order, deserializable
+/// from any self-describing format.
+#[derive(Debug, PartialEq)]
+pub(super) enum Node {
+	Null,
+	Bool(bool),
+	Int(i128),
+	Float(f64),
+	Str(String),
+	Seq(Vec<Self>),
+	Map(Vec<(Self, Self)>),
+}
+
+impl<'de> Deserialize<'de> for Node {
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		deserializer.deserialize_any(NodeVisitor)
+	}
+}
+
+struct NodeVisitor;
+
+impl<'de> Visitor<'de> for NodeVisitor {
+	type Value = Node;
+
+	fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+		formatter.write_str("any value")
+	}
+
+	fn visit_bool<E>(self, v: bool) -> Result<Node, E> { Ok(Node::Bool(v)) }
+
+	fn visit_i64<E>(self, v: i64) -> Result<Node, E> { Ok(Node::Int(v.into())) }
+
+	fn visit_i128<E>(self, v: i128) -> Result<Node, E> { Ok(Node::Int(v)) }
+
+	fn visit_u64<E>(self, v: u64) -> Result<Node, E> { Ok(Node::Int(v.into())) }
+
+	fn visit_u128<E>(self, v: u128) -> Result<Node, E> {
+		Ok(i128::try_from(v).map_or(Node::Float(v as f64), Node::Int))
+	}
+
+	fn visit_f64<E>(self, v: f64) -> Result<Node, E> { Ok(Node::Float(v)) }
+
+	fn visit_str<E>(self, v: &str) -> Result<Node, E> { Ok(Node::Str(v.to_owned())) }
+
+	fn visit_string<E>(self, v: String) -> Result<Node, E> { Ok(Node::Str(v)) }
+
+	fn visit_bytes<E>(self, v: &[u8]) -> Result<Node, E> {
+		Ok(Node::Str(String::from_utf8_lossy(v).into_owned()))
+	}
+
+	fn visit_none<E>(self) -> Result<Node, E> { Ok(Node::Null) }
+
+	fn visit_unit<E>(self) -> Result<Node, E> { Ok(Node::Null) }
+
+	fn visit_some<D>(self, deserializer: D) -> Result<Node, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		Node::deserialize(deserializer)
+	}
+
+	fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Node, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		Node::deserialize(deserializer)
+	}
+
+	fn visit_seq<A>(self, mut seq: A) -> Result<Node, A::Error>
+	where
+		A: SeqAccess<'de>,
+	{
+		let mut items = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(4096));
+		while let Some(item) = seq.next_element()? {
+			items.push(item);
+		}
+		Ok(Node::Seq(items))
+	}
+
+	fn visit_map<A>(self, mut map: A) -> Result<Node, A::Error>
+	where
+		A: MapAccess<'de>,
+	{
+		let mut entries = Vec::with_capacity(map.size_hint().unwrap_or(0).min(4096));
+		while let Some(entry) = map.next_entry()? {
+			entries.push(entry);
+		}
+
+		match entries.pop() {
+			Some((Node::Str(k), v)) if k == TOML_DATETIME && entries.is_empty() => Ok(v),
+			Some(last) => {
+				entries.push(last);
+				Ok(Node::Map(entries))
+			}
+			None => Ok(Node::Map(entries)),
+		}
+	}
+
+	// Tagged values in YAML, e.g. `!Tag value`
+	fn visit_enum<A>(self, data: A) -> Result<Node, A::Error>
+	where
+		A: EnumAccess<'de>,
+	{
+		let (tag, variant) = data.variant::<Node>()?;
+		Ok(Node::Map(vec![(tag, variant.newtype_variant()?)]))
+	}
+}
+
+impl Node {
+	pub(super) fn len(&self) -> Option<usize> {
+		match self {
+			Self::Seq(v) => Some(v.len()),
+			Self::Map(v) => Some(v.len()),
+			_ => None,
+		}
+	}
+}
@@ -0,0 +1,314 @@
+use std::io::{BufRead, BufReader};
+
+use anyhow::{Result, anyhow};
+use ratatui::{layout::Size, style::{Color, Style}, text::{Line, Span, Text}};
+use serde::de::DeserializeSeed;
+use tokio_util::io::SyncIoBridge;
+use yazi_runner::previewer::PeekError;
+use yazi_shared::{Id, Ids, url::{UrlBuf, UrlLike}};
+use yazi_vfs::provider;
+
+use super::{Emit, Format, Node};
+
+static INCR: Ids = Ids::new();
+
+/// Renders structured documents (JSON, NDJSON, YAML and TOML) as an indented
+/// tree, with containers nested deeper than `depth` collapsed.
+pub struct Outliner {
+	format: Format,
+
+	skip:             usize,
+	size:             Size,
+	pub(super) depth: usize,
+	ticket:           Id,
+
+	i:                usize,
+	pub(super) lines: Vec<Line<'static>>,
+}
+
+impl Outliner {
+	/// Levels expanded when no depth is given.
+	pub const DEPTH: usize = 3;
+
+	pub async fn oneshot<U>(
+		url: U,
+		mime: &str,
+		skip: usize,
+		size: Size,
+		depth: Option<usize>,
+	) -> Result<Text<'static>, PeekError>
+	where
+		U: Into<UrlBuf>,
+	{
+		// Read through the provider, so that remote files only need to be fetched
+		// as far as the lines being outlined
+		let url = url.into();
+		let file = provider::open(&url).await.map_err(anyhow::Error::from)?;
+		let reader = BufReader::with_capacity(64 * 1024, SyncIoBridge::new(file));
+
+		let me = Self {
+			format: Format::new(mime, url.ext().map(|e| e.to_string_lossy()).as_deref()),
+
+			skip,
+			size,
+			depth: depth.unwrap_or(Self::DEPTH),
+			ticket: INCR.current(),
+
+			i: 0,
+			lines: Vec::with_capacity(size.height as usize),
+		};
+
+		tokio::task::spawn_blocking(move || me.outline(reader)).await?
+	}
+
+	pub fn abort() { INCR.next(); }
+
+	fn outline<R: BufRead>(mut self, reader: R) -> Result<Text<'static>, PeekError> {
+		self.parse(reader)?;
+
+		if self.skip > 0 && self.i < self.skip + self.size.height as usize {
+			return Err(PeekError::Exceeded(self.i.saturating_sub(self.size.height as _)));
+		}
+
+		Ok(Text::from(self.lines))
+	}
+
+	fn parse<R: BufRead>(&mut self, reader: R) -> Result<()> {
+		let Err(e) = self.parse_impl(reader) else { return Ok(()) };
+		self.ensure_not_cancelled()?;
+
+		// Parsing is stopped by an error once the window is filled
+		if self.filled() { Ok(()) } else { Err(e) }
+	}
+
+	fn parse_impl<R: BufRead>(&mut self, reader: R) -> Result<()> {
+		match self.format {
+			Format::Json => {
+				let mut de = serde_json::Deserializer::from_reader(reader);
+				self.emit().deserialize(&mut de)?;
+				de.end()?;
+			}
+			Format::Ndjson => {
+				for line in reader.lines() {
+					let line = line?;
+					if !line.trim().is_empty() {
+						self.emit().deserialize(&mut serde_json::Deserializer::from_str(&line))?;
+					}
+				}
+			}
+			Format::Yaml => {
+				for de in serde_yaml_ng::Deserializer::from_reader(reader) {
+					self.emit().deserialize(de)?;
+				}
+			}
+			Format::Toml => {
+				let s = std::io::read_to_string(reader)?;
+				self.emit().deserialize(toml::Deserializer::parse(&s)?)?;
+			}
+		}
+		Ok(())
+	}
+
+	#[inline]
+	fn emit(&mut self) -> Emit<'_> { Emit { me: self, depth: 0, spans: vec![] } }
+
+	pub(super) fn push(&mut self, spans: Vec<Span<'static>>) -> Result<bool> {
+		self.ensure_not_cancelled()?;
+
+		self.i += 1;
+		if self.filled() {
+			return Ok(false);
+		} else if self.i > self.skip {
+			self.lines.push(Line::from(spans));
+		}
+		Ok(true)
+	}
+
+	#[inline]
+	fn filled(&self) -> bool { self.i > self.skip + self.size.height as usize }
+
+	#[inline]
+	fn ensure_not_cancelled(&self) -> Result<(), PeekError> {
+		if self.ticket != INCR.current() { Err(anyhow!("Outlining cancelled"))? } else { Ok(()) }
+	}
+}
+
+impl Outliner {
+	pub(super) const DIM: Style = Style::new().fg(Color::DarkGray);
+
+	pub(super) fn key(node: &Node) -> Span<'static> {
+		let Node::Str(s) = node else {
+			return Self::scalar(node);
+		};
+
+		let s = if s.is_empty() || s.trim() != s || s.chars().any(char::is_control) {
+			format!("{s:?}")
+		} else {
+			s.clone()
+		};
+		Span::styled(s, Style::new().fg(Color::Blue))
+	}
+
+	pub(super) fn scalar(node: &Node) -> Span<'static> {
+		match node {
+			Node::Null => Span::styled("null", Self::DIM),
+			Node::Bool(b) => Span::styled(b.to_string(), Style::new().fg(Color::Magenta)),
+			Node::Int(n) => Span::styled(n.to_string(), Style::new().fg(Color::Yellow)),
+			Node::Float(n) => Span::styled(format!("{n:?}"), Style::new().fg(Color::Yellow)),
+			Node::Str(s) => Span::styled(format!("{s:?}"), Style::new().fg(Color::Green)),
+			Node::Seq(_) | Node::Map(_) => Self::summary(matches!(node, Node::Seq(_)), node.len(), false),
+		}
+	}
+
+	/// Summary of a container, whose size is unknown until it's been read.
+	pub(super) fn summary(seq: bool, len: Option<usize>, expand: bool) -> Span<'static> {
+		let (open, close, unit) = if seq { ('[', ']', "item") } else { ('{', '}', "key") };
+		let arrow = if expand { '▾' } else { '▸' };
+
+		let s = match len {
+			Some(0) => format!("{open}{close}"),
+			Some(n) => format!("{arrow} {open}{n} {unit}{}{close}", if n == 1 { "" } else { "s" }),
+			None => format!("{arrow} {open}…{close}"),
+		};
+		Span::styled(s, Self::DIM)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use serde::Deserialize;
+
+	use super::*;
+
+	fn outline(json: &str, depth: usize) -> Vec<String> {
+		let mut me = Outliner {
+			format: Format::Json,
+			skip: 0,
+			size: Size::new(80, 100),
+			depth,
+			ticket: INCR.current(),
+			i: 0,
+			lines: vec![],
+		};
+		me.emit().deserialize(&mut serde_json::Deserializer::from_str(json)).unwrap();
+		me.lines.iter().map(|l| l.to_string()).collect()
+	}
+
+	async fn oneshot(content: &str, mime: &str, skip: usize, height: u16) -> Result<Vec<String>, PeekError> {
+		yazi_shared::init_tests();
+		let tmp = tempfile::NamedTempFile::new().unwrap();
+		std::fs::write(tmp.path(), content).unwrap();
+
+		let text = Outliner::oneshot(tmp.path().to_owned(), mime, skip, Size::new(80, height), None).await?;
+		Ok(text.lines.iter().map(|l| l.to_string()).collect())
+	}
+
+	#[test]
+	fn test_outline() {
+		let json =
+			r#"{"name":"yazi","tags":["a",1,null],"deps":{"serde":{"version":"1.0"}},"empty":[]}"#;
+
+		assert_eq!(outline(json, usize::MAX), [
+			"▾ {4 keys}",
+			"  name: \"yazi\"",
+			"  tags: ▾ [3 items]",
+			"    - \"a\"",
+			"    - 1",
+			"    - null",
+			"  deps: ▾ {1 key}",
+			"    serde: ▾ {1 key}",
+			"      version: \"1.0\"",
+			"  empty: []",
+		]);
+
+		assert_eq!(outline(json, 2), [
+			"▾ {4 keys}",
+			"  name: \"yazi\"",
+			"  tags: ▾ [3 items]",
+			"    - \"a\"",
+			"    - 1",
+			"    - null",
+			"  deps: ▾ {1 key}",
+			"    serde: ▸ {1 key}",
+			"  empty: []",
+		]);
+	}
+
+	#[tokio::test]
+	async fn test_skip() {
+		let json = r#"{"a":[1,2,3],"b":{"c":{"d":{"e":true}}}}"#;
+		assert_eq!(oneshot(json, "application/json", 2, 3).await.unwrap(), [
+			"    - 1",
+			"    - 2",
+			"    - 3",
+		]);
+
+		// Collapsed beyond the default depth
+		assert_eq!(oneshot(json, "application/json", 5, 3).await.unwrap(), [
+			"  b: ▾ {1 key}",
+			"    c: ▾ {1 key}",
+			"      d: ▸ {1 key}",
+		]);
+
+		// Containers cut off by the window don't know their size yet
+		assert_eq!(oneshot(json, "application/json", 0, 2).await.unwrap(), ["▾ {…}", "  a: ▾ […]"]);
+	}
+
+	#[tokio::test]
+	async fn test_exceeded() {
+		let json = r#"{"a":[1,2,3]}"#;
+		assert!(matches!(oneshot(json, "application/json", 4, 3).await, Err(PeekError::Exceeded(2))));
+		assert!(matches!(oneshot(json, "application/json", 9, 3).await, Err(PeekError::Exceeded(2))));
+		assert_eq!(oneshot(json, "application/json", 2, 3).await.unwrap(), ["    - 1", "    - 2", "    - 3"]);
+	}
+
+	#[tokio::test]
+	async fn test_stop() {
+		// Parsing stops once the window is filled, before reaching the broken part
+		let broken = "[1, 2, 3, oops";
+		assert_eq!(oneshot(broken, "application/json", 0, 3).await.unwrap(), [
+			"▾ […]",
+			"  - 1",
+			"  - 2",
+		]);
+		assert!(oneshot(broken, "application/json", 0, 10).await.is_err());
+
+		// NDJSON by its MIME type, without the extension
+		let ndjson = "{\"a\":1}\n\n[true]\n";
+		assert_eq!(oneshot(ndjson, "application/x-ndjson", 0, 10).await.unwrap(), [
+			"▾ {1 key}",
+			"  a: 1",
+			"▾ [1 item]",
+			"  - true",
+		]);
+	}
+
+	#[test]
+	fn test_node_formats() {
+		let toml = "title = \"x\"\nwhen = 1979-05-27T07:32:00Z\n[owner]\nname = \"Tom\"\n";
+		let node: Node = toml::from_str(toml).unwrap();
+		assert_eq!(
+			node,
+			Node::Map(vec![
+				(Node::Str("title".to_owned()), Node::Str("x".to_owned())),
+				(Node::Str("when".to_owned()), Node::Str("1979-05-27T07:32:00Z".to_owned())),
+				(
+					Node::Str("owner".to_owned()),
+					Node::Map(vec![(Node::Str("name".to_owned()), Node::Str("Tom".to_owned()))])
+				),
+			])
+		);
+
+		let yaml = "b: 1\na: [true, ~]\n---\n- 2.5\n";
+		let docs: Vec<Node> = serde_yaml_ng::Deserializer::from_str(yaml)
+			.map(|de| Node::deserialize(de).unwrap())
+			.collect();
+		assert_eq!(docs, [
+			Node::Map(vec![
+				(Node::Str("b".to_owned()), Node::Int(1)),
+				(Node::Str("a".to_owned()), Node::Seq(vec![Node::Bool(true), Node::Null])),
+			]),
+			Node::Seq(vec![Node::Float(2.5)]),
+		]);
+	}
+}
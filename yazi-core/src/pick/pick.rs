@@ -6,6 +6,7 @@ use yazi_widgets::Scrollable;
 pub struct Pick {
 	pub title:    String,
 	pub items:    Vec<String>,
+	pub previews: Vec<Vec<String>>,
 	pub position: Position,
 
 	pub offset:   usize,
@@ -18,6 +19,9 @@ pub struct Pick {
 impl Pick {
 	pub fn title(&self) -> &str { &self.title }
 
+	/// Preview of the item under the cursor, if the items have previews.
+	pub fn preview(&self) -> Option<&[String]> { self.previews.get(self.cursor).map(Vec::as_slice) }
+
 	pub fn window(&self) -> impl Iterator<Item = (usize, &str)> {
 		self.items.iter().map(AsRef::as_ref).enumerate().skip(self.offset).take(self.limit())
 	}
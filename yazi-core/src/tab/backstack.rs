@@ -1,11 +1,31 @@
+use serde::{Deserialize, Serialize};
 use yazi_shared::url::{Url, UrlBuf};
 
-#[derive(Default)]
+#[derive(Clone, Default, Deserialize, Serialize)]
+#[serde(try_from = "BackstackShadow")]
 pub struct Backstack {
 	cursor: usize,
 	stack:  Vec<UrlBuf>,
 }
 
+#[derive(Deserialize)]
+struct BackstackShadow {
+	cursor: usize,
+	stack:  Vec<UrlBuf>,
+}
+
+impl TryFrom<BackstackShadow> for Backstack {
+	type Error = &'static str;
+
+	fn try_from(BackstackShadow { cursor, stack }: BackstackShadow) -> Result<Self, Self::Error> {
+		if cursor < stack.len().max(1) {
+			Ok(Self { cursor, stack })
+		} else {
+			Err("backstack cursor out of bounds")
+		}
+	}
+}
+
 impl Backstack {
 	pub fn push(&mut self, url: Url) {
 		if self.stack.is_empty() {
@@ -86,4 +106,19 @@ mod tests {
 		assert_eq!(bs.shift_forward(), None);
 		assert_eq!(bs.shift_backward().unwrap(), Url::regular("2"));
 	}
+
+	#[test]
+	fn test_backstack_serde() {
+		let mut bs: Backstack = Backstack::default();
+		bs.push(Url::regular("1"));
+		bs.push(Url::regular("2"));
+		bs.shift_backward();
+
+		let mut bs: Backstack = serde_json::from_slice(&serde_json::to_vec(&bs).unwrap()).unwrap();
+		assert_eq!(bs.current().unwrap(), Url::regular("1"));
+		assert_eq!(bs.shift_forward().unwrap(), Url::regular("2"));
+
+		let json = r#"{"cursor":1,"stack":[]}"#;
+		assert!(serde_json::from_str::<Backstack>(json).is_err());
+	}
 }
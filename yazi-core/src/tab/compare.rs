@@ -0,0 +1,192 @@
+use std::{io, time::UNIX_EPOCH};
+
+use hashbrown::HashMap;
+use strum::IntoStaticStr;
+use tokio::io::AsyncReadExt;
+use yazi_fs::File;
+use yazi_shared::{Id, path::{PathBufDyn, PathDyn}, url::UrlBuf};
+use yazi_vfs::provider;
+
+use crate::tab::Folder;
+
+/// Status of a file compared to the one of the same name in the other tab.
+#[derive(Clone, Copy, Debug, Eq, IntoStaticStr, PartialEq)]
+#[strum(serialize_all = "kebab-case")]
+pub enum CompareStatus {
+	Unique,
+	Identical,
+	Newer,
+	Older,
+	Differing,
+}
+
+impl CompareStatus {
+	fn flip(self) -> Self {
+		match self {
+			Self::Newer => Self::Older,
+			Self::Older => Self::Newer,
+			s => s,
+		}
+	}
+}
+
+#[derive(Clone, Debug)]
+pub struct Compare {
+	/// The other tab.
+	pub peer:     Id,
+	/// The directory this tab was in when compared.
+	pub cwd:      UrlBuf,
+	pub statuses: HashMap<PathBufDyn, CompareStatus>,
+}
+
+impl Compare {
+	/// Status of a file in `folder`, `None` if it's not the compared one.
+	pub fn get(&self, folder: &Folder, urn: PathDyn) -> Option<CompareStatus> {
+		if folder.url == self.cwd { self.statuses.get(&urn).copied() } else { None }
+	}
+
+	pub fn differences(&self) -> impl Iterator<Item = &PathBufDyn> {
+		self.statuses.iter().filter(|&(_, &s)| s != CompareStatus::Identical).map(|(urn, _)| urn)
+	}
+
+	/// Classifies the files of two directories by their size and mtime, or by
+	/// content if `content` is set and sizes are equal.
+	///
+	/// Directories present on both sides are left out, as they aren't descended.
+	pub async fn classify(
+		left: Vec<File>,
+		right: Vec<File>,
+		content: bool,
+	) -> (HashMap<PathBufDyn, CompareStatus>, HashMap<PathBufDyn, CompareStatus>) {
+		let mut others: HashMap<PathBufDyn, File> =
+			right.into_iter().map(|f| (f.urn().into(), f)).collect();
+
+		let (mut l, mut r) = (HashMap::new(), HashMap::new());
+		for a in left {
+			let urn: PathBufDyn = a.urn().into();
+			let Some(b) = others.remove(&urn) else {
+				l.insert(urn, CompareStatus::Unique);
+				continue;
+			};
+
+			let status = match (a.is_dir(), b.is_dir()) {
+				(true, true) => continue,
+				(false, false) => Self::status(&a, &b, content).await,
+				_ => CompareStatus::Differing,
+			};
+			r.insert(urn.clone(), status.flip());
+			l.insert(urn, status);
+		}
+
+		r.extend(others.into_keys().map(|urn| (urn, CompareStatus::Unique)));
+		(l, r)
+	}
+
+	async fn status(a: &File, b: &File, content: bool) -> CompareStatus {
+		// Whole seconds, as remote providers like SFTP don't keep finer precision
+		let secs =
+			|f: &File| f.mtime.and_then(|t| t.duration_since(UNIX_EPOCH).ok()).map(|d| d.as_secs());
+		let (ma, mb) = (secs(a), secs(b));
+
+		if a.len == b.len {
+			let same =
+				if content { Self::same_content(&a.url, &b.url).await.unwrap_or(false) } else { ma == mb };
+			if same {
+				return CompareStatus::Identical;
+			}
+		}
+
+		match ma.cmp(&mb) {
+			std::cmp::Ordering::Greater => CompareStatus::Newer,
+			std::cmp::Ordering::Less => CompareStatus::Older,
+			std::cmp::Ordering::Equal => CompareStatus::Differing,
+		}
+	}
+
+	async fn same_content(a: &UrlBuf, b: &UrlBuf) -> io::Result<bool> {
+		let (mut a, mut b) = (provider::open(a).await?, provider::open(b).await?);
+		let (mut x, mut y) = (vec![0; 64 * 1024], vec![0; 64 * 1024]);
+
+		loop {
+			let n = Self::fill(&mut a, &mut x).await?;
+			if n != Self::fill(&mut b, &mut y).await? || x[..n] != y[..n] {
+				return Ok(false);
+			} else if n == 0 {
+				return Ok(true);
+			}
+		}
+	}
+
+	/// Reads until the buffer is full or EOF, so chunks of both files line up.
+	async fn fill(r: &mut (impl AsyncReadExt + Unpin), buf: &mut [u8]) -> io::Result<usize> {
+		let mut n = 0;
+		while n < buf.len() {
+			match r.read(&mut buf[n..]).await? {
+				0 => break,
+				m => n += m,
+			}
+		}
+		Ok(n)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::time::Duration;
+
+	use yazi_fs::cha::ChaType;
+	use yazi_shared::url::Url;
+
+	use super::*;
+
+	fn file(path: &str, dir: bool, len: u64, mtime: u64) -> File {
+		let mut f =
+			File::from_dummy(Url::regular(path), Some(if dir { ChaType::Dir } else { ChaType::File }));
+		f.cha.len = len;
+		f.cha.mtime = Some(UNIX_EPOCH + Duration::from_millis(mtime));
+		f
+	}
+
+	#[tokio::test]
+	async fn test_classify() {
+		let left = vec![
+			file("/l/same", false, 1, 1000),
+			file("/l/same-sec", false, 1, 1500),
+			file("/l/newer", false, 1, 3000),
+			file("/l/older", false, 2, 1000),
+			file("/l/differing", false, 1, 1000),
+			file("/l/kind", true, 0, 1000),
+			file("/l/dir", true, 0, 1000),
+			file("/l/only-left", false, 1, 1000),
+		];
+		let right = vec![
+			file("/r/same", false, 1, 1000),
+			file("/r/same-sec", false, 1, 1000),
+			file("/r/newer", false, 1, 1000),
+			file("/r/older", false, 1, 2000),
+			file("/r/differing", false, 2, 1000),
+			file("/r/kind", false, 0, 1000),
+			file("/r/dir", true, 0, 5000),
+			file("/r/only-right", false, 1, 1000),
+		];
+
+		let (l, r) = Compare::classify(left, right, false).await;
+		let get = |m: &HashMap<PathBufDyn, CompareStatus>, s: &str| {
+			m.get(&PathBufDyn::from(Url::regular(s).urn())).copied()
+		};
+
+		use CompareStatus::*;
+		assert_eq!(get(&l, "same"), Some(Identical));
+		assert_eq!(get(&l, "same-sec"), Some(Identical));
+		assert_eq!(get(&l, "newer"), Some(Newer));
+		assert_eq!(get(&r, "newer"), Some(Older));
+		assert_eq!(get(&l, "older"), Some(Older));
+		assert_eq!(get(&r, "older"), Some(Newer));
+		assert_eq!(get(&l, "differing"), Some(Differing));
+		assert_eq!(get(&r, "kind"), Some(Differing));
+		assert_eq!(get(&l, "dir"), None);
+		assert_eq!(get(&l, "only-left"), Some(Unique));
+		assert_eq!(get(&r, "only-right"), Some(Unique));
+		assert_eq!(get(&l, "only-right"), None);
+	}
+}
@@ -18,9 +18,9 @@ struct FinderLock {
 }
 
 impl Finder {
-	pub fn new(s: &str, case: FilterCase) -> Result<Self> {
+	pub fn new(s: &str, case: FilterCase, fuzzy: bool) -> Result<Self> {
 		Ok(Self {
-			filter:  Filter::new(s, case)?,
+			filter:  if fuzzy { Filter::fuzzy(s, case, false) } else { Filter::new(s, case)? },
 			matched: Default::default(),
 			lock:    Default::default(),
 		})
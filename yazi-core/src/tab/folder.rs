@@ -3,7 +3,7 @@ use std::mem;
 use yazi_config::{LAYOUT, YAZI};
 use yazi_dds::Pubsub;
 use yazi_fs::{File, Files, FilesOp, FolderStage, cha::Cha};
-use yazi_macro::err;
+use yazi_macro::{err, render};
 use yazi_shared::{Id, path::{AsPath, PathBufDyn, PathDyn}, url::UrlBuf};
 use yazi_widgets::{Scrollable, Step};
 
@@ -69,6 +69,8 @@ impl Folder {
 			FilesOp::Part(_, files, ticket) => self.files.update_part(files, ticket),
 			FilesOp::Done(..) => {}
 			FilesOp::Size(_, sizes) => self.files.update_size(sizes),
+			FilesOp::Rank(_, plugin, revision, ranks) => self.files.update_ranks(plugin, revision, ranks),
+			FilesOp::Git(_, git) => render!(self.files.update_git(git)),
 			FilesOp::IOErr(..) => self.files.update_ioerr(),
 
 			FilesOp::Creating(_, files) => self.files.update_creating(files),
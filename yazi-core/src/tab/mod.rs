@@ -1 +1 @@
-yazi_macro::mod_flat!(backstack finder folder history mode preference preview preview_lock selected tab);
+yazi_macro::mod_flat!(backstack compare finder folder history mode preference preview preview_lock selected tab);
@@ -1,9 +1,11 @@
 use std::sync::Arc;
 
+use serde::{Deserialize, Serialize};
 use yazi_config::YAZI;
-use yazi_fs::{FilesSorter, SortBy, SortFallback};
+use yazi_fs::{FilesSorter, SortFallback, SortSpec};
 
-#[derive(Clone, PartialEq)]
+#[derive(Clone, Deserialize, PartialEq, Serialize)]
+#[serde(default)]
 pub struct Preference {
 	// Display
 	pub name:        String,
@@ -11,7 +13,7 @@ pub struct Preference {
 	pub show_hidden: bool,
 
 	// Sorting
-	pub sort_by:        SortBy,
+	pub sort_by:        SortSpec,
 	pub sort_sensitive: bool,
 	pub sort_reverse:   bool,
 	pub sort_dir_first: bool,
@@ -28,7 +30,7 @@ impl Default for Preference {
 			show_hidden: YAZI.mgr.show_hidden.get(),
 
 			// Sorting
-			sort_by:        YAZI.mgr.sort_by.get(),
+			sort_by:        (**YAZI.mgr.sort_by.load()).clone(),
 			sort_sensitive: YAZI.mgr.sort_sensitive.get(),
 			sort_reverse:   YAZI.mgr.sort_reverse.get(),
 			sort_dir_first: YAZI.mgr.sort_dir_first.get(),
@@ -41,7 +43,7 @@ impl Default for Preference {
 impl From<&Preference> for FilesSorter {
 	fn from(value: &Preference) -> Self {
 		Self {
-			by:        value.sort_by,
+			by:        value.sort_by.clone(),
 			sensitive: value.sort_sensitive,
 			reverse:   value.sort_reverse,
 			dir_first: value.sort_dir_first,
@@ -10,7 +10,7 @@ use yazi_runner::{RUNNER, previewer::{PeekError, PeekJob}};
 use yazi_shared::{pool::Symbol, url::{UrlBuf, UrlLike}};
 use yazi_vfs::{VfsFiles, VfsFilesOp};
 
-use crate::{AppProxy, Highlighter, MgrProxy, tab::PreviewLock};
+use crate::{AppProxy, Highlighter, MgrProxy, Outliner, tab::PreviewLock};
 
 #[derive(Default)]
 pub struct Preview {
@@ -82,6 +82,7 @@ impl Preview {
 	pub fn abort(&mut self) {
 		self.handle.take().map(|ct| ct.abort());
 		Highlighter::abort();
+		Outliner::abort();
 	}
 
 	pub fn reset(&mut self) {
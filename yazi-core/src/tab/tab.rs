@@ -8,7 +8,7 @@ use yazi_emulator::Dimension;
 use yazi_fs::File;
 use yazi_shared::{Id, Ids, url::{UrlBuf, UrlLike}};
 
-use super::{Backstack, Finder, Folder, History, Mode, Preference, Preview};
+use super::{Backstack, Compare, Finder, Folder, History, Mode, Preference, Preview};
 use crate::{spot::Spot, tab::Selected};
 
 pub struct Tab {
@@ -26,6 +26,7 @@ pub struct Tab {
 	pub preview: Preview,
 	pub finder:  Option<Finder>,
 	pub search:  Option<JoinHandle<Result<()>>>,
+	pub compare: Option<Compare>,
 }
 
 impl Default for Tab {
@@ -47,6 +48,7 @@ impl Default for Tab {
 			preview: Default::default(),
 			finder:  Default::default(),
 			search:  Default::default(),
+			compare: Default::default(),
 		}
 	}
 }
@@ -1,82 +1,120 @@
 use indexmap::IndexSet;
 use tracing::debug;
+use yazi_scheduler::file::{Conflict, Resolver};
 use yazi_shared::url::{UrlBuf, UrlBufCov, UrlLike};
 
 use super::Tasks;
 use crate::mgr::Yanked;
 
 impl Tasks {
-	pub fn file_cut(&self, src: &Yanked, dest: &UrlBuf, force: bool) {
+	pub fn file_cut(&self, src: &Yanked, dest: &UrlBuf, conflict: Conflict, verify: bool) {
 		self.scheduler.behavior.reset();
 
-		for u in src.iter() {
-			let Some(Ok(to)) = u.name().map(|n| dest.try_join(n)) else {
-				debug!("file_cut: cannot join {u:?} with {dest:?}");
-				continue;
-			};
-			if force && *u == to {
-				debug!("file_cut: same file, skip {to:?}");
-			} else {
-				self.scheduler.file_cut(u.0.clone(), to, force);
+		let resolver = Resolver::from(conflict);
+		self.scheduler.journal.scope(|| {
+			for u in src.iter() {
+				let Some(Ok(to)) = u.name().map(|n| dest.try_join(n)) else {
+					debug!("file_cut: cannot join {u:?} with {dest:?}");
+					continue;
+				};
+				if conflict != Conflict::Rename && *u == to {
+					debug!("file_cut: same file, skip {to:?}");
+				} else {
+					self.scheduler.file_cut(u.0.clone(), to, resolver.clone(), verify);
+				}
 			}
-		}
+		});
 	}
 
-	pub fn file_copy(&self, src: &Yanked, dest: &UrlBuf, force: bool, follow: bool) {
+	pub fn file_copy(
+		&self,
+		src: &Yanked,
+		dest: &UrlBuf,
+		conflict: Conflict,
+		follow: bool,
+		verify: bool,
+	) {
 		self.scheduler.behavior.reset();
 
-		for u in src.iter() {
-			let Some(Ok(to)) = u.name().map(|n| dest.try_join(n)) else {
-				debug!("file_copy: cannot join {u:?} with {dest:?}");
-				continue;
-			};
-			if force && *u == to {
-				debug!("file_copy: same file, skip {to:?}");
-			} else {
-				self.scheduler.file_copy(u.0.clone(), to, force, follow);
+		let resolver = Resolver::from(conflict);
+		self.scheduler.journal.scope(|| {
+			for u in src.iter() {
+				let Some(Ok(to)) = u.name().map(|n| dest.try_join(n)) else {
+					debug!("file_copy: cannot join {u:?} with {dest:?}");
+					continue;
+				};
+				// A file pasted onto itself is duplicated under a unique name
+				let resolver = if *u == to { Conflict::Rename.into() } else { resolver.clone() };
+				self.scheduler.file_copy(u.0.clone(), to, resolver, follow, verify);
 			}
-		}
+		});
+	}
+
+	pub fn file_sync(&self, from: &UrlBuf, to: &UrlBuf, delete: bool, dry: bool, verify: bool) {
+		self.scheduler.behavior.reset();
+		self.scheduler.file_sync(from.clone(), to.clone(), delete, dry, verify);
 	}
 
 	pub fn file_link(&self, src: &IndexSet<UrlBufCov>, dest: &UrlBuf, relative: bool, force: bool) {
 		self.scheduler.behavior.reset();
 
-		for u in src {
-			let Some(Ok(to)) = u.name().map(|n| dest.try_join(n)) else {
-				debug!("file_link: cannot join {u:?} with {dest:?}");
-				continue;
-			};
-			if force && *u == to {
-				debug!("file_link: same file, skip {to:?}");
-			} else {
-				self.scheduler.file_link(u.0.clone(), to, relative, force);
+		self.scheduler.journal.scope(|| {
+			for u in src {
+				let Some(Ok(to)) = u.name().map(|n| dest.try_join(n)) else {
+					debug!("file_link: cannot join {u:?} with {dest:?}");
+					continue;
+				};
+				if force && *u == to {
+					debug!("file_link: same file, skip {to:?}");
+				} else {
+					self.scheduler.file_link(u.0.clone(), to, relative, force);
+				}
 			}
-		}
+		});
 	}
 
 	pub fn file_hardlink(&self, src: &IndexSet<UrlBufCov>, dest: &UrlBuf, force: bool, follow: bool) {
 		self.scheduler.behavior.reset();
 
-		for u in src {
-			let Some(Ok(to)) = u.name().map(|n| dest.try_join(n)) else {
-				debug!("file_hardlink: cannot join {u:?} with {dest:?}");
-				continue;
-			};
-			if force && *u == to {
-				debug!("file_hardlink: same file, skip {to:?}");
-			} else {
-				self.scheduler.file_hardlink(u.0.clone(), to, force, follow);
+		self.scheduler.journal.scope(|| {
+			for u in src {
+				let Some(Ok(to)) = u.name().map(|n| dest.try_join(n)) else {
+					debug!("file_hardlink: cannot join {u:?} with {dest:?}");
+					continue;
+				};
+				if force && *u == to {
+					debug!("file_hardlink: same file, skip {to:?}");
+				} else {
+					self.scheduler.file_hardlink(u.0.clone(), to, force, follow);
+				}
 			}
-		}
+		});
 	}
 
 	pub fn file_remove(&self, targets: Vec<UrlBuf>, permanently: bool) {
-		for u in targets {
-			if permanently {
-				self.scheduler.file_delete(u);
-			} else {
-				self.scheduler.file_trash(u);
+		self.scheduler.journal.scope(|| {
+			for u in targets {
+				if permanently {
+					self.scheduler.file_delete(u);
+				} else {
+					self.scheduler.file_trash(u);
+				}
 			}
+		});
+	}
+
+	pub fn file_restore(&self, targets: Vec<UrlBuf>) {
+		self.scheduler.behavior.reset();
+		for u in targets {
+			self.scheduler.file_restore(u);
 		}
 	}
+
+	pub fn file_upload(&self, targets: Vec<UrlBuf>) {
+		self.scheduler.journal.scope(|| {
+			for u in targets {
+				self.scheduler.file_upload(u);
+			}
+		});
+	}
 }
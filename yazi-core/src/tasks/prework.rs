@@ -45,7 +45,7 @@ impl Tasks {
 	}
 
 	pub fn prework_sorted(&self, targets: &Files) {
-		if targets.sorter().by != SortBy::Size {
+		if !targets.sorter().by.contains(SortBy::Size) {
 			return;
 		}
 
@@ -0,0 +1,40 @@
+use yazi_config::keymap::{Chord, Term};
+use yazi_fs::provider::local::Trash;
+use yazi_shared::{Condition, url::UrlLike};
+
+use crate::Core;
+
+impl Core {
+	/// Whether the `when` condition of a chord holds in the current state.
+	pub fn satisfies(&self, chord: &Chord) -> bool {
+		chord.when.as_ref().is_none_or(|c| self.eval(c))
+	}
+
+	pub fn eval(&self, cond: &Condition) -> bool {
+		cond.eval(|t| t.parse().is_ok_and(|t| self.term(&t))).unwrap_or(false)
+	}
+
+	fn term(&self, term: &Term) -> bool {
+		let tab = self.active();
+		let hovered = tab.hovered();
+
+		match term {
+			Term::Hovered => hovered.is_some(),
+			Term::HoveredIsDir => hovered.is_some_and(|h| h.is_dir()),
+			Term::HoveredIsFile => hovered.is_some_and(|h| h.is_file()),
+			Term::HoveredIsLink => hovered.is_some_and(|h| h.is_link()),
+			Term::HoveredIsOrphan => hovered.is_some_and(|h| h.is_orphan()),
+			Term::HoveredIsHidden => hovered.is_some_and(|h| h.is_hidden()),
+			Term::Mime(p) => {
+				hovered.and_then(|h| self.mgr.mimetype.get(&h.url)).is_some_and(|m| p.match_mime(m))
+			}
+			Term::Scheme(kind) => *kind == tab.cwd().kind(),
+			Term::Tabs(ord, n) => self.mgr.tabs.len().cmp(n) == *ord,
+			Term::Selected => !tab.selected.is_empty(),
+			Term::Visual => tab.mode.is_visual(),
+			Term::Yanked => !self.mgr.yanked.is_empty(),
+			Term::Finding => tab.finder.is_some(),
+			Term::Trash => Trash::view().is_some_and(|v| v == *tab.cwd()),
+		}
+	}
+}
@@ -1,3 +1,5 @@
+use std::mem;
+
 use tokio::sync::mpsc;
 use yazi_config::keymap::{ChordCow, Key};
 use yazi_macro::{emit, render_and};
@@ -7,6 +9,8 @@ pub struct Which {
 	pub tx:    Option<mpsc::UnboundedSender<Option<yazi_binding::ChordCow>>>,
 	pub times: usize,
 	pub cands: Vec<ChordCow>,
+	/// Count prefix typed before the chord, 0 if none.
+	pub count: usize,
 
 	// Active state
 	pub active: bool,
@@ -31,7 +35,21 @@ impl Which {
 		render_and!(true)
 	}
 
+	/// Appends the digit `n` to the count prefix, returns whether it did.
+	///
+	/// A count can't start with `0`, nor with a digit `bound` to a chord, so
+	/// bindings like `1` keep working, but any digit continues one.
+	pub fn push_count(&mut self, n: u32, bound: bool) -> bool {
+		if self.count == 0 && (n == 0 || bound) {
+			return false;
+		}
+
+		self.count = self.count.saturating_mul(10).saturating_add(n as usize);
+		true
+	}
+
 	pub fn dismiss(&mut self, chord: Option<ChordCow>) {
+		let count = mem::take(&mut self.count);
 		self.times = 0;
 		self.cands.clear();
 
@@ -42,7 +60,29 @@ impl Which {
 			_ = tx.send(chord.clone().map(Into::into));
 		}
 		if let Some(chord) = chord {
-			emit!(Seq(chord.into_seq()));
+			emit!(Seq(chord.into_counted_seq(count)));
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_push_count() {
+		let mut which = Which::default();
+		assert!(!which.push_count(0, false));
+		assert!(!which.push_count(1, true));
+		assert_eq!(which.count, 0);
+
+		assert!(which.push_count(1, false));
+		assert!(which.push_count(0, false));
+		assert!(which.push_count(2, true));
+		assert_eq!(which.count, 102);
+
+		which.count = usize::MAX / 2;
+		assert!(which.push_count(9, false));
+		assert_eq!(which.count, usize::MAX);
+	}
+}
@@ -2,7 +2,7 @@ use anyhow::{Result, bail};
 use mlua::{ExternalResult, IntoLua, Lua, Value};
 use yazi_shared::Id;
 
-use super::{EmberBulk, EmberBye, EmberCd, EmberCustom, EmberDelete, EmberDownload, EmberDuplicate, EmberHey, EmberHi, EmberHover, EmberLoad, EmberMount, EmberMove, EmberRename, EmberTab, EmberTrash, EmberYank};
+use super::{EmberBulk, EmberBye, EmberCd, EmberCustom, EmberDelete, EmberDownload, EmberDuplicate, EmberHey, EmberHi, EmberHover, EmberLoad, EmberMark, EmberMount, EmberMove, EmberRename, EmberTab, EmberTrash, EmberYank};
 use crate::Payload;
 
 #[derive(Clone, Debug)]
@@ -17,6 +17,7 @@ pub enum Ember<'a> {
 	Rename(EmberRename<'a>),
 	Bulk(EmberBulk<'a>),
 	Yank(EmberYank<'a>),
+	Mark(EmberMark<'a>),
 	Duplicate(EmberDuplicate<'a>),
 	Move(EmberMove<'a>),
 	Trash(EmberTrash<'a>),
@@ -39,6 +40,7 @@ impl Ember<'static> {
 			"rename" => Self::Rename(serde_json::from_str(body)?),
 			"bulk" => Self::Bulk(serde_json::from_str(body)?),
 			"@yank" => Self::Yank(serde_json::from_str(body)?),
+			"@mark" => Self::Mark(serde_json::from_str(body)?),
 			"duplicate" => Self::Duplicate(serde_json::from_str(body)?),
 			"move" => Self::Move(serde_json::from_str(body)?),
 			"trash" => Self::Trash(serde_json::from_str(body)?),
@@ -67,6 +69,7 @@ impl Ember<'static> {
 				| "rename"
 				| "bulk"
 				| "@yank"
+				| "@mark"
 				| "duplicate"
 				| "move"
 				| "trash"
@@ -106,6 +109,7 @@ impl<'a> Ember<'a> {
 			Self::Rename(_) => "rename",
 			Self::Bulk(_) => "bulk",
 			Self::Yank(_) => "@yank",
+			Self::Mark(_) => "@mark",
 			Self::Duplicate(_) => "duplicate",
 			Self::Move(_) => "move",
 			Self::Trash(_) => "trash",
@@ -134,6 +138,7 @@ impl<'a> IntoLua for Ember<'a> {
 			Self::Rename(b) => b.into_lua(lua),
 			Self::Bulk(b) => b.into_lua(lua),
 			Self::Yank(b) => b.into_lua(lua),
+			Self::Mark(b) => b.into_lua(lua),
 			Self::Duplicate(b) => b.into_lua(lua),
 			Self::Move(b) => b.into_lua(lua),
 			Self::Trash(b) => b.into_lua(lua),
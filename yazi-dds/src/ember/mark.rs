@@ -0,0 +1,61 @@
+use std::borrow::Cow;
+
+use mlua::{IntoLua, Lua, Value};
+use serde::{Deserialize, Serialize};
+use yazi_shared::url::UrlBuf;
+
+use super::Ember;
+
+/// A mark set to `url`, or deleted if `url` is `None`.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct EmberMark<'a> {
+	pub key: char,
+	pub url: Option<Cow<'a, UrlBuf>>,
+}
+
+impl<'a> EmberMark<'a> {
+	pub fn borrowed(key: char, url: Option<&'a UrlBuf>) -> Ember<'a> {
+		Self { key, url: url.map(Cow::Borrowed) }.into()
+	}
+}
+
+impl EmberMark<'static> {
+	pub fn owned(key: char, url: Option<&UrlBuf>) -> Ember<'static> {
+		Self { key, url: url.map(|u| Cow::Owned(u.clone())) }.into()
+	}
+}
+
+impl<'a> From<EmberMark<'a>> for Ember<'a> {
+	fn from(value: EmberMark<'a>) -> Self { Self::Mark(value) }
+}
+
+impl IntoLua for EmberMark<'_> {
+	fn into_lua(self, lua: &Lua) -> mlua::Result<Value> {
+		let url = self.url.map(|u| yazi_binding::Url::new(u.into_owned()));
+		lua
+			.create_table_from([("key", self.key.to_string().into_lua(lua)?), ("url", url.into_lua(lua)?)])?
+			.into_lua(lua)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_round_trip() {
+		yazi_shared::init_tests();
+		let url: UrlBuf = "/tmp".parse().unwrap();
+
+		let Ember::Mark(set) = EmberMark::borrowed('a', Some(&url)) else { panic!() };
+		let json = serde_json::to_string(&set).unwrap();
+		let Ember::Mark(b) = Ember::from_str("@mark", &json).unwrap() else { panic!() };
+		assert_eq!((b.key, b.url.as_deref()), ('a', Some(&url)));
+
+		// Deleted
+		let Ember::Mark(delete) = EmberMark::owned('b', None) else { panic!() };
+		let json = serde_json::to_string(&delete).unwrap();
+		let Ember::Mark(b) = Ember::from_str("@mark", &json).unwrap() else { panic!() };
+		assert_eq!((b.key, b.url), ('b', None));
+	}
+}
@@ -1,3 +1,3 @@
 yazi_macro::mod_flat!(
-	bulk bye cd custom delete download duplicate ember hey hi hover load mount r#move rename tab trash yank
+	bulk bye cd custom delete download duplicate ember hey hi hover load mark mount r#move rename tab trash yank
 );
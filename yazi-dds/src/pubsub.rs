@@ -120,7 +120,8 @@ impl Pubsub {
 	}
 
 	pub fn pub_inner_hi() -> bool {
-		let abilities = REMOTE.read().keys().cloned().collect();
+		// Marks are always shared between instances
+		let abilities = REMOTE.read().keys().cloned().chain(["@mark".to_owned()]).collect();
 		let abilities = BOOT.remote_events.union(&abilities).map(AsRef::as_ref);
 
 		// FIXME: handle error
@@ -164,6 +165,8 @@ impl Pubsub {
 
 	pub_after!(@yank(cut: bool, urls: &IndexSet<UrlBufCov>), (cut, urls));
 
+	pub_after!(@mark(key: char, url: Option<&UrlBuf>), (key, url));
+
 	pub_after!(duplicate(items: Vec<EmberDuplicateItem>), (&items), (items));
 
 	pub_after!(move(items: Vec<EmberMoveItem>), (&items), (items));
@@ -1,10 +1,29 @@
 use ratatui::{buffer::Buffer, layout::{Constraint, Rect}, text::Span, widgets::{Paragraph, Widget}};
 use yazi_config::THEME;
+use yazi_core::Core;
 
-pub(crate) struct Buttons;
+pub(crate) struct Buttons<'a> {
+	core: &'a Core,
+}
+
+impl<'a> Buttons<'a> {
+	pub(crate) fn new(core: &'a Core) -> Self { Self { core } }
+}
 
-impl Widget for Buttons {
+impl Widget for Buttons<'_> {
 	fn render(self, area: Rect, buf: &mut Buffer) {
+		let choices = &self.core.confirm.choices;
+		if !choices.is_empty() {
+			let chunks = ratatui::layout::Layout::horizontal(choices.iter().map(|_| Constraint::Fill(1)))
+				.split(area);
+
+			for (i, &(_, label)) in choices.iter().enumerate() {
+				let style = if i == 0 { THEME.confirm.btn_yes.get() } else { THEME.confirm.btn_no.get() };
+				Paragraph::new(Span::raw(label).style(style)).centered().render(chunks[i], buf);
+			}
+			return;
+		}
+
 		let chunks =
 			ratatui::layout::Layout::horizontal([Constraint::Fill(1), Constraint::Fill(1)]).split(area);
 		let labels = THEME.confirm.btn_labels.load();
@@ -40,6 +40,6 @@ impl Widget for Confirm<'_> {
 
 		super::Body::new(self.core, body_border).render(chunks[0], buf);
 		super::List::new(self.core).render(chunks[1], buf);
-		super::Buttons.render(chunks[2], buf);
+		super::Buttons::new(self.core).render(chunks[2], buf);
 	}
 }
@@ -37,6 +37,8 @@ impl<'a> Dispatcher<'a> {
 
 	fn dispatch_call(&mut self, action: ActionCow) {
 		let tx = action.replier().cloned();
+		self.app.core.mgr.macros.push(&action);
+
 		let result = Executor::new(self.app).execute(action);
 
 		if let Err(e) = &result {
@@ -106,6 +106,12 @@ impl<'a> Executor<'a> {
 		on!(reveal);
 		on!(follow);
 		on!(stash);
+		on!(mark);
+
+		// Macros
+		on!(macro_record);
+		on!(macro_stop);
+		on!(macro_play);
 
 		// Toggle
 		on!(toggle);
@@ -132,6 +138,11 @@ impl<'a> Executor<'a> {
 		on!(search_do);
 		on!(bulk_exit);
 		on!(bulk_rename);
+		on!(undo);
+		on!(redo);
+		on!(trash);
+		on!(trash_restore);
+		on!(trash_empty);
 
 		// Filter
 		on!(filter);
@@ -152,6 +163,11 @@ impl<'a> Executor<'a> {
 		on!(tab_switch);
 		on!(tab_swap);
 
+		// Compare
+		on!(compare);
+		on!(compare_do);
+		on!(sync);
+
 		// VFS
 		on!(download);
 		on!(upload);
@@ -187,6 +203,9 @@ impl<'a> Executor<'a> {
 		on!(arrow);
 		on!(inspect);
 		on!(cancel);
+		on!(pause);
+		on!(resume);
+		on!(throttle);
 		on!(process_open);
 		on!(open_shell_compat);
 
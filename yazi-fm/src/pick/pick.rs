@@ -1,4 +1,4 @@
-use ratatui::{buffer::Buffer, layout::{Margin, Rect}, widgets::{Block, BorderType, Widget}};
+use ratatui::{buffer::Buffer, layout::{Constraint, Layout, Margin, Rect}, widgets::{Block, BorderType, Borders, Padding, Paragraph, Widget}};
 use yazi_config::THEME;
 use yazi_core::Core;
 
@@ -25,6 +25,22 @@ impl Widget for Pick<'_> {
 			.border_style(THEME.pick.border.get())
 			.render(area, buf);
 
-		List::new(self.core).render(area.inner(Margin::new(0, 1)), buf);
+		let inner = area.inner(Margin::new(0, 1));
+		let Some(preview) = pick.preview() else {
+			return List::new(self.core).render(inner, buf);
+		};
+
+		let [list, right] = Layout::horizontal([Constraint::Fill(1); 2]).areas(inner);
+		List::new(self.core).render(list, buf);
+
+		Paragraph::new(preview.join("\n"))
+			.style(THEME.pick.inactive.get())
+			.block(
+				Block::new()
+					.borders(Borders::LEFT)
+					.border_style(THEME.pick.border.get())
+					.padding(Padding::horizontal(1)),
+			)
+			.render(right, buf);
 	}
 }
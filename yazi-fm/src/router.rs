@@ -1,7 +1,10 @@
+use std::mem;
+
 use anyhow::Result;
 use yazi_actor::Ctx;
 use yazi_config::{KEYMAP, keymap::{Chord, ChordCow, Key}};
-use yazi_macro::act;
+use yazi_core::which::Which;
+use yazi_macro::{act, render, render_and};
 use yazi_shared::Layer;
 
 use crate::{Dispatcher, app::App};
@@ -27,28 +30,108 @@ impl<'a> Router<'a> {
 		use Layer as L;
 		Ok(match layer {
 			L::Null | L::App | L::Notify => unreachable!(),
-			L::Mgr | L::Tasks | L::Spot | L::Pick | L::Input | L::Confirm | L::Help => {
-				self.matches(layer, key)
-			}
+			L::Mgr => self.count(layer, key) || self.matches(layer, key),
+			L::Tasks | L::Spot | L::Pick | L::Confirm | L::Help => self.matches(layer, key),
+			L::Input => self.matches(layer, key),
 			L::Cmp => self.matches(L::Cmp, key) || self.matches(L::Input, key),
 			L::Which => core.which.r#type(key),
 		})
 	}
 
+	/// Accumulates digits into a count prefix, shown in the status bar.
+	///
+	/// Only done in the manager, the only layer whose actions take a count.
+	fn count(&mut self, layer: Layer, key: Key) -> bool {
+		let core = &mut self.app.core;
+		let bound = Self::lookup(KEYMAP.get(layer), key, |c| core.satisfies(c)).is_some();
+		render_and!(Self::counted(&mut core.which, key, bound))
+	}
+
+	fn counted(which: &mut Which, key: Key, bound: bool) -> bool {
+		key.plain().and_then(|c| c.to_digit(10)).is_some_and(|n| which.push_count(n, bound))
+	}
+
+	/// The first of the `chords` started by `key` whose condition is satisfied.
+	fn lookup(chords: &[Chord], key: Key, satisfies: impl Fn(&Chord) -> bool) -> Option<&Chord> {
+		chords.iter().find(|&c| c.on.first() == Some(&key) && satisfies(c))
+	}
+
 	fn matches(&mut self, layer: Layer, key: Key) -> bool {
-		for chord @ Chord { on, .. } in KEYMAP.get(layer) {
-			if on.is_empty() || on[0] != key {
-				continue;
+		let core = &self.app.core;
+		let Some(chord) = Self::lookup(KEYMAP.get(layer), key, |c| core.satisfies(c)) else {
+			if mem::take(&mut self.app.core.which.count) != 0 {
+				render!();
 			}
+			return false;
+		};
 
-			if on.len() > 1 {
-				let cx = &mut Ctx::active(&mut self.app.core, &mut self.app.term);
-				act!(which:activate, cx, (layer, key)).ok();
-			} else {
-				Dispatcher::new(self.app).dispatch_seq(ChordCow::from(chord).into_seq());
-			}
-			return true;
+		if chord.on.len() > 1 {
+			let cx = &mut Ctx::active(&mut self.app.core, &mut self.app.term);
+			act!(which:activate, cx, (layer, key)).ok();
+		} else {
+			let count = mem::take(&mut self.app.core.which.count);
+			Dispatcher::new(self.app).dispatch_seq(ChordCow::from(chord).into_counted_seq(count));
+		}
+		true
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use yazi_config::keymap::Keymap;
+
+	use super::*;
+
+	fn key(s: &str) -> Key { s.parse().unwrap() }
+
+	fn chord(s: &str) -> Chord { toml::from_str(s).unwrap() }
+
+	#[test]
+	fn test_lookup() {
+		yazi_shared::init_tests();
+		let chords = [
+			chord("on = '1'\nrun = 'noop'\nwhen = 'trash'"),
+			chord("on = ['g', '2']\nrun = 'noop'"),
+			chord("on = '<C-3>'\nrun = 'noop'"),
+		];
+		let always = |_: &Chord| true;
+
+		assert!(Router::lookup(&chords, key("1"), always).is_some());
+		assert!(Router::lookup(&chords, key("g"), always).is_some());
+
+		// Only the first key of a chord, and with the same modifiers
+		assert!(Router::lookup(&chords, key("2"), always).is_none());
+		assert!(Router::lookup(&chords, key("3"), always).is_none());
+
+		// Bound under a condition that doesn't hold, so free to start a count
+		let unconditional = |c: &Chord| c.when.is_none();
+		assert!(Router::lookup(&chords, key("1"), unconditional).is_none());
+		assert!(Router::counted(&mut Which::default(), key("1"), false));
+	}
+
+	#[test]
+	fn test_count_prefix() {
+		yazi_shared::init_tests();
+		let keymap: Keymap = toml::from_str(include_str!("../../yazi-config/preset/keymap-default.toml")).unwrap();
+		let chords = keymap.get(Layer::Mgr);
+		let mut which = Which::default();
+
+		// `5` then `j` with the preset keymap
+		let bound = Router::lookup(chords, key("5"), |_| true).is_some();
+		assert!(Router::counted(&mut which, key("5"), bound));
+
+		let chord = Router::lookup(chords, key("j"), |_| true).unwrap();
+		let seq = ChordCow::from(chord.clone()).into_counted_seq(mem::take(&mut which.count));
+		assert_eq!(seq.iter().map(|a| &*a.name).collect::<Vec<_>>(), ["arrow"]);
+		assert_eq!(seq[0].get::<i64>("count").unwrap(), 5);
+
+		// `1` then `0` then `G`
+		for k in ["1", "0"] {
+			let bound = Router::lookup(chords, key(k), |_| true).is_some();
+			assert!(Router::counted(&mut which, key(k), bound));
 		}
-		false
+		let chord = Router::lookup(chords, key("G"), |_| true).unwrap();
+		let seq = ChordCow::from(chord.clone()).into_counted_seq(mem::take(&mut which.count));
+		assert_eq!(seq[0].get::<i64>("count").unwrap(), 10);
 	}
 }
@@ -1,10 +1,10 @@
 use std::{mem, ops::{Deref, DerefMut, Not}};
 
 use hashbrown::{HashMap, HashSet};
-use yazi_shared::{Id, path::{PathBufDyn, PathDyn}};
+use yazi_shared::{Id, path::{PathBufDyn, PathDyn}, pool::Symbol};
 
 use super::{FilesSorter, Filter};
-use crate::{FILES_TICKET, File, SortBy};
+use crate::{FILES_TICKET, File, SortBy, git::GitStatus};
 
 #[derive(Default)]
 pub struct Files {
@@ -15,6 +15,13 @@ pub struct Files {
 	pub revision: u64,
 
 	pub sizes: HashMap<PathBufDyn, u64>,
+	pub mimes: HashMap<PathBufDyn, Symbol<str>>,
+	pub ranks: HashMap<PathBufDyn, u32>,
+	pub git:   HashMap<PathBufDyn, GitStatus>,
+
+	// The plugin and revision the ranks are for, and the one being requested
+	ranked:  Option<(Symbol<str>, u64)>,
+	ranking: Option<(Symbol<str>, u64)>,
 
 	sorter:      FilesSorter,
 	filter:      Option<Filter>,
@@ -78,12 +85,52 @@ impl Files {
 			return;
 		}
 
-		if self.sorter.by == SortBy::Size {
+		if self.sorter.by.contains(SortBy::Size) {
 			self.revision += 1;
 		}
 		self.sizes.extend(sizes);
 	}
 
+	pub fn update_mimes(&mut self, mut mimes: HashMap<PathBufDyn, Symbol<str>>) {
+		mimes.retain(|k, v| self.mimes.get(k) != Some(v));
+		if mimes.is_empty() {
+			return;
+		}
+
+		if self.sorter.by.contains(SortBy::Mime) {
+			self.revision += 1;
+		}
+		self.mimes.extend(mimes);
+	}
+
+	pub fn update_git(&mut self, git: HashMap<PathBufDyn, GitStatus>) -> bool {
+		if self.git == git {
+			return false;
+		}
+		self.git = git;
+		true
+	}
+
+	pub fn update_ranks(
+		&mut self,
+		plugin: Symbol<str>,
+		revision: u64,
+		ranks: HashMap<PathBufDyn, u32>,
+	) {
+		if self.sorter.by.plugin() != Some(&plugin) {
+			return;
+		}
+
+		let fresh = revision == self.revision;
+		self.ranking.take_if(|(p, r)| *p == plugin && *r == revision);
+
+		self.ranks = ranks;
+		self.revision += 1;
+		if fresh {
+			self.ranked = Some((plugin, self.revision));
+		}
+	}
+
 	pub fn update_ioerr(&mut self) {
 		self.ticket = FILES_TICKET.next();
 		self.hidden.clear();
@@ -250,10 +297,17 @@ impl Files {
 		}
 
 		self.version = self.revision;
-		self.sorter.sort(&mut self.items, &self.sizes);
+		self.sort();
 		true
 	}
 
+	fn sort(&mut self) {
+		self.sorter.sort(&mut self.items, &self.sizes, &self.mimes, &self.ranks);
+		if let Some(filter) = &self.filter {
+			filter.rank(&mut self.items);
+		}
+	}
+
 	fn split_files(&self, files: impl IntoIterator<Item = File>) -> (Vec<File>, Vec<File>) {
 		if let Some(filter) = &self.filter {
 			files
@@ -287,6 +341,18 @@ impl Files {
 		}
 	}
 
+	/// The plugin and revision to request ranks for, if they're outdated and
+	/// haven't been requested yet.
+	pub fn request_ranks(&mut self) -> Option<(Symbol<str>, u64)> {
+		let want = (self.sorter.by.plugin()?.clone(), self.revision);
+		if self.ranked.as_ref() == Some(&want) || self.ranking.as_ref() == Some(&want) {
+			return None;
+		}
+
+		self.ranking = Some(want.clone());
+		Some(want)
+	}
+
 	// --- Filter
 	#[inline]
 	pub fn filter(&self) -> Option<&Filter> { self.filter.as_ref() }
@@ -304,14 +370,14 @@ impl Files {
 			self.hidden = hidden;
 			if !items.is_empty() {
 				self.items.extend(items);
-				self.sorter.sort(&mut self.items, &self.sizes);
+				self.sort();
 			}
 			return true;
 		}
 
 		let it = mem::take(&mut self.items).into_iter().chain(mem::take(&mut self.hidden));
 		(self.hidden, self.items) = self.split_files(it);
-		self.sorter.sort(&mut self.items, &self.sizes);
+		self.sort();
 		true
 	}
 
@@ -1,25 +1,31 @@
-use std::{fmt::Display, ops::Range};
+use std::{cmp::Reverse, fmt::Display, ops::Range};
 
 use anyhow::Result;
 use regex::bytes::{Regex, RegexBuilder};
 use yazi_shared::{event::Action, strand::AsStrand};
 
+use crate::{File, Fuzzy};
+
 pub struct Filter {
-	raw:   String,
-	regex: Regex,
+	raw:     String,
+	matcher: Matcher,
+	rank:    bool,
+}
+
+enum Matcher {
+	Regex(Regex),
+	Fuzzy(Fuzzy),
 }
 
 impl Filter {
 	pub fn new(s: &str, case: FilterCase) -> Result<Self> {
-		let regex = match case {
-			FilterCase::Smart => {
-				let uppercase = s.chars().any(|c| c.is_uppercase());
-				RegexBuilder::new(s).case_insensitive(!uppercase).build()?
-			}
-			FilterCase::Sensitive => Regex::new(s)?,
-			FilterCase::Insensitive => RegexBuilder::new(s).case_insensitive(true).build()?,
-		};
-		Ok(Self { raw: s.to_owned(), regex })
+		let regex = RegexBuilder::new(s).case_insensitive(case.insensitive(s)).build()?;
+		Ok(Self { raw: s.to_owned(), matcher: Matcher::Regex(regex), rank: false })
+	}
+
+	/// A fzf-style subsequence filter, `rank` sorts the matched files by score.
+	pub fn fuzzy(s: &str, case: FilterCase, rank: bool) -> Self {
+		Self { raw: s.to_owned(), matcher: Matcher::Fuzzy(Fuzzy::new(s, case.insensitive(s))), rank }
 	}
 
 	#[inline]
@@ -28,16 +34,39 @@ impl Filter {
 	where
 		T: AsStrand,
 	{
-		self.regex.is_match(name.as_strand().encoded_bytes())
+		let b = name.as_strand().encoded_bytes();
+		match &self.matcher {
+			Matcher::Regex(regex) => regex.is_match(b),
+			Matcher::Fuzzy(fuzzy) => fuzzy.matches(b),
+		}
 	}
 
 	pub fn highlighted(&self, name: impl AsStrand) -> Option<Vec<Range<usize>>> {
-		self.regex.find(name.as_strand().encoded_bytes()).map(|m| vec![m.range()])
+		let b = name.as_strand().encoded_bytes();
+		match &self.matcher {
+			Matcher::Regex(regex) => regex.find(b).map(|m| vec![m.range()]),
+			Matcher::Fuzzy(fuzzy) => fuzzy.highlighted(b),
+		}
 	}
+
+	/// Re-ranks the files by their fuzzy score, the original order is kept
+	/// among files with the same score.
+	pub fn rank(&self, files: &mut [File]) {
+		let Matcher::Fuzzy(fuzzy) = &self.matcher else { return };
+		if self.rank {
+			files
+				.sort_by_cached_key(|f| Reverse(fuzzy.score(f.urn().encoded_bytes()).unwrap_or(i32::MIN)));
+		}
+	}
+
+	#[inline]
+	fn is_fuzzy(&self) -> bool { matches!(self.matcher, Matcher::Fuzzy(_)) }
 }
 
 impl PartialEq for Filter {
-	fn eq(&self, other: &Self) -> bool { self.raw == other.raw }
+	fn eq(&self, other: &Self) -> bool {
+		self.raw == other.raw && self.is_fuzzy() == other.is_fuzzy() && self.rank == other.rank
+	}
 }
 
 impl Display for Filter {
@@ -52,6 +81,16 @@ pub enum FilterCase {
 	Insensitive,
 }
 
+impl FilterCase {
+	fn insensitive(self, s: &str) -> bool {
+		match self {
+			Self::Smart => !s.chars().any(|c| c.is_uppercase()),
+			Self::Sensitive => false,
+			Self::Insensitive => true,
+		}
+	}
+}
+
 impl From<&Action> for FilterCase {
 	fn from(a: &Action) -> Self {
 		match (a.bool("smart"), a.bool("insensitive")) {
@@ -0,0 +1,188 @@
+use std::ops::Range;
+
+const SCORE_MATCH: i32 = 16;
+const SCORE_GAP_START: i32 = -3;
+const SCORE_GAP_EXTENSION: i32 = -1;
+
+const BONUS_BOUNDARY: i32 = SCORE_MATCH / 2;
+const BONUS_NON_WORD: i32 = SCORE_MATCH / 2;
+const BONUS_CAMEL: i32 = BONUS_BOUNDARY + SCORE_GAP_EXTENSION;
+const BONUS_CONSECUTIVE: i32 = -(SCORE_GAP_START + SCORE_GAP_EXTENSION);
+const BONUS_FIRST_CHAR_MULTIPLIER: i32 = 2;
+
+/// An fzf-style subsequence matcher, where a higher score means a better
+/// match.
+#[derive(Debug)]
+pub(super) struct Fuzzy {
+	pattern:     Vec<char>,
+	insensitive: bool,
+}
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+enum Class {
+	Lower,
+	Upper,
+	Number,
+	NonWord,
+}
+
+impl Fuzzy {
+	pub(super) fn new(pattern: &str, insensitive: bool) -> Self {
+		let pattern = pattern.chars().map(|c| if insensitive { Self::lower(c) } else { c }).collect();
+		Self { pattern, insensitive }
+	}
+
+	pub(super) fn matches(&self, text: &[u8]) -> bool { self.span(&Self::decode(text)).is_some() }
+
+	pub(super) fn score(&self, text: &[u8]) -> Option<i32> {
+		let chars = Self::decode(text);
+		self.span(&chars).map(|span| self.calculate(&chars, span).0)
+	}
+
+	/// Byte ranges of the matched characters, with adjacent ones merged.
+	pub(super) fn highlighted(&self, text: &[u8]) -> Option<Vec<Range<usize>>> {
+		let chars = Self::decode(text);
+		let (_, positions) = self.calculate(&chars, self.span(&chars)?);
+
+		let mut ranges: Vec<Range<usize>> = vec![];
+		for i in positions {
+			let (start, end) = (chars[i].0, chars.get(i + 1).map_or(text.len(), |&(j, _)| j));
+			match ranges.last_mut() {
+				Some(last) if last.end == start => last.end = end,
+				_ => ranges.push(start..end),
+			}
+		}
+		Some(ranges)
+	}
+
+	/// The shortest range of characters containing the pattern, found by a
+	/// forward scan followed by a backward one.
+	fn span(&self, chars: &[(usize, char)]) -> Option<Range<usize>> {
+		if self.pattern.is_empty() {
+			return Some(0..0);
+		}
+
+		let mut pidx = 0;
+		let mut end = None;
+		for (i, &(_, c)) in chars.iter().enumerate() {
+			if self.fold(c) == self.pattern[pidx] {
+				pidx += 1;
+				if pidx == self.pattern.len() {
+					end = Some(i + 1);
+					break;
+				}
+			}
+		}
+
+		let end = end?;
+		let mut start = end;
+		for (i, &(_, c)) in chars[..end].iter().enumerate().rev() {
+			if self.fold(c) == self.pattern[pidx - 1] {
+				pidx -= 1;
+				if pidx == 0 {
+					start = i;
+					break;
+				}
+			}
+		}
+		Some(start..end)
+	}
+
+	fn calculate(&self, chars: &[(usize, char)], span: Range<usize>) -> (i32, Vec<usize>) {
+		let (mut score, mut positions) = (0, Vec::with_capacity(self.pattern.len()));
+		let (mut pidx, mut in_gap, mut consecutive, mut first_bonus) = (0, false, 0, 0);
+
+		let mut prev = span.start.checked_sub(1).map_or(Class::NonWord, |i| Self::class(chars[i].1));
+		for i in span {
+			let c = chars[i].1;
+			let class = Self::class(c);
+
+			if pidx < self.pattern.len() && self.fold(c) == self.pattern[pidx] {
+				positions.push(i);
+				score += SCORE_MATCH;
+
+				let mut bonus = Self::bonus(prev, class);
+				if consecutive == 0 {
+					first_bonus = bonus;
+				} else {
+					// Break consecutive chunks at a better boundary
+					if bonus >= BONUS_BOUNDARY && bonus > first_bonus {
+						first_bonus = bonus;
+					}
+					bonus = bonus.max(first_bonus).max(BONUS_CONSECUTIVE);
+				}
+
+				score += if pidx == 0 { bonus * BONUS_FIRST_CHAR_MULTIPLIER } else { bonus };
+				(in_gap, consecutive, pidx) = (false, consecutive + 1, pidx + 1);
+			} else {
+				score += if in_gap { SCORE_GAP_EXTENSION } else { SCORE_GAP_START };
+				(in_gap, consecutive, first_bonus) = (true, 0, 0);
+			}
+			prev = class;
+		}
+		(score, positions)
+	}
+
+	fn bonus(prev: Class, class: Class) -> i32 {
+		match (prev, class) {
+			(Class::NonWord, c) if c != Class::NonWord => BONUS_BOUNDARY,
+			(Class::Lower, Class::Upper) => BONUS_CAMEL,
+			(p, Class::Number) if p != Class::Number => BONUS_CAMEL,
+			(_, Class::NonWord) => BONUS_NON_WORD,
+			_ => 0,
+		}
+	}
+
+	/// Characters with their byte offsets, or bytes as-is if it's not UTF-8.
+	fn decode(text: &[u8]) -> Vec<(usize, char)> {
+		match std::str::from_utf8(text) {
+			Ok(s) => s.char_indices().collect(),
+			Err(_) => text.iter().enumerate().map(|(i, &b)| (i, b as char)).collect(),
+		}
+	}
+
+	fn class(c: char) -> Class {
+		if c.is_lowercase() {
+			Class::Lower
+		} else if c.is_uppercase() {
+			Class::Upper
+		} else if c.is_numeric() {
+			Class::Number
+		} else if c.is_alphabetic() {
+			Class::Lower
+		} else {
+			Class::NonWord
+		}
+	}
+
+	#[inline]
+	fn fold(&self, c: char) -> char { if self.insensitive { Self::lower(c) } else { c } }
+
+	#[inline]
+	fn lower(c: char) -> char { c.to_lowercase().next().unwrap_or(c) }
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_score() {
+		let fuzzy = Fuzzy::new("fb", true);
+		assert!(fuzzy.score(b"xyz").is_none());
+		assert!(fuzzy.score(b"foo_bar").unwrap() > fuzzy.score(b"afoobar").unwrap());
+		assert!(fuzzy.score(b"FooBar").unwrap() > fuzzy.score(b"foobar").unwrap());
+
+		let fuzzy = Fuzzy::new("fb", false);
+		assert!(fuzzy.score(b"FooBar").is_none());
+	}
+
+	#[test]
+	fn test_highlighted() {
+		let fuzzy = Fuzzy::new("fobr", true);
+		assert_eq!(fuzzy.highlighted(b"foo_bar").unwrap(), [0..2, 4..5, 6..7]);
+
+		let fuzzy = Fuzzy::new("bar", true);
+		assert_eq!(fuzzy.highlighted("äb-a-r".as_bytes()), Some(vec![2..3, 4..5, 6..7]));
+	}
+}
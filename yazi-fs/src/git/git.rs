@@ -0,0 +1,505 @@
+use std::{collections::BTreeMap, ffi::OsString, fs, hash::{DefaultHasher, Hash, Hasher}, io::{self, BufReader}, num::NonZeroUsize, path::{Path, PathBuf}, sync::{Arc, LazyLock}, time::{SystemTime, UNIX_EPOCH}};
+
+use hashbrown::{HashMap, HashSet};
+use lru::LruCache;
+use parking_lot::Mutex;
+use sha1::{Digest, Sha1};
+use yazi_shared::path::PathBufDyn;
+
+use super::{Entry, GitStatus, Index, Odb, Oid, Repo, Revision};
+use crate::Ignore;
+
+const MODE_TREE: u32 = 0o040000;
+const MODE_GITLINK: u32 = 0o160000;
+
+/// Statuses of the directories seen recently, valid as long as the revision
+/// of their repo, and the mtimes of them, their entries, and the tracked files
+/// beneath them and their parents stay the same.
+static STATUSES: LazyLock<Mutex<LruCache<PathBuf, (Stamp, Statuses)>>> =
+	LazyLock::new(|| Mutex::new(LruCache::new(NonZeroUsize::new(64).unwrap())));
+
+/// Index and HEAD of the repos seen recently, by their git dir.
+static SNAPSHOTS: LazyLock<Mutex<LruCache<PathBuf, Arc<Snapshot>>>> =
+	LazyLock::new(|| Mutex::new(LruCache::new(NonZeroUsize::new(8).unwrap())));
+
+type Statuses = HashMap<PathBufDyn, GitStatus>;
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+struct Stamp {
+	revision: Revision,
+	/// Fingerprint of the mtimes, changed by any of them changing, unlike the
+	/// latest one, which could stay the same within the precision of the mtime.
+	mtimes:   u64,
+}
+
+struct Snapshot {
+	revision: Revision,
+	index:    Index,
+	/// Files in HEAD, as `path => (mode, oid)` relative to the work tree.
+	head:     BTreeMap<Vec<u8>, (u32, Oid)>,
+}
+
+pub struct Git;
+
+impl Git {
+	/// Statuses of the entries in `dir`, `None` if it's not in a git work tree.
+	///
+	/// A directory takes the most significant status of the files beneath it,
+	/// or is untracked or ignored as a whole if none of them is tracked.
+	pub fn status(dir: &Path) -> io::Result<Option<Statuses>> {
+		let Some(repo) = Repo::discover(dir) else { return Ok(None) };
+		let Ok(rel) = dir.strip_prefix(&repo.workdir) else { return Ok(None) };
+
+		let children = Self::children(dir)?;
+		let revision = repo.revision()?;
+		let snapshot = Self::snapshot(&repo, revision)?;
+
+		// Files further down don't change the mtime of `dir` or its entries when
+		// edited in place, nor do the directories they're in when added
+		let mut nested = HashSet::new();
+		for (path, _) in snapshot.index.under(&Self::prefix(rel)) {
+			let mut path = Some(path);
+			while let Some(p) = path.filter(|p| p.contains(&b'/')) {
+				nested.insert(p);
+				path = p.iter().rposition(|&b| b == b'/').map(|i| &p[..i]);
+			}
+		}
+
+		let mtime = |p: &[u8]| fs::symlink_metadata(dir.join(Self::os_path(p))).ok()?.modified().ok();
+		let fingerprint = |name: &[u8], mtime: Option<SystemTime>| {
+			let mut h = DefaultHasher::new();
+			(name, mtime).hash(&mut h);
+			h.finish()
+		};
+		let stamp = Stamp {
+			revision,
+			mtimes: children
+				.iter()
+				.map(|(name, _, mtime)| fingerprint(name.as_encoded_bytes(), *mtime))
+				.chain(nested.into_iter().map(|p| fingerprint(p, mtime(p))))
+				.chain([fingerprint(b"", fs::metadata(dir)?.modified().ok())])
+				.fold(0, u64::wrapping_add),
+		};
+
+		if let Some((s, statuses)) = STATUSES.lock().get(dir)
+			&& *s == stamp
+		{
+			return Ok(Some(statuses.clone()));
+		}
+
+		let statuses = Self::compute(&repo, &snapshot, dir, rel, children)?;
+		STATUSES.lock().put(dir.to_owned(), (stamp, statuses.clone()));
+		Ok(Some(statuses))
+	}
+
+	fn compute(
+		repo: &Repo,
+		snapshot: &Snapshot,
+		dir: &Path,
+		rel: &Path,
+		children: Vec<(OsString, bool, Option<SystemTime>)>,
+	) -> io::Result<Statuses> {
+		let prefix = Self::prefix(rel);
+		let join = |path: &[u8]| {
+			if prefix.is_empty() { path.to_vec() } else { [&prefix[..], b"/", path].concat() }
+		};
+
+		let Snapshot { index, head, .. } = snapshot;
+		let mut statuses: HashMap<&[u8], GitStatus> = HashMap::new();
+		let mut tracked = HashSet::new();
+		for (path, entry) in index.under(&prefix) {
+			let child = path.split(|&b| b == b'/').next().unwrap_or(path);
+			tracked.insert(child);
+
+			let staged = head.get(&join(path)) != Some(&(entry.mode, entry.oid)) || entry.intent_to_add;
+			let status = if entry.stage != 0 {
+				Some(GitStatus::Conflicted)
+			} else if Self::modified(&dir.join(Self::os_path(path)), entry) {
+				Some(GitStatus::Modified)
+			} else {
+				staged.then_some(GitStatus::Staged)
+			};
+
+			if let Some(s) = status {
+				statuses.entry(child).and_modify(|t| *t = s.max(*t)).or_insert(s);
+			}
+		}
+
+		// Files deleted from the index but still in HEAD
+		let start = join(b"");
+		for (full, _) in head.range(start.clone()..) {
+			let Some(path) = full.strip_prefix(&start[..]) else { break };
+			if index.contains(full) {
+				continue;
+			}
+
+			let child = path.split(|&b| b == b'/').next().unwrap_or(path);
+			let s = GitStatus::Staged;
+			statuses.entry(child).and_modify(|t| *t = s.max(*t)).or_insert(s);
+		}
+
+		let mut ignores = Self::ignores(repo, rel);
+		let rel = rel.to_string_lossy().replace('\\', "/");
+		let dir_ignored = rel
+			.match_indices('/')
+			.map(|(i, _)| &rel[..i])
+			.chain((!rel.is_empty()).then_some(rel.as_str()))
+			.any(|p| Ignore::ignored(ignores.iter(), p, true));
+
+		let mut result = HashMap::new();
+		for (name, is_dir, _) in children {
+			let bytes = name.as_encoded_bytes();
+			let path = if rel.is_empty() {
+				name.to_string_lossy().into_owned()
+			} else {
+				format!("{rel}/{}", name.to_string_lossy())
+			};
+
+			let status = if let Some(&s) = statuses.get(bytes) {
+				s
+			} else if name == ".git" {
+				continue;
+			} else if tracked.contains(bytes) {
+				// Tracked and clean, unless there're untracked files beneath it
+				if !is_dir || !Self::untracked(index, &mut ignores, &dir.join(&name), &path) {
+					continue;
+				}
+				GitStatus::Untracked
+			} else if dir_ignored || Ignore::ignored(ignores.iter(), &path, is_dir) {
+				GitStatus::Ignored
+			} else {
+				GitStatus::Untracked
+			};
+			result.insert(Path::new(&name).into(), status);
+		}
+		Ok(result)
+	}
+
+	/// `rel` in the work tree as a path in the index.
+	fn prefix(rel: &Path) -> Vec<u8> {
+		rel.components().map(|c| c.as_os_str().as_encoded_bytes()).collect::<Vec<_>>().join(&b'/')
+	}
+
+	/// Entries of `dir`, as `(name, is_dir, mtime)`.
+	fn children(dir: &Path) -> io::Result<Vec<(OsString, bool, Option<SystemTime>)>> {
+		let mut children = vec![];
+		for entry in fs::read_dir(dir)? {
+			let entry = entry?;
+			let meta = entry.metadata().ok();
+			children.push((
+				entry.file_name(),
+				meta.as_ref().is_some_and(|m| m.is_dir()),
+				meta.and_then(|m| m.modified().ok()),
+			));
+		}
+		Ok(children)
+	}
+
+	/// Index and HEAD of the repo at `revision`, read once per revision.
+	fn snapshot(repo: &Repo, revision: Revision) -> io::Result<Arc<Snapshot>> {
+		if let Some(s) = SNAPSHOTS.lock().get(&repo.gitdir)
+			&& s.revision == revision
+		{
+			return Ok(s.clone());
+		}
+
+		let snapshot = Arc::new(Snapshot {
+			revision,
+			index: Index::read(&repo.gitdir.join("index"))?,
+			head: Self::head(repo)?,
+		});
+		SNAPSHOTS.lock().put(repo.gitdir.clone(), snapshot.clone());
+		Ok(snapshot)
+	}
+
+	/// Files in HEAD, as `path => (mode, oid)` relative to the work tree.
+	fn head(repo: &Repo) -> io::Result<BTreeMap<Vec<u8>, (u32, Oid)>> {
+		let mut files = BTreeMap::new();
+		let Some(commit) = repo.head()? else { return Ok(files) };
+
+		let odb = Odb::open(&repo.common)?;
+		let mut stack = vec![(vec![], odb.commit_tree(&commit)?)];
+		while let Some((base, tree)) = stack.pop() {
+			for (mode, name, oid) in odb.tree(&tree)? {
+				let path = if base.is_empty() { name } else { [&base[..], b"/", &name].concat() };
+				if mode == MODE_TREE {
+					stack.push((path, oid));
+				} else {
+					files.insert(path, (mode, oid));
+				}
+			}
+		}
+		Ok(files)
+	}
+
+	/// Whether the tracked directory `dir`, at `rel` in the work tree, has any
+	/// file beneath it that's neither tracked nor ignored.
+	fn untracked(index: &Index, ignores: &mut Vec<Ignore>, dir: &Path, rel: &str) -> bool {
+		let ignore = fs::read_to_string(dir.join(".gitignore")).ok();
+		let pushed = match ignore.and_then(|s| Ignore::parse(rel.to_owned(), &s)) {
+			Some(i) => {
+				ignores.push(i);
+				true
+			}
+			None => false,
+		};
+
+		let found = fs::read_dir(dir).into_iter().flatten().flatten().any(|entry| {
+			let name = entry.file_name();
+			if name == ".git" {
+				return false;
+			}
+
+			let path = format!("{rel}/{}", name.to_string_lossy());
+			let bytes = [rel.as_bytes(), b"/", name.as_encoded_bytes()].concat();
+			let is_dir = entry.file_type().is_ok_and(|t| t.is_dir());
+			if index.contains(&bytes) || Ignore::ignored(ignores.iter(), &path, is_dir) {
+				false
+			} else if is_dir && !entry.path().join(".git").exists() {
+				Self::untracked(index, ignores, &entry.path(), &path)
+			} else {
+				true
+			}
+		});
+
+		if pushed {
+			ignores.pop();
+		}
+		found
+	}
+
+	fn modified(path: &Path, entry: &Entry) -> bool {
+		if entry.skip_worktree || entry.mode == MODE_GITLINK {
+			return false;
+		}
+
+		let Ok(meta) = fs::symlink_metadata(path) else { return true };
+		if meta.is_dir() || meta.len() as u32 != entry.size {
+			return true;
+		}
+
+		let mtime = meta.modified().ok().and_then(|t| t.duration_since(UNIX_EPOCH).ok());
+		if let Some(t) = mtime
+			&& t.as_secs() as u32 == entry.mtime.0
+			&& (entry.mtime.1 == 0 || t.subsec_nanos() == entry.mtime.1)
+		{
+			return false;
+		}
+
+		// Touched but of the same size, compare the content
+		let oid = if meta.is_symlink() {
+			fs::read_link(path).map(|p| Self::hash(p.as_os_str().as_encoded_bytes()))
+		} else {
+			Self::hash_file(path, meta.len())
+		};
+		oid.map_or(true, |oid| oid != entry.oid)
+	}
+
+	fn ignores(repo: &Repo, rel: &Path) -> Vec<Ignore> {
+		let read = |path: &Path| fs::read_to_string(path).ok();
+
+		let mut ignores: Vec<_> =
+			repo.excludes().filter_map(|p| Ignore::parse(String::new(), &read(&p)?)).collect();
+
+		let mut dir = String::new();
+		for comp in [None].into_iter().chain(rel.components().map(Some)) {
+			if let Some(c) = comp {
+				if !dir.is_empty() {
+					dir.push('/');
+				}
+				dir.push_str(&c.as_os_str().to_string_lossy());
+			}
+			let path = repo.workdir.join(&dir).join(".gitignore");
+			ignores.extend(read(&path).and_then(|s| Ignore::parse(dir.clone(), &s)));
+		}
+		ignores
+	}
+
+	fn hash(content: &[u8]) -> Oid {
+		let mut hasher = Sha1::new();
+		hasher.update(format!("blob {}\0", content.len()));
+		hasher.update(content);
+		hasher.finalize().into()
+	}
+
+	/// Hash of the file at `path` of `len` bytes, read in chunks so that large
+	/// files aren't loaded into memory at once.
+	fn hash_file(path: &Path, len: u64) -> io::Result<Oid> {
+		let mut hasher = Sha1::new();
+		hasher.update(format!("blob {len}\0"));
+		io::copy(&mut BufReader::new(fs::File::open(path)?), &mut hasher)?;
+		Ok(hasher.finalize().into())
+	}
+
+	#[cfg(unix)]
+	fn os_path(path: &[u8]) -> &Path {
+		use std::os::unix::ffi::OsStrExt;
+		Path::new(std::ffi::OsStr::from_bytes(path))
+	}
+
+	#[cfg(windows)]
+	fn os_path(path: &[u8]) -> std::path::PathBuf {
+		String::from_utf8_lossy(path).into_owned().into()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::process::Command;
+
+	use super::*;
+
+	fn git(dir: &Path, args: &[&str]) {
+		let status = Command::new("git")
+			.args(["-c", "user.name=yazi", "-c", "user.email=yazi@localhost", "-c", "commit.gpgsign=false"])
+			.args(args)
+			.current_dir(dir)
+			.env("GIT_CONFIG_NOSYSTEM", "1")
+			.output()
+			.unwrap()
+			.status;
+		assert!(status.success(), "git {args:?}");
+	}
+
+	fn write(dir: &Path, path: &str, content: &str) {
+		let path = dir.join(path);
+		fs::create_dir_all(path.parent().unwrap()).unwrap();
+		fs::write(path, content).unwrap();
+	}
+
+	fn status(dir: &Path) -> Vec<(String, GitStatus)> {
+		let mut v: Vec<_> = Git::status(dir)
+			.unwrap()
+			.unwrap()
+			.into_iter()
+			.map(|(k, s)| (String::from_utf8(k.into_encoded_bytes()).unwrap(), s))
+			.collect();
+		v.sort();
+		v
+	}
+
+	fn repo() -> tempfile::TempDir {
+		let tmp = tempfile::tempdir().unwrap();
+		let root = tmp.path();
+		git(root, &["init", "-q"]);
+
+		write(root, ".gitignore", "*.log\n");
+		for path in ["a.txt", "b.txt", "sub/c.txt", "lib/l.txt", "lib/m.txt", "deep/er/d.txt"] {
+			write(root, path, path);
+		}
+		git(root, &["add", "."]);
+		git(root, &["commit", "-qm", "init"]);
+		tmp
+	}
+
+	#[test]
+	fn test_status() {
+		use GitStatus::*;
+		let tmp = repo();
+		let root = tmp.path();
+
+		write(root, "a.txt", "changed");
+		write(root, "e.txt", "");
+		write(root, "x.log", "");
+		write(root, "f.txt", "");
+		git(root, &["add", "f.txt"]);
+		git(root, &["rm", "-q", "lib/l.txt"]);
+
+		// Untracked files in a tracked directory, however deep, but not ignored ones
+		write(root, "sub/new.txt", "");
+		write(root, "deep/er/new/n.txt", "");
+		write(root, "deep/er/x.log", "");
+
+		assert_eq!(status(root), [
+			("a.txt".to_owned(), Modified),
+			("deep".to_owned(), Untracked),
+			("e.txt".to_owned(), Untracked),
+			("f.txt".to_owned(), Staged),
+			("lib".to_owned(), Staged),
+			("sub".to_owned(), Untracked),
+			("x.log".to_owned(), Ignored),
+		]);
+		assert_eq!(status(&root.join("deep/er")), [
+			("new".to_owned(), Untracked),
+			("x.log".to_owned(), Ignored)
+		]);
+
+		// Edits in place are seen despite the cache
+		write(root, "b.txt", "changed too");
+		assert!(status(root).contains(&("b.txt".to_owned(), Modified)));
+
+		// However deep they are
+		assert!(!status(root).contains(&("deep".to_owned(), Modified)));
+		write(root, "deep/er/d.txt", "deep/er/D.txt");
+		assert!(status(root).contains(&("deep".to_owned(), Modified)));
+
+		// Not a work tree
+		let other = tempfile::tempdir().unwrap();
+		assert!(Git::status(other.path()).unwrap().is_none());
+	}
+
+	#[test]
+	fn test_split_index() {
+		let tmp = repo();
+		let root = tmp.path();
+
+		git(root, &["update-index", "--split-index"]);
+		write(root, "b.txt", "changed");
+		write(root, "g.txt", "");
+		git(root, &["add", "b.txt", "g.txt"]);
+		git(root, &["rm", "-q", "--cached", "sub/c.txt"]);
+		assert!(fs::read_dir(root.join(".git")).unwrap().flatten().any(|e| {
+			e.file_name().to_string_lossy().starts_with("sharedindex.")
+		}));
+
+		let index = Index::read(&root.join(".git/index")).unwrap();
+		let paths: Vec<_> = index.under(b"").map(|(p, _)| String::from_utf8_lossy(p)).collect();
+		assert_eq!(paths, [
+			".gitignore",
+			"a.txt",
+			"b.txt",
+			"deep/er/d.txt",
+			"g.txt",
+			"lib/l.txt",
+			"lib/m.txt"
+		]);
+
+		let status = status(root);
+		assert!(status.contains(&("b.txt".to_owned(), GitStatus::Staged)));
+		assert!(status.contains(&("sub".to_owned(), GitStatus::Staged)));
+	}
+
+	#[test]
+	fn test_excludes_file() {
+		let tmp = repo();
+		let root = tmp.path();
+
+		write(root, "y.tmp", "");
+		assert!(status(root).contains(&("y.tmp".to_owned(), GitStatus::Untracked)));
+
+		write(root, ".git/excludes-file", "*.tmp\n");
+		git(root, &["config", "core.excludesFile", root.join(".git/excludes-file").to_str().unwrap()]);
+		write(root, "a.txt", "changed");
+		git(root, &["add", "a.txt"]); // Bump the revision
+		assert!(status(root).contains(&("y.tmp".to_owned(), GitStatus::Ignored)));
+	}
+
+	#[test]
+	fn test_packed() {
+		let tmp = repo();
+		let root = tmp.path();
+
+		// Trees stored as deltas of each other
+		for i in 0..20 {
+			write(root, "lib/m.txt", &i.to_string());
+			write(root, &format!("lib/{i}.txt"), "");
+			git(root, &["add", "."]);
+			git(root, &["commit", "-qm", &i.to_string()]);
+		}
+		git(root, &["gc", "-q", "--aggressive"]);
+		assert!(root.join(".git/objects/pack").read_dir().unwrap().next().is_some());
+
+		assert_eq!(status(root), []);
+		assert_eq!(status(&root.join("lib")), []);
+	}
+}
@@ -0,0 +1,289 @@
+use std::{fs, io::{self, ErrorKind::{InvalidData, NotFound}}, path::Path};
+
+use super::Oid;
+
+#[derive(Clone, Debug, Default)]
+pub(super) struct Entry {
+	pub(super) path:          Vec<u8>,
+	pub(super) mtime:         (u32, u32),
+	pub(super) size:          u32,
+	pub(super) mode:          u32,
+	pub(super) oid:           Oid,
+	pub(super) stage:         u8,
+	pub(super) skip_worktree: bool,
+	pub(super) intent_to_add: bool,
+}
+
+/// Entries of `.git/index`, sorted by path.
+#[derive(Debug, Default)]
+pub(super) struct Index {
+	entries: Vec<Entry>,
+	link:    Option<Link>,
+}
+
+/// The `link` extension of a split index, which only holds the changes made
+/// to the shared index it refers to.
+#[derive(Debug)]
+struct Link {
+	shared:  Oid,
+	delete:  Bitmap,
+	replace: Bitmap,
+}
+
+impl Index {
+	pub(super) fn read(path: &Path) -> io::Result<Self> {
+		let malformed = || io::Error::new(InvalidData, "malformed git index");
+		let mut index = match fs::read(path) {
+			Ok(buf) => Self::parse(&buf).ok_or_else(malformed)?,
+			Err(e) if e.kind() == NotFound => return Ok(Self::default()),
+			Err(e) => return Err(e),
+		};
+
+		if let Some(link) = index.link.take() {
+			let hex: String = link.shared.iter().map(|b| format!("{b:02x}")).collect();
+			let shared = Self::parse(&fs::read(path.with_file_name(format!("sharedindex.{hex}")))?)
+				.ok_or_else(malformed)?;
+			index = shared.merge(index.entries, link).ok_or_else(malformed)?;
+		}
+		Ok(index)
+	}
+
+	/// Entries under the directory `prefix`, relative to the work tree.
+	pub(super) fn under<'a>(&'a self, prefix: &[u8]) -> impl Iterator<Item = (&'a [u8], &'a Entry)> {
+		let mut dir = prefix.to_vec();
+		if !dir.is_empty() {
+			dir.push(b'/');
+		}
+
+		let start = self.entries.partition_point(|e| e.path < dir);
+		self.entries[start..].iter().map_while(move |e| Some((e.path.strip_prefix(dir.as_slice())?, e)))
+	}
+
+	/// Whether `path` relative to the work tree is tracked.
+	pub(super) fn contains(&self, path: &[u8]) -> bool {
+		let i = self.entries.partition_point(|e| e.path.as_slice() < path);
+		self.entries.get(i).is_some_and(|e| e.path == path)
+	}
+
+	pub(super) fn parse(buf: &[u8]) -> Option<Self> {
+		let mut r = Reader { buf: buf.get(..buf.len().checked_sub(20)?)?, pos: 0 };
+		if r.take(4)? != b"DIRC" {
+			return None;
+		}
+
+		let version = r.u32()?;
+		if !(2..=4).contains(&version) {
+			return None;
+		}
+
+		let count = r.u32()? as usize;
+		let mut entries: Vec<Entry> = Vec::with_capacity(count);
+		for _ in 0..count {
+			let start = r.pos;
+			r.take(8)?; // ctime
+			let mtime = (r.u32()?, r.u32()?);
+			r.take(8)?; // dev, ino
+			let mode = r.u32()?;
+			r.take(8)?; // uid, gid
+			let size = r.u32()?;
+			let oid = r.take(20)?.try_into().ok()?;
+
+			let flags = r.u16()?;
+			let extended = if version >= 3 && flags & 0x4000 != 0 { r.u16()? } else { 0 };
+
+			let path = if version == 4 {
+				// Prefix-compressed against the previous path, without padding
+				let strip = r.varint()?;
+				let prev = entries.last().map_or(&[][..], |e| &e.path);
+				let mut path = prev.get(..prev.len().checked_sub(strip)?)?.to_vec();
+				path.extend_from_slice(r.until_nul()?);
+				path
+			} else {
+				let path = r.until_nul()?.to_vec();
+				r.pos = start + ((r.pos - 1 - start + 8) & !7);
+				path
+			};
+
+			entries.push(Entry {
+				path,
+				mtime,
+				size,
+				mode,
+				oid,
+				stage: ((flags >> 12) & 3) as u8,
+				skip_worktree: extended & 0x4000 != 0,
+				intent_to_add: extended & 0x2000 != 0,
+			});
+		}
+
+		let mut link = None;
+		while r.pos < r.buf.len() {
+			let (sig, len) = (r.take(4)?, r.u32()? as usize);
+			let mut ext = Reader { buf: r.take(len)?, pos: 0 };
+			if sig == b"link" {
+				link = Link::parse(&mut ext)?;
+			}
+		}
+		Some(Self { entries, link })
+	}
+
+	/// Applies the changes of a split index to this shared one.
+	fn merge(self, split: Vec<Entry>, link: Link) -> Option<Self> {
+		let mut split = split.into_iter();
+		let mut entries = Vec::with_capacity(self.entries.len() + split.len());
+
+		for (i, shared) in self.entries.into_iter().enumerate() {
+			let entry = if link.replace.get(i) {
+				// Replacements may leave the path out to save space
+				let entry = split.next()?;
+				if entry.path.is_empty() { Entry { path: shared.path, ..entry } } else { entry }
+			} else {
+				shared
+			};
+			if !link.delete.get(i) {
+				entries.push(entry);
+			}
+		}
+
+		entries.extend(split);
+		entries.sort_by(|a, b| a.path.cmp(&b.path).then(a.stage.cmp(&b.stage)));
+		Some(Self { entries, link: None })
+	}
+}
+
+impl Link {
+	fn parse(r: &mut Reader) -> Option<Option<Self>> {
+		let shared: Oid = r.take(20)?.try_into().ok()?;
+		if shared == Oid::default() {
+			return Some(None);
+		}
+
+		let (delete, replace) = if r.pos < r.buf.len() {
+			(Bitmap::parse(r)?, Bitmap::parse(r)?)
+		} else {
+			Default::default()
+		};
+		Some(Some(Self { shared, delete, replace }))
+	}
+}
+
+// --- Bitmap
+/// An uncompressed EWAH bitmap.
+#[derive(Debug, Default)]
+struct Bitmap(Vec<u64>);
+
+impl Bitmap {
+	fn parse(r: &mut Reader) -> Option<Self> {
+		r.u32()?; // size in bits
+		let words = r.u32()? as usize;
+		let mut compressed = Vec::with_capacity(words);
+		for _ in 0..words {
+			compressed.push(u64::from_be_bytes(r.take(8)?.try_into().ok()?));
+		}
+		r.u32()?; // position of the last marker word
+
+		// Each marker word is a run of identical words followed by literal ones
+		let (mut bits, mut it) = (vec![], compressed.into_iter());
+		while let Some(marker) = it.next() {
+			let run = if marker & 1 == 0 { 0 } else { u64::MAX };
+			bits.extend(std::iter::repeat_n(run, ((marker >> 1) & 0xffff_ffff) as usize));
+			for _ in 0..marker >> 33 {
+				bits.push(it.next()?);
+			}
+		}
+		Some(Self(bits))
+	}
+
+	fn get(&self, i: usize) -> bool { self.0.get(i / 64).is_some_and(|w| w & (1 << (i % 64)) != 0) }
+}
+
+// --- Reader
+struct Reader<'a> {
+	buf: &'a [u8],
+	pos: usize,
+}
+
+impl<'a> Reader<'a> {
+	fn take(&mut self, n: usize) -> Option<&'a [u8]> {
+		let b = self.buf.get(self.pos..self.pos.checked_add(n)?)?;
+		self.pos += n;
+		Some(b)
+	}
+
+	fn u16(&mut self) -> Option<u16> { Some(u16::from_be_bytes(self.take(2)?.try_into().ok()?)) }
+
+	fn u32(&mut self) -> Option<u32> { Some(u32::from_be_bytes(self.take(4)?.try_into().ok()?)) }
+
+	fn varint(&mut self) -> Option<usize> {
+		let mut c = self.take(1)?[0];
+		let mut n = (c & 0x7f) as usize;
+		while c & 0x80 != 0 {
+			c = self.take(1)?[0];
+			n = ((n + 1) << 7) | (c & 0x7f) as usize;
+		}
+		Some(n)
+	}
+
+	fn until_nul(&mut self) -> Option<&'a [u8]> {
+		let len = self.buf.get(self.pos..)?.iter().position(|&b| b == 0)?;
+		let s = self.take(len)?;
+		self.pos += 1;
+		Some(s)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn entry(path: &str, flags: u16) -> Vec<u8> {
+		let mut b = vec![0; 40];
+		b.splice(24..28, 0o100644u32.to_be_bytes());
+		b.splice(36..40, 3u32.to_be_bytes());
+		b.extend([7; 20]);
+		b.extend((flags | path.len() as u16).to_be_bytes());
+		b.extend(path.as_bytes());
+		b.resize((b.len() + 8) & !7, 0);
+		b
+	}
+
+	fn index(version: u32, entries: &[Vec<u8>], ext: &[u8]) -> Vec<u8> {
+		let mut b = [&b"DIRC"[..], &version.to_be_bytes(), &(entries.len() as u32).to_be_bytes()].concat();
+		entries.iter().for_each(|e| b.extend(e));
+		b.extend(ext);
+		b.extend([0; 20]); // checksum
+		b
+	}
+
+	#[test]
+	fn test_parse() {
+		let buf = index(2, &[entry("a", 0), entry("dir/b", 0x2000), entry("dir/c", 0)], b"TREE\0\0\0\0");
+		let parsed = Index::parse(&buf).unwrap();
+		assert!(parsed.link.is_none());
+		assert!(parsed.contains(b"dir/b") && !parsed.contains(b"dir"));
+
+		let under: Vec<_> = parsed.under(b"dir").map(|(p, e)| (p, e.stage, e.size)).collect();
+		assert_eq!(under, [(&b"b"[..], 2, 3), (&b"c"[..], 0, 3)]);
+		assert_eq!(parsed.entries[0].oid, [7; 20]);
+
+		// Unsupported or truncated
+		assert!(Index::parse(&index(5, &[], &[])).is_none());
+		assert!(Index::parse(&buf[..buf.len() - 30]).is_none());
+		assert!(Index::parse(b"DIRC").is_none());
+	}
+
+	#[test]
+	fn test_bitmap() {
+		// 2 runs of zeros then 1 literal, and a run of ones
+		let words = [2u64 << 1 | 1 << 33, 0b101, 1 | 1 << 1];
+		let mut buf = [192u32.to_be_bytes(), 3u32.to_be_bytes()].concat();
+		words.iter().for_each(|w| buf.extend(w.to_be_bytes()));
+		buf.extend(2u32.to_be_bytes());
+
+		let bitmap = Bitmap::parse(&mut Reader { buf: &buf, pos: 0 }).unwrap();
+		assert!(!bitmap.get(0) && !bitmap.get(127));
+		assert!(bitmap.get(128) && !bitmap.get(129) && bitmap.get(130));
+		assert!((192..256).all(|i| bitmap.get(i)));
+		assert!(!bitmap.get(256));
+	}
+}
@@ -0,0 +1,359 @@
+use std::{cell::RefCell, fs::{self, File}, io::{self, BufRead, BufReader, ErrorKind::{InvalidData, NotFound}, Read, Seek, SeekFrom}, num::NonZeroUsize, path::{Path, PathBuf}};
+
+use flate2::bufread::ZlibDecoder;
+use lru::LruCache;
+
+pub(super) type Oid = [u8; 20];
+
+pub(super) fn parse_oid(hex: &str) -> Option<Oid> {
+	let mut oid = [0; 20];
+	for (i, b) in oid.iter_mut().enumerate() {
+		*b = u8::from_str_radix(hex.get(i * 2..i * 2 + 2)?, 16).ok()?;
+	}
+	(hex.len() == 40).then_some(oid)
+}
+
+const KIND_COMMIT: u8 = 1;
+const KIND_TREE: u8 = 2;
+
+/// Read-only access to the commits and trees in `.git/objects`, either loose
+/// or packed.
+pub(super) struct Odb {
+	dir:   PathBuf,
+	packs: Vec<Pack>,
+}
+
+impl Odb {
+	pub(super) fn open(common: &Path) -> io::Result<Self> {
+		let dir = common.join("objects");
+		let mut packs = vec![];
+
+		match fs::read_dir(dir.join("pack")) {
+			Ok(it) => {
+				for entry in it {
+					let path = entry?.path();
+					if path.extension().is_some_and(|e| e == "idx")
+						&& let Some(pack) = Pack::open(&path)?
+					{
+						packs.push(pack);
+					}
+				}
+			}
+			Err(e) if e.kind() == NotFound => {}
+			Err(e) => return Err(e),
+		}
+		Ok(Self { dir, packs })
+	}
+
+	/// The root tree of a commit.
+	pub(super) fn commit_tree(&self, oid: &Oid) -> io::Result<Oid> {
+		let data = self.read_kind(oid, KIND_COMMIT)?;
+		data
+			.strip_prefix(b"tree ")
+			.and_then(|s| parse_oid(std::str::from_utf8(s.get(..40)?).ok()?))
+			.ok_or_else(|| io::Error::new(InvalidData, "malformed git commit"))
+	}
+
+	/// Entries of a tree, as `(mode, name, oid)`.
+	pub(super) fn tree(&self, oid: &Oid) -> io::Result<Vec<(u32, Vec<u8>, Oid)>> {
+		let data = self.read_kind(oid, KIND_TREE)?;
+		let (mut entries, mut rest) = (vec![], &data[..]);
+
+		while !rest.is_empty() {
+			let entry = (|| {
+				let space = rest.iter().position(|&b| b == b' ')?;
+				let nul = space + rest[space..].iter().position(|&b| b == 0)?;
+				let mode = u32::from_str_radix(std::str::from_utf8(&rest[..space]).ok()?, 8).ok()?;
+				let oid = rest.get(nul + 1..nul + 21)?.try_into().ok()?;
+				entries.push((mode, rest[space + 1..nul].to_vec(), oid));
+				Some(&rest[nul + 21..])
+			})();
+			rest = entry.ok_or_else(|| io::Error::new(InvalidData, "malformed git tree"))?;
+		}
+		Ok(entries)
+	}
+
+	fn read_kind(&self, oid: &Oid, kind: u8) -> io::Result<Vec<u8>> {
+		match self.read(oid)? {
+			(k, data) if k == kind => Ok(data),
+			_ => Err(io::Error::new(InvalidData, "unexpected git object type")),
+		}
+	}
+
+	fn read(&self, oid: &Oid) -> io::Result<(u8, Vec<u8>)> {
+		let hex: String = oid.iter().map(|b| format!("{b:02x}")).collect();
+		match fs::read(self.dir.join(&hex[..2]).join(&hex[2..])) {
+			Ok(buf) => return Self::loose(&buf),
+			Err(e) if e.kind() == NotFound => {}
+			Err(e) => return Err(e),
+		}
+
+		for pack in &self.packs {
+			if let Some(offset) = pack.find(oid) {
+				return pack.read(self, offset);
+			}
+		}
+		Err(io::Error::new(NotFound, format!("git object {hex} not found")))
+	}
+
+	fn loose(buf: &[u8]) -> io::Result<(u8, Vec<u8>)> {
+		let mut data = vec![];
+		ZlibDecoder::new(buf).read_to_end(&mut data)?;
+
+		let nul = data.iter().position(|&b| b == 0).ok_or_else(|| io::Error::from(InvalidData))?;
+		let kind = match data.split(|&b| b == b' ').next() {
+			Some(b"commit") => KIND_COMMIT,
+			Some(b"tree") => KIND_TREE,
+			_ => 0,
+		};
+		Ok((kind, data.split_off(nul + 1)))
+	}
+}
+
+// --- Pack
+struct Pack {
+	idx:   Vec<u8>,
+	count: usize,
+	file:  File,
+	/// Bases shared by the deltas of the same chain, e.g. versions of a tree.
+	cache: RefCell<LruCache<u64, (u8, Vec<u8>)>>,
+}
+
+impl Pack {
+	const FANOUT: usize = 8;
+	const NAMES: usize = Self::FANOUT + 256 * 4;
+
+	fn open(idx: &Path) -> io::Result<Option<Self>> {
+		let buf = fs::read(idx)?;
+		if !buf.starts_with(b"\xfftOc\0\0\0\x02") || buf.len() < Self::NAMES {
+			return Ok(None); // Only version 2 is supported
+		}
+
+		let count = be32(&buf, Self::FANOUT + 255 * 4).unwrap_or(0) as usize;
+		Ok(Some(Self {
+			idx: buf,
+			count,
+			file: File::open(idx.with_extension("pack"))?,
+			cache: RefCell::new(LruCache::new(NonZeroUsize::new(256).unwrap())),
+		}))
+	}
+
+	fn find(&self, oid: &Oid) -> Option<u64> {
+		let first = oid[0] as usize;
+		let lo = if first == 0 { 0 } else { be32(&self.idx, Self::FANOUT + (first - 1) * 4)? as usize };
+		let hi = be32(&self.idx, Self::FANOUT + first * 4)? as usize;
+
+		let (mut lo, mut hi) = (lo, hi);
+		let i = loop {
+			if lo >= hi {
+				return None;
+			}
+			let mid = (lo + hi) / 2;
+			match self.idx.get(Self::NAMES + mid * 20..Self::NAMES + mid * 20 + 20)?.cmp(oid) {
+				std::cmp::Ordering::Less => lo = mid + 1,
+				std::cmp::Ordering::Greater => hi = mid,
+				std::cmp::Ordering::Equal => break mid,
+			}
+		};
+
+		// Names are followed by CRC32s, then 32-bit offsets, then 64-bit ones
+		let offsets = Self::NAMES + self.count * 24;
+		let offset = be32(&self.idx, offsets + i * 4)?;
+		if offset & 0x8000_0000 == 0 {
+			return Some(offset as u64);
+		}
+
+		let at = offsets + self.count * 4 + (offset & 0x7fff_ffff) as usize * 8;
+		Some(u64::from_be_bytes(self.idx.get(at..at + 8)?.try_into().ok()?))
+	}
+
+	/// Reads the object at `offset`, following its chain of deltas down to the
+	/// nearest base that's either whole or cached.
+	fn read(&self, odb: &Odb, offset: u64) -> io::Result<(u8, Vec<u8>)> {
+		let (mut at, mut deltas) = (offset, vec![]);
+		let (kind, mut data) = loop {
+			if let Some(hit) = self.cache.borrow_mut().get(&at) {
+				break hit.clone();
+			}
+			match self.entry(at)? {
+				Object::Whole(kind, data) => {
+					if !deltas.is_empty() {
+						self.cache.borrow_mut().put(at, (kind, data.clone()));
+					}
+					break (kind, data);
+				}
+				Object::OfsDelta(base, delta) => {
+					deltas.push((at, delta));
+					at = base;
+				}
+				Object::RefDelta(base, delta) => {
+					deltas.push((at, delta));
+					break odb.read(&base)?;
+				}
+			}
+		};
+
+		while let Some((at, delta)) = deltas.pop() {
+			data = patch(&data, &delta).ok_or_else(|| io::Error::from(InvalidData))?;
+			if !deltas.is_empty() {
+				self.cache.borrow_mut().put(at, (kind, data.clone()));
+			}
+		}
+		Ok((kind, data))
+	}
+
+	fn entry(&self, offset: u64) -> io::Result<Object> {
+		let mut file = &self.file;
+		file.seek(SeekFrom::Start(offset))?;
+		let mut r = BufReader::new(file);
+
+		let (kind, size) = header(&mut r)?;
+		Ok(match kind {
+			1..=4 => Object::Whole(kind, inflate(r, size)?),
+			// OFS_DELTA, based on an object earlier in the same pack
+			6 => {
+				let mut c = byte(&mut r)?;
+				let mut back = (c & 0x7f) as u64;
+				while c & 0x80 != 0 {
+					c = byte(&mut r)?;
+					let next = back.checked_add(1).filter(|&b| b.leading_zeros() >= 7);
+					back = (next.ok_or_else(|| io::Error::from(InvalidData))? << 7) | (c & 0x7f) as u64;
+				}
+
+				let base = offset.checked_sub(back).ok_or_else(|| io::Error::from(InvalidData))?;
+				Object::OfsDelta(base, inflate(r, size)?)
+			}
+			// REF_DELTA, based on an object by its ID
+			7 => {
+				let mut base = [0; 20];
+				r.read_exact(&mut base)?;
+				Object::RefDelta(base, inflate(r, size)?)
+			}
+			_ => Err(io::Error::new(InvalidData, "unknown git pack object type"))?,
+		})
+	}
+}
+
+enum Object {
+	Whole(u8, Vec<u8>),
+	OfsDelta(u64, Vec<u8>),
+	RefDelta(Oid, Vec<u8>),
+}
+
+fn be32(buf: &[u8], at: usize) -> Option<u32> {
+	Some(u32::from_be_bytes(buf.get(at..at + 4)?.try_into().ok()?))
+}
+
+/// Type and inflated size of a pack object.
+fn header(r: &mut impl Read) -> io::Result<(u8, usize)> {
+	let mut c = byte(r)?;
+	let kind = (c >> 4) & 7;
+	let (mut size, mut shift) = ((c & 0xf) as usize, 4);
+	while c & 0x80 != 0 {
+		if shift >= usize::BITS {
+			return Err(io::Error::new(InvalidData, "malformed git pack object header"));
+		}
+		c = byte(r)?;
+		size |= ((c & 0x7f) as usize) << shift;
+		shift += 7;
+	}
+	Ok((kind, size))
+}
+
+fn byte(r: &mut impl Read) -> io::Result<u8> {
+	let mut b = [0];
+	r.read_exact(&mut b)?;
+	Ok(b[0])
+}
+
+fn inflate(r: impl BufRead, size: usize) -> io::Result<Vec<u8>> {
+	let mut data = Vec::with_capacity(size);
+	ZlibDecoder::new(r).take(size as u64).read_to_end(&mut data)?;
+	Ok(data)
+}
+
+/// Applies a git delta to its base object.
+fn patch(base: &[u8], delta: &[u8]) -> Option<Vec<u8>> {
+	let mut i = 0;
+	let mut varint = || {
+		let (mut n, mut shift) = (0, 0);
+		loop {
+			let c = *delta.get(i)?;
+			i += 1;
+			if shift >= usize::BITS {
+				return None;
+			}
+			n |= ((c & 0x7f) as usize) << shift;
+			shift += 7;
+			if c & 0x80 == 0 {
+				return Some(n);
+			}
+		}
+	};
+
+	if varint()? != base.len() {
+		return None;
+	}
+	let len = varint()?;
+
+	let mut out = Vec::with_capacity(len);
+	while let Some(&c) = delta.get(i) {
+		i += 1;
+		if c & 0x80 != 0 {
+			let (mut off, mut n) = (0, 0);
+			for b in 0..4 {
+				if c & (1 << b) != 0 {
+					off |= (*delta.get(i)? as usize) << (8 * b);
+					i += 1;
+				}
+			}
+			for b in 0..3 {
+				if c & (0x10 << b) != 0 {
+					n |= (*delta.get(i)? as usize) << (8 * b);
+					i += 1;
+				}
+			}
+			out.extend_from_slice(base.get(off..off + if n == 0 { 0x10000 } else { n })?);
+		} else if c != 0 {
+			out.extend_from_slice(delta.get(i..i + c as usize)?);
+			i += c as usize;
+		} else {
+			return None;
+		}
+	}
+	(out.len() == len).then_some(out)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_patch() {
+		let delta = [&[11, 11, 0x90, 5, 6][..], b" there"].concat();
+		assert_eq!(patch(b"hello world", &delta).unwrap(), b"hello there");
+
+		assert!(patch(b"hello", &delta).is_none());
+		assert!(patch(b"hello world", &delta[..delta.len() - 1]).is_none());
+
+		// Sizes longer than they can be
+		assert!(patch(b"", &[0x80; 20]).is_none());
+	}
+
+	#[test]
+	fn test_header() {
+		// A blob of 0x1234 bytes
+		assert_eq!(header(&mut &[0xb4, 0xa3, 0x02][..]).unwrap(), (3, 0x1234));
+
+		let e = header(&mut &[0xff; 20][..]).unwrap_err();
+		assert_eq!(e.kind(), InvalidData);
+		assert_eq!(header(&mut &[0xb4][..]).unwrap_err().kind(), io::ErrorKind::UnexpectedEof);
+	}
+
+	#[test]
+	fn test_parse_oid() {
+		let oid = parse_oid("0123456789abcdef0123456789abcdef01234567").unwrap();
+		assert_eq!(oid[..3], [0x01, 0x23, 0x45]);
+		assert!(parse_oid("0123").is_none());
+	}
+}
@@ -0,0 +1,150 @@
+use std::{fs, io::{self, ErrorKind::NotFound}, path::{Path, PathBuf}, time::SystemTime};
+
+use super::{Oid, parse_oid};
+
+pub(super) struct Repo {
+	pub(super) workdir: PathBuf,
+	pub(super) gitdir:  PathBuf,
+	/// Shared by all work trees of the repo, where objects and refs live.
+	pub(super) common:  PathBuf,
+}
+
+impl Repo {
+	pub(super) fn discover(dir: &Path) -> Option<Self> {
+		if dir.components().any(|c| c.as_os_str() == ".git") {
+			return None;
+		}
+
+		for workdir in dir.ancestors() {
+			let dotgit = workdir.join(".git");
+			let Ok(meta) = fs::metadata(&dotgit) else { continue };
+
+			// Linked work trees and submodules have a `.git` file pointing to the gitdir
+			let gitdir = if meta.is_dir() {
+				dotgit
+			} else {
+				let s = fs::read_to_string(&dotgit).ok()?;
+				workdir.join(s.strip_prefix("gitdir:")?.trim())
+			};
+
+			let common = match fs::read_to_string(gitdir.join("commondir")) {
+				Ok(s) => gitdir.join(s.trim()),
+				Err(_) => gitdir.clone(),
+			};
+			return Some(Self { workdir: workdir.to_owned(), gitdir, common });
+		}
+		None
+	}
+
+	/// The commit HEAD points to, `None` if the branch is unborn.
+	pub(super) fn head(&self) -> io::Result<Option<Oid>> {
+		let head = fs::read_to_string(self.gitdir.join("HEAD"))?;
+		let Some(name) = head.trim().strip_prefix("ref:").map(str::trim) else {
+			return Ok(parse_oid(head.trim()));
+		};
+
+		for dir in [&self.gitdir, &self.common] {
+			match fs::read_to_string(dir.join(name)) {
+				Ok(s) => return Ok(parse_oid(s.trim())),
+				Err(e) if e.kind() == NotFound => {}
+				Err(e) => return Err(e),
+			}
+		}
+
+		match fs::read_to_string(self.common.join("packed-refs")) {
+			Ok(s) => Ok(
+				s.lines()
+					.filter_map(|l| l.split_once(' '))
+					.find(|&(_, r)| r == name)
+					.and_then(|(h, _)| parse_oid(h)),
+			),
+			Err(e) if e.kind() == NotFound => Ok(None),
+			Err(e) => Err(e),
+		}
+	}
+
+	/// Identifies the state of the index and HEAD, which statuses are cached by.
+	pub(super) fn revision(&self) -> io::Result<Revision> {
+		let index = match fs::metadata(self.gitdir.join("index")) {
+			Ok(m) => Some((m.modified()?, m.len())),
+			Err(e) if e.kind() == NotFound => None,
+			Err(e) => return Err(e),
+		};
+		Ok(Revision { index, head: self.head()? })
+	}
+
+	/// Exclude files applying to the whole work tree, from the lowest priority
+	/// to the highest.
+	pub(super) fn excludes(&self) -> impl Iterator<Item = PathBuf> {
+		let global = match self.config("core", "excludesfile") {
+			Some(s) => match s.strip_prefix("~/") {
+				Some(rest) => dirs::home_dir().map(|h| h.join(rest)),
+				None => Some(self.workdir.join(s)),
+			},
+			None => Self::xdg().map(|p| p.join("git/ignore")),
+		};
+
+		global.into_iter().chain([self.common.join("info/exclude")])
+	}
+
+	/// Value of `section.key` in the repo, user or XDG config, whichever comes
+	/// first, without following includes.
+	fn config(&self, section: &str, key: &str) -> Option<String> {
+		let files = [
+			Some(self.common.join("config")),
+			dirs::home_dir().map(|h| h.join(".gitconfig")),
+			Self::xdg().map(|p| p.join("git/config")),
+		];
+
+		files.into_iter().flatten().find_map(|p| Self::config_in(&fs::read_to_string(p).ok()?, section, key))
+	}
+
+	fn config_in(content: &str, section: &str, key: &str) -> Option<String> {
+		let (mut inside, mut value) = (false, None);
+		for line in content.lines().map(str::trim) {
+			if let Some(header) = line.strip_prefix('[') {
+				let name = header.split([']', ' ', '"']).next().unwrap_or_default();
+				inside = name.eq_ignore_ascii_case(section) && !header.contains('"');
+			} else if inside
+				&& let Some((k, v)) = line.split_once('=')
+				&& k.trim().eq_ignore_ascii_case(key)
+			{
+				// The last one wins
+				let v = v.split([';', '#']).next().unwrap_or_default().trim();
+				value = Some(v.trim_matches('"').to_owned());
+			}
+		}
+		value
+	}
+
+	fn xdg() -> Option<PathBuf> {
+		std::env::var_os("XDG_CONFIG_HOME")
+			.map(PathBuf::from)
+			.or_else(|| dirs::home_dir().map(|h| h.join(".config")))
+	}
+}
+
+// --- Revision
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(super) struct Revision {
+	index: Option<(SystemTime, u64)>,
+	head:  Option<Oid>,
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_config_in() {
+		let content = "[user]\n\tname = a\n[core]\n\texcludesFile = ~/.ignore ; comment\n\
+			[core \"sub\"]\n\texcludesfile = b\n[Core]\n\tbare = false\n";
+
+		assert_eq!(Repo::config_in(content, "core", "excludesfile").as_deref(), Some("~/.ignore"));
+		assert_eq!(Repo::config_in(content, "core", "bare").as_deref(), Some("false"));
+		assert_eq!(Repo::config_in(content, "user", "email"), None);
+
+		let content = "[core]\nexcludesfile = \"a\"\nexcludesfile = b\n";
+		assert_eq!(Repo::config_in(content, "core", "excludesfile").as_deref(), Some("b"));
+	}
+}
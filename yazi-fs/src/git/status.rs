@@ -0,0 +1,13 @@
+use strum::{EnumString, IntoStaticStr};
+
+/// Status of a file in a git work tree, ordered by significance, where a
+/// directory takes the most significant one of the files beneath it.
+#[derive(Clone, Copy, Debug, EnumString, Eq, IntoStaticStr, Ord, PartialEq, PartialOrd)]
+#[strum(serialize_all = "kebab-case")]
+pub enum GitStatus {
+	Ignored,
+	Untracked,
+	Staged,
+	Modified,
+	Conflicted,
+}
@@ -0,0 +1,119 @@
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
+
+/// Rules of a single `.gitignore` or `.ignore` file.
+pub struct Ignore {
+	/// Directory containing the file, relative to the root, e.g. of a search or
+	/// a git work tree.
+	dir:   String,
+	set:   GlobSet,
+	rules: Vec<Rule>,
+}
+
+struct Rule {
+	negate:   bool,
+	dir_only: bool,
+}
+
+impl Ignore {
+	pub fn parse(dir: String, content: &str) -> Option<Self> {
+		let (mut builder, mut rules) = (GlobSetBuilder::new(), vec![]);
+
+		for line in content.lines() {
+			let line = line.trim_end();
+			if line.is_empty() || line.starts_with('#') {
+				continue;
+			}
+
+			let (negate, line) = match line.strip_prefix('!') {
+				Some(s) => (true, s),
+				None => (false, line.strip_prefix('\\').unwrap_or(line)),
+			};
+			let (dir_only, line) = match line.strip_suffix('/') {
+				Some(s) => (true, s),
+				None => (false, line),
+			};
+
+			// Patterns with a slash are relative to the directory, otherwise match at any
+			// level
+			let glob = match line.strip_prefix('/') {
+				Some(s) => s.to_owned(),
+				None if line.contains('/') => line.to_owned(),
+				None => format!("**/{line}"),
+			};
+
+			let Ok(glob) = GlobBuilder::new(&glob).literal_separator(true).build() else { continue };
+			builder.add(glob);
+			rules.push(Rule { negate, dir_only });
+		}
+
+		if rules.is_empty() {
+			return None;
+		}
+		Some(Self { dir, set: builder.build().ok()?, rules })
+	}
+
+	/// Whether `path` relative to the root is ignored, `None` if no rule
+	/// applies to it.
+	pub fn matched(&self, path: &str, is_dir: bool) -> Option<bool> {
+		let path =
+			if self.dir.is_empty() { path } else { path.strip_prefix(&self.dir)?.strip_prefix('/')? };
+
+		self
+			.set
+			.matches(path)
+			.into_iter()
+			.rev()
+			.map(|i| &self.rules[i])
+			.find(|r| is_dir || !r.dir_only)
+			.map(|r| !r.negate)
+	}
+
+	/// Whether `path` is ignored by the innermost rule that applies to it.
+	pub fn ignored<'a>(
+		stack: impl DoubleEndedIterator<Item = &'a Self>,
+		path: &str,
+		is_dir: bool,
+	) -> bool {
+		stack.rev().find_map(|i| i.matched(path, is_dir)).unwrap_or(false)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_matched() {
+		let ignore = Ignore::parse(String::new(), "# comment\n*.log\n!keep.log\n/build\nout/\ndocs/*.md\n").unwrap();
+
+		assert_eq!(ignore.matched("a.log", false), Some(true));
+		assert_eq!(ignore.matched("sub/a.log", false), Some(true));
+		assert_eq!(ignore.matched("keep.log", false), Some(false));
+		assert_eq!(ignore.matched("a.txt", false), None);
+
+		// Anchored to the directory of the file
+		assert_eq!(ignore.matched("build", true), Some(true));
+		assert_eq!(ignore.matched("sub/build", true), None);
+		assert_eq!(ignore.matched("docs/a.md", false), Some(true));
+		assert_eq!(ignore.matched("docs/sub/a.md", false), None);
+
+		// Trailing slashes only match directories
+		assert_eq!(ignore.matched("out", true), Some(true));
+		assert_eq!(ignore.matched("out", false), None);
+
+		assert!(Ignore::parse(String::new(), "# only comments\n\n").is_none());
+	}
+
+	#[test]
+	fn test_ignored() {
+		let outer = Ignore::parse(String::new(), "*.log\n").unwrap();
+		let inner = Ignore::parse("sub".to_owned(), "!a.log\n/b.txt\n").unwrap();
+		let stack = [outer, inner];
+
+		assert!(Ignore::ignored(stack.iter(), "x.log", false));
+		assert!(Ignore::ignored(stack.iter(), "sub/x.log", false));
+		assert!(!Ignore::ignored(stack.iter(), "sub/a.log", false));
+		assert!(Ignore::ignored(stack.iter(), "sub/b.txt", false));
+		assert!(!Ignore::ignored(stack.iter(), "b.txt", false));
+	}
+}
@@ -2,10 +2,10 @@ use std::path::Path;
 
 use hashbrown::{HashMap, HashSet};
 use yazi_macro::relay;
-use yazi_shared::{Id, Ids, path::PathBufDyn, url::{UrlBuf, UrlLike, UrlMapExt}};
+use yazi_shared::{Id, Ids, path::PathBufDyn, pool::Symbol, url::{UrlBuf, UrlLike, UrlMapExt}};
 
 use super::File;
-use crate::{cha::Cha, error::Error};
+use crate::{cha::Cha, error::Error, git::GitStatus};
 
 pub static FILES_TICKET: Ids = Ids::new();
 
@@ -15,6 +15,8 @@ pub enum FilesOp {
 	Part(UrlBuf, Vec<File>, Id),
 	Done(UrlBuf, Cha, Id),
 	Size(UrlBuf, HashMap<PathBufDyn, u64>),
+	Rank(UrlBuf, Symbol<str>, u64, HashMap<PathBufDyn, u32>),
+	Git(UrlBuf, HashMap<PathBufDyn, GitStatus>),
 	IOErr(UrlBuf, Error),
 
 	Creating(UrlBuf, Vec<File>),
@@ -31,6 +33,8 @@ impl FilesOp {
 			Self::Part(u, ..) => u,
 			Self::Done(u, ..) => u,
 			Self::Size(u, _) => u,
+			Self::Rank(u, ..) => u,
+			Self::Git(u, _) => u,
 			Self::IOErr(u, _) => u,
 
 			Self::Creating(u, _) => u,
@@ -112,6 +116,8 @@ impl FilesOp {
 			Self::Part(_, files, ticket) => Self::Part(w, files!(files), *ticket),
 			Self::Done(_, cha, ticket) => Self::Done(w, *cha, *ticket),
 			Self::Size(_, map) => Self::Size(w, map.iter().map(|(urn, &s)| (urn.clone(), s)).collect()),
+			Self::Rank(_, plugin, revision, map) => Self::Rank(w, plugin.clone(), *revision, map.clone()),
+			Self::Git(_, map) => Self::Git(w, map.clone()),
 			Self::IOErr(_, err) => Self::IOErr(w, err.clone()),
 
 			Self::Creating(_, files) => Self::Creating(w, files!(files)),
@@ -0,0 +1,44 @@
+use std::{fmt, io};
+
+/// A copy interrupted by a connection problem or a [`Pause`](super::Pause),
+/// the destination is known to be intact up to `offset`, so the copy can be
+/// resumed from there.
+#[derive(Debug)]
+pub struct Interrupted {
+	pub offset: u64,
+	source:     io::Error,
+}
+
+impl fmt::Display for Interrupted {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{} (interrupted at {} bytes)", self.source, self.offset)
+	}
+}
+
+impl std::error::Error for Interrupted {
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> { Some(&self.source) }
+}
+
+impl Interrupted {
+	/// Marks `e` as interrupting a copy at `offset`, if it's a pause or a
+	/// connection problem that may go away.
+	pub fn wrap(e: io::Error, offset: u64) -> io::Error {
+		use io::ErrorKind as K;
+
+		match e.kind() {
+			K::Interrupted
+			| K::ConnectionReset
+			| K::ConnectionAborted
+			| K::NotConnected
+			| K::BrokenPipe
+			| K::TimedOut
+			| K::UnexpectedEof => io::Error::new(e.kind(), Self { offset, source: e }),
+			_ => e,
+		}
+	}
+
+	/// The offset to resume from, if `e` is an interrupted copy.
+	pub fn offset_of(e: &io::Error) -> Option<u64> {
+		e.get_ref()?.downcast_ref::<Self>().map(|i| i.offset)
+	}
+}
@@ -2,7 +2,7 @@ use std::{io, path::PathBuf};
 
 use tokio::{select, sync::{mpsc, oneshot}};
 
-use crate::provider::Attrs;
+use crate::provider::{Attrs, CopyOpt};
 
 pub(super) async fn copy_impl(from: PathBuf, to: PathBuf, attrs: Attrs) -> io::Result<u64> {
 	#[cfg(any(target_os = "linux", target_os = "android"))]
@@ -48,10 +48,68 @@ pub(super) async fn copy_impl(from: PathBuf, to: PathBuf, attrs: Attrs) -> io::R
 	}
 }
 
+/// Bytes copied between checks of the [`Pause`](crate::provider::Pause) and
+/// the [`Rate`](crate::provider::Rate).
+const PER_CHUNK: u64 = 8 * 1024 * 1024;
+
+fn copy_chunked_impl(from: PathBuf, to: PathBuf, opt: &CopyOpt) -> io::Result<u64> {
+	use std::io::{Read, Write};
+
+	let CopyOpt { attrs, rate, pause } = opt;
+	let mut opts = std::fs::OpenOptions::new();
+	#[cfg(unix)]
+	if let Some(mode) = attrs.mode {
+		use std::os::unix::fs::OpenOptionsExt;
+		opts.mode(mode.bits() as _);
+	}
+
+	let mut reader = std::fs::File::open(from)?;
+	let mut writer = opts.write(true).create(true).truncate(true).open(to)?;
+
+	let mut buf = vec![];
+	let mut written = 0;
+	loop {
+		pause.check(written)?;
+
+		// Unlimited chunks keep the fast path, e.g. `copy_file_range` on Linux
+		let mut chunk = (&mut reader).take(PER_CHUNK);
+		let n = if rate.get() > 0 {
+			buf.resize(65536, 0);
+			let mut n = 0;
+			loop {
+				let m = chunk.read(&mut buf)?;
+				if m == 0 {
+					break n;
+				}
+
+				rate.acquire_blocking(m as u64);
+				writer.write_all(&buf[..m])?;
+				n += m as u64;
+			}
+		} else {
+			std::io::copy(&mut chunk, &mut writer)?
+		};
+
+		if n == 0 {
+			break;
+		}
+		written += n;
+	}
+
+	if let Ok(perm) = (*attrs).try_into() {
+		writer.set_permissions(perm).ok();
+	}
+	if let Ok(times) = (*attrs).try_into() {
+		writer.set_times(times).ok();
+	}
+
+	Ok(written)
+}
+
 pub(super) fn copy_with_progress_impl(
 	from: PathBuf,
 	to: PathBuf,
-	attrs: Attrs,
+	opt: CopyOpt,
 ) -> mpsc::Receiver<Result<u64, io::Error>> {
 	let (prog_tx, prog_rx) = mpsc::channel(20);
 	let (done_tx, mut done_rx) = oneshot::channel();
@@ -59,7 +117,19 @@ pub(super) fn copy_with_progress_impl(
 	tokio::spawn({
 		let to = to.clone();
 		async move {
-			done_tx.send(copy_impl(from, to, attrs).await).ok();
+			// Only limited or pausable copies go in chunks, others keep the native fast path,
+			// and elsewhere a pausable one does too, e.g. `clonefile` on macOS, so it's only
+			// paused between files
+			let chunked = opt.rate.get() > 0
+				|| (cfg!(any(target_os = "linux", target_os = "android")) && opt.pause.armed());
+			let res = if chunked {
+				tokio::task::spawn_blocking(move || copy_chunked_impl(from, to, &opt))
+					.await
+					.unwrap_or_else(|e| Err(e.into()))
+			} else {
+				copy_impl(from, to, opt.attrs).await
+			};
+			done_tx.send(res).ok();
 		}
 	});
 
@@ -98,3 +168,45 @@ pub(super) fn copy_with_progress_impl(
 
 	prog_rx
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::provider::Interrupted;
+
+	#[test]
+	fn test_pause() {
+		let tmp = tempfile::tempdir().unwrap();
+		let (from, to) = (tmp.path().join("from"), tmp.path().join("to"));
+		let data: Vec<u8> = (0..PER_CHUNK + 100).map(|i| (i % 251) as u8).collect();
+		std::fs::write(&from, &data).unwrap();
+
+		let opt = CopyOpt::default();
+		opt.pause.set(true);
+		let e = copy_chunked_impl(from.clone(), to.clone(), &opt).unwrap_err();
+		assert_eq!(e.kind(), io::ErrorKind::Interrupted);
+		assert_eq!(Interrupted::offset_of(&e), Some(0));
+
+		opt.pause.set(false);
+		let n = copy_chunked_impl(from, to.clone(), &opt).unwrap();
+		assert_eq!(n, data.len() as u64);
+		assert_eq!(std::fs::read(to).unwrap(), data);
+	}
+
+	#[test]
+	fn test_rate() {
+		let tmp = tempfile::tempdir().unwrap();
+		let (from, to) = (tmp.path().join("from"), tmp.path().join("to"));
+		let data = vec![1; 2 * PER_CHUNK as usize];
+		std::fs::write(&from, &data).unwrap();
+
+		// A limit set through another handle applies to the copy, high enough not to wait
+		let opt = CopyOpt::default();
+		opt.rate.clone().set(u64::MAX);
+		assert!(!opt.rate.drawn());
+
+		assert_eq!(copy_chunked_impl(from, to.clone(), &opt).unwrap(), data.len() as u64);
+		assert!(opt.rate.drawn());
+		assert_eq!(std::fs::read(to).unwrap(), data);
+	}
+}
@@ -3,7 +3,7 @@ use std::{io, path::Path, sync::Arc};
 use tokio::sync::mpsc;
 use yazi_shared::{path::{AsPath, PathBufDyn}, scheme::SchemeKind, strand::AsStrand, url::{Url, UrlBuf, UrlCow}};
 
-use crate::{cha::{Cha, ChaMode}, provider::{Attrs, Capabilities, Provider}};
+use crate::{cha::{Cha, ChaMode}, provider::{Attrs, Capabilities, CopyOpt, Provider}};
 
 #[derive(Clone)]
 pub struct Local<'a> {
@@ -45,14 +45,14 @@ impl<'a> Provider for Local<'a> {
 		super::copy_impl(from, to, attrs).await
 	}
 
-	fn copy_with_progress<P, A>(&self, to: P, attrs: A) -> io::Result<mpsc::Receiver<io::Result<u64>>>
+	fn copy_with_progress<P, O>(&self, to: P, opt: O) -> io::Result<mpsc::Receiver<io::Result<u64>>>
 	where
 		P: AsPath,
-		A: Into<Attrs>,
+		O: Into<CopyOpt>,
 	{
 		let to = to.as_path().to_os_owned()?;
 		let from = self.path.to_owned();
-		Ok(super::copy_with_progress_impl(from, to, attrs.into()))
+		Ok(super::copy_with_progress_impl(from, to, opt.into()))
 	}
 
 	#[inline]
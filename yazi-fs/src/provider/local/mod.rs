@@ -1 +1 @@
-yazi_macro::mod_flat!(absolute calculator casefold copier dir_entry gate identical local read_dir);
+yazi_macro::mod_flat!(absolute calculator casefold copier dir_entry gate identical local read_dir trash);
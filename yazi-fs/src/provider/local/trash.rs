@@ -0,0 +1,405 @@
+use std::{io, path::{Path, PathBuf}, sync::LazyLock, time::SystemTime};
+
+use hashbrown::HashMap;
+use parking_lot::RwLock;
+use yazi_shared::url::{UrlBuf, UrlLike};
+
+static TRASHED: LazyLock<RwLock<HashMap<PathBuf, Trashed>>> = LazyLock::new(Default::default);
+
+pub struct Trash;
+
+#[derive(Clone, Debug)]
+pub struct Trashed {
+	/// Where the item currently lives inside the trash.
+	pub path:    PathBuf,
+	/// Where the item lived before it was trashed.
+	pub origin:  PathBuf,
+	pub deleted: Option<SystemTime>,
+}
+
+impl Trashed {
+	/// The URL of the item in the trash view, rooted at the trash it lives in,
+	/// since items on other mounts aren't under the home trash.
+	pub fn url(&self) -> Option<UrlBuf> {
+		let files = UrlBuf::from(self.path.parent()?.to_owned()).into_search("trash").ok()?;
+		files.try_join(self.path.file_name()?).ok()
+	}
+}
+
+impl Trash {
+	/// The `files` directory of the home trash, where trashed items live.
+	pub fn dir() -> Option<PathBuf> {
+		if cfg!(all(unix, not(target_os = "macos"), not(target_os = "ios"), not(target_os = "android")))
+		{
+			dirs::data_dir().map(|p| p.join("Trash/files"))
+		} else {
+			None
+		}
+	}
+
+	/// The virtual folder that lists all trashed items.
+	pub fn view() -> Option<UrlBuf> { UrlBuf::from(Self::dir()?).into_search("trash").ok() }
+
+	/// Lists all items in the trash, across the trash directories of all mounts.
+	pub async fn list() -> io::Result<Vec<Trashed>> {
+		let items = tokio::task::spawn_blocking(Self::list_impl).await??;
+
+		let mut cache = TRASHED.write();
+		cache.clear();
+		cache.extend(items.iter().map(|t| (t.path.clone(), t.clone())));
+		Ok(items)
+	}
+
+	/// Looks up a listed item by its path inside the trash.
+	pub fn lookup(path: &Path) -> Option<Trashed> { TRASHED.read().get(path).cloned() }
+
+	/// Restores the most recently trashed item that originally lived at `path`.
+	pub async fn restore<P>(path: P) -> io::Result<()>
+	where
+		P: AsRef<Path>,
+	{
+		let path = path.as_ref().to_owned();
+		tokio::task::spawn_blocking(move || Self::restore_impl(path)).await?
+	}
+
+	/// Moves the item at `path` inside the trash back to where it came from,
+	/// returning its original location.
+	pub async fn recover<P>(path: P) -> io::Result<PathBuf>
+	where
+		P: AsRef<Path>,
+	{
+		let path = path.as_ref().to_owned();
+		let origin = tokio::task::spawn_blocking({
+			let path = path.clone();
+			move || Self::recover_impl(path)
+		})
+		.await??;
+
+		TRASHED.write().remove(&path);
+		Ok(origin)
+	}
+
+	/// Permanently deletes the item at `path` inside the trash,
+	/// along with its `.trashinfo` file.
+	pub async fn purge<P>(path: P) -> io::Result<()>
+	where
+		P: AsRef<Path>,
+	{
+		let path = path.as_ref().to_owned();
+		tokio::task::spawn_blocking({
+			let path = path.clone();
+			move || Self::purge_impl(path)
+		})
+		.await??;
+
+		TRASHED.write().remove(&path);
+		Ok(())
+	}
+
+	#[cfg(target_os = "windows")]
+	fn restore_impl(path: PathBuf) -> io::Result<()> {
+		use trash::os_limited;
+
+		let item = os_limited::list()
+			.map_err(io::Error::other)?
+			.into_iter()
+			.filter(|item| item.original_path() == path)
+			.max_by_key(|item| item.time_deleted)
+			.ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "No such item in the trash"))?;
+
+		os_limited::restore_all([item]).map_err(io::Error::other)
+	}
+
+	#[cfg(not(any(
+		target_os = "windows",
+		all(unix, not(target_os = "macos"), not(target_os = "ios"), not(target_os = "android"))
+	)))]
+	fn restore_impl(_path: PathBuf) -> io::Result<()> {
+		Err(io::Error::new(io::ErrorKind::Unsupported, "Unsupported OS for trash restoration"))
+	}
+}
+
+// --- FreeDesktop
+#[cfg(all(unix, not(target_os = "macos"), not(target_os = "ios"), not(target_os = "android")))]
+impl Trash {
+	fn list_impl() -> io::Result<Vec<Trashed>> {
+		Ok(Self::roots()?.iter().flat_map(|root| Self::list_in(root)).collect())
+	}
+
+	fn restore_impl(origin: PathBuf) -> io::Result<()> {
+		let roots = Self::roots()?;
+		let item = roots
+			.iter()
+			.flat_map(|root| Self::list_in(root))
+			.filter(|item| item.origin == origin)
+			.max_by_key(|item| item.deleted)
+			.ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "No such item in the trash"))?;
+
+		Self::recover_in(&roots, &item.path).map(|_| ())
+	}
+
+	fn recover_impl(path: PathBuf) -> io::Result<PathBuf> { Self::recover_in(&Self::roots()?, &path) }
+
+	fn purge_impl(path: PathBuf) -> io::Result<()> { Self::purge_in(&Self::roots()?, &path) }
+
+	/// The home trash and the trash directories of all mounts.
+	fn roots() -> io::Result<Vec<PathBuf>> {
+		Ok(trash::os_limited::trash_folders().map_err(io::Error::other)?.into_iter().collect())
+	}
+
+	fn list_in(root: &Path) -> Vec<Trashed> {
+		let Ok(it) = std::fs::read_dir(root.join("info")) else { return vec![] };
+
+		it.flatten()
+			.map(|entry| entry.path())
+			.filter(|info| info.extension().is_some_and(|e| e == "trashinfo"))
+			.filter_map(|info| {
+				let (origin, deleted) = Self::parse_info(root, &std::fs::read(&info).ok()?)?;
+				Some(Trashed { path: Self::path_of(&info)?, origin, deleted })
+			})
+			.collect()
+	}
+
+	fn recover_in(roots: &[PathBuf], path: &Path) -> io::Result<PathBuf> {
+		let (root, info) = Self::info_in(roots, path)?;
+		let (origin, _) = Self::parse_info(root, &std::fs::read(&info)?)
+			.ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "Malformed .trashinfo file"))?;
+
+		if let Some(parent) = origin.parent() {
+			std::fs::create_dir_all(parent)?;
+		}
+		match std::fs::symlink_metadata(&origin) {
+			Ok(_) => Err(io::Error::new(io::ErrorKind::AlreadyExists, "Original location is occupied"))?,
+			Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e)?,
+			Err(_) => {}
+		}
+
+		std::fs::rename(path, &origin)?;
+		std::fs::remove_file(info)?;
+		Ok(origin)
+	}
+
+	fn purge_in(roots: &[PathBuf], path: &Path) -> io::Result<()> {
+		let (_, info) = Self::info_in(roots, path)?;
+
+		match std::fs::symlink_metadata(path) {
+			Ok(m) if m.is_dir() => std::fs::remove_dir_all(path)?,
+			Ok(_) => std::fs::remove_file(path)?,
+			Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+			Err(e) => Err(e)?,
+		}
+
+		std::fs::remove_file(info)
+	}
+
+	/// Ensures `path` lives right under the `files` directory of one of the
+	/// `roots`, and has a matching `.trashinfo`, returning the root and that file.
+	fn info_in<'a>(roots: &[PathBuf], path: &'a Path) -> io::Result<(&'a Path, PathBuf)> {
+		let invalid = || io::Error::new(io::ErrorKind::InvalidInput, "Not an item in the trash");
+
+		let root = path
+			.parent()
+			.filter(|p| p.file_name().is_some_and(|n| n == "files"))
+			.and_then(Path::parent)
+			.filter(|&r| roots.iter().any(|p| p == r))
+			.ok_or_else(invalid)?;
+
+		let info = Self::info_of(path).ok_or_else(invalid)?;
+		match std::fs::symlink_metadata(&info) {
+			Ok(m) if m.is_file() => Ok((root, info)),
+			Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e),
+			_ => Err(invalid()),
+		}
+	}
+
+	/// Parses the original path and deletion time out of a `.trashinfo` file.
+	fn parse_info(root: &Path, b: &[u8]) -> Option<(PathBuf, Option<SystemTime>)> {
+		use std::{ffi::OsStr, os::unix::ffi::OsStrExt};
+
+		let s = String::from_utf8_lossy(b);
+		let mut lines = s.lines().map(str::trim);
+		if lines.next()? != "[Trash Info]" {
+			return None;
+		}
+
+		let (mut origin, mut deleted) = (None, None);
+		for (key, value) in lines.filter_map(|l| l.split_once('=')) {
+			match key.trim() {
+				"Path" => {
+					let value = percent_encoding::percent_decode_str(value.trim()).collect::<Vec<_>>();
+					origin = Some(Path::new(OsStr::from_bytes(&value)).to_owned());
+				}
+				"DeletionDate" => deleted = Self::parse_date(value.trim()),
+				_ => {}
+			}
+		}
+
+		let origin = origin?;
+		if origin.is_absolute() {
+			Some((origin, deleted))
+		} else {
+			Some((Self::topdir_of(root)?.join(origin), deleted))
+		}
+	}
+
+	/// Parses a `YYYY-MM-DDThh:mm:ss` date in local time.
+	fn parse_date(s: &str) -> Option<SystemTime> {
+		use std::time::{Duration, UNIX_EPOCH};
+
+		let mut it = s.split(['-', 'T', ':']).map(|n| n.parse::<libc::c_int>().ok());
+		let mut tm: libc::tm = unsafe { std::mem::zeroed() };
+		tm.tm_year = it.next()?? - 1900;
+		tm.tm_mon = it.next()?? - 1;
+		tm.tm_mday = it.next()??;
+		tm.tm_hour = it.next()??;
+		tm.tm_min = it.next()??;
+		tm.tm_sec = it.next()??;
+		tm.tm_isdst = -1;
+
+		let secs = unsafe { libc::mktime(&mut tm) };
+		Some(UNIX_EPOCH + Duration::from_secs(u64::try_from(secs).ok()?))
+	}
+
+	/// `$topdir/.Trash/$uid` or `$topdir/.Trash-$uid` → `$topdir`
+	fn topdir_of(root: &Path) -> Option<&Path> {
+		let parent = root.parent()?;
+		if root.file_name()?.as_encoded_bytes().starts_with(b".Trash-") {
+			Some(parent)
+		} else if parent.file_name()? == ".Trash" {
+			parent.parent()
+		} else {
+			None
+		}
+	}
+
+	/// `$trash/info/foo.trashinfo` → `$trash/files/foo`
+	fn path_of(info: &Path) -> Option<PathBuf> {
+		let trash = info.parent()?.parent()?;
+		Some(trash.join("files").join(info.file_stem()?))
+	}
+
+	/// `$trash/files/foo` → `$trash/info/foo.trashinfo`
+	fn info_of(path: &Path) -> Option<PathBuf> {
+		let trash = path.parent()?.parent()?;
+		let mut name = path.file_name()?.to_owned();
+		name.push(".trashinfo");
+		Some(trash.join("info").join(name))
+	}
+}
+
+#[cfg(not(all(
+	unix,
+	not(target_os = "macos"),
+	not(target_os = "ios"),
+	not(target_os = "android")
+)))]
+impl Trash {
+	fn list_impl() -> io::Result<Vec<Trashed>> {
+		Err(io::Error::new(io::ErrorKind::Unsupported, "Unsupported OS for trash listing"))
+	}
+
+	fn recover_impl(_path: PathBuf) -> io::Result<PathBuf> {
+		Err(io::Error::new(io::ErrorKind::Unsupported, "Unsupported OS for trash restoration"))
+	}
+
+	fn purge_impl(_path: PathBuf) -> io::Result<()> {
+		Err(io::Error::new(io::ErrorKind::Unsupported, "Unsupported OS for trash purging"))
+	}
+}
+
+#[cfg(all(
+	test,
+	unix,
+	not(target_os = "macos"),
+	not(target_os = "ios"),
+	not(target_os = "android")
+))]
+mod tests {
+	use super::*;
+
+	fn trash(root: &Path, name: &str, origin: &str) -> PathBuf {
+		std::fs::create_dir_all(root.join("files")).unwrap();
+		std::fs::create_dir_all(root.join("info")).unwrap();
+
+		let info = format!("[Trash Info]\nPath={origin}\nDeletionDate=2024-01-02T03:04:05\n");
+		std::fs::write(root.join("info").join(format!("{name}.trashinfo")), info).unwrap();
+		std::fs::write(root.join("files").join(name), name).unwrap();
+		root.join("files").join(name)
+	}
+
+	#[test]
+	fn test_list() {
+		let tmp = tempfile::tempdir().unwrap();
+		let (home, mount) = (tmp.path().join("Trash"), tmp.path().join("mnt/.Trash-1000"));
+
+		let origin = tmp.path().join("a b");
+		trash(&home, "a b", &origin.to_str().unwrap().replace(' ', "%20"));
+		trash(&mount, "c", "dir/c");
+		std::fs::write(home.join("info/stray"), "").unwrap();
+
+		let items = Trash::list_in(&home);
+		assert_eq!(items.len(), 1);
+		assert_eq!(items[0].path, home.join("files/a b"));
+		assert_eq!(items[0].origin, origin);
+		assert!(items[0].deleted.is_some());
+
+		let items = Trash::list_in(&mount);
+		assert_eq!(items[0].origin, tmp.path().join("mnt/dir/c"));
+	}
+
+	#[test]
+	fn test_recover() {
+		let tmp = tempfile::tempdir().unwrap();
+		let root = tmp.path().join("Trash");
+		let (roots, origin) = ([root.clone()], tmp.path().join("sub/a"));
+
+		let path = trash(&root, "a", origin.to_str().unwrap());
+		assert_eq!(Trash::recover_in(&roots, &path).unwrap(), origin);
+		assert_eq!(std::fs::read(&origin).unwrap(), b"a");
+		assert!(!root.join("info/a.trashinfo").exists());
+
+		// Never overwrites what now lives at the original location
+		let path = trash(&root, "a", origin.to_str().unwrap());
+		let err = Trash::recover_in(&roots, &path).unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+		assert!(path.exists());
+	}
+
+	#[test]
+	fn test_purge() {
+		let tmp = tempfile::tempdir().unwrap();
+		let root = tmp.path().join("Trash");
+		let roots = [root.clone()];
+
+		let path = trash(&root, "a", "/a");
+		Trash::purge_in(&roots, &path).unwrap();
+		assert!(!path.exists());
+		assert!(!root.join("info/a.trashinfo").exists());
+
+		// Anything outside a known trash, or without a `.trashinfo`, is left alone
+		let outside = tmp.path().join("files/b");
+		std::fs::create_dir_all(outside.parent().unwrap()).unwrap();
+		std::fs::write(&outside, "b").unwrap();
+		std::fs::write(root.join("files/c"), "c").unwrap();
+
+		for p in [&outside, &root.join("files/c"), &root.join("files/../files/c")] {
+			let err = Trash::purge_in(&roots, p).unwrap_err();
+			assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+			assert!(p.exists());
+		}
+	}
+
+	#[test]
+	fn test_url() {
+		yazi_shared::init_tests();
+
+		let tmp = tempfile::tempdir().unwrap();
+		let path = trash(&tmp.path().join("Trash"), "a", "/a");
+
+		let item = Trashed { path: path.clone(), origin: "/a".into(), deleted: None };
+		let url = item.url().unwrap();
+		assert!(url.is_search());
+		assert_eq!(url.as_local(), Some(path.as_path()));
+		assert_eq!(url.parent().and_then(|u| u.as_local()), path.parent());
+	}
+}
@@ -1,3 +1,3 @@
 yazi_macro::mod_pub!(local);
 
-yazi_macro::mod_flat!(attrs capabilities traits);
+yazi_macro::mod_flat!(attrs capabilities interrupted opt pause rate traits);
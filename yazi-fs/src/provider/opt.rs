@@ -0,0 +1,32 @@
+use crate::{cha::Cha, provider::{Attrs, Pause, Rate}};
+
+/// Options of a copy with progress.
+#[derive(Clone, Debug, Default)]
+pub struct CopyOpt {
+	/// Attributes set on the destination once it's done.
+	pub attrs: Attrs,
+	/// Limit of the throughput, read on every chunk.
+	pub rate:  Rate,
+	/// Checked between chunks to stop the copy halfway.
+	pub pause: Pause,
+}
+
+impl From<Attrs> for CopyOpt {
+	fn from(attrs: Attrs) -> Self { Self { attrs, ..Default::default() } }
+}
+
+impl From<Cha> for CopyOpt {
+	fn from(cha: Cha) -> Self { Attrs::from(cha).into() }
+}
+
+impl CopyOpt {
+	pub fn with_rate(mut self, rate: &Rate) -> Self {
+		self.rate = rate.clone();
+		self
+	}
+
+	pub fn with_pause(mut self, pause: Pause) -> Self {
+		self.pause = pause;
+		self
+	}
+}
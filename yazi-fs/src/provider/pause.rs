@@ -0,0 +1,25 @@
+use std::{io, sync::{Arc, atomic::{AtomicBool, Ordering}}};
+
+use super::Interrupted;
+
+/// A switch shared by a task and its copies in progress, which stop at the
+/// next chunk boundary once it's on, so they can be resumed from there.
+#[derive(Clone, Debug, Default)]
+pub struct Pause(Arc<AtomicBool>);
+
+impl Pause {
+	#[inline]
+	pub fn get(&self) -> bool { self.0.load(Ordering::Relaxed) }
+
+	/// Whether it's held by anyone else, e.g. a task, who can turn it on.
+	#[inline]
+	pub fn armed(&self) -> bool { Arc::strong_count(&self.0) > 1 }
+
+	#[inline]
+	pub fn set(&self, on: bool) { self.0.store(on, Ordering::Relaxed) }
+
+	/// Fails with an [`Interrupted`] at `offset` if it's on.
+	pub fn check(&self, offset: u64) -> io::Result<()> {
+		if self.get() { Err(Interrupted::wrap(io::ErrorKind::Interrupted.into(), offset)) } else { Ok(()) }
+	}
+}
@@ -0,0 +1,97 @@
+use std::{sync::{Arc, atomic::{AtomicU64, Ordering}}, time::{Duration, Instant}};
+
+use parking_lot::Mutex;
+
+/// A token bucket limiting the throughput in bytes per second,
+/// where a limit of `0` means unlimited.
+///
+/// Clones share the same bucket, so copies in progress see a new limit at once.
+#[derive(Clone, Debug, Default)]
+pub struct Rate(Arc<Inner>);
+
+#[derive(Debug, Default)]
+struct Inner {
+	limit:  AtomicU64,
+	bucket: Mutex<Bucket>,
+}
+
+#[derive(Debug, Default)]
+struct Bucket {
+	tokens: f64,
+	last:   Option<Instant>,
+}
+
+impl Rate {
+	pub fn new(limit: u64) -> Self {
+		Self(Arc::new(Inner { limit: AtomicU64::new(limit), ..Default::default() }))
+	}
+
+	#[inline]
+	pub fn get(&self) -> u64 { self.0.limit.load(Ordering::Relaxed) }
+
+	pub fn set(&self, limit: u64) {
+		*self.0.bucket.lock() = Bucket::default();
+		self.0.limit.store(limit, Ordering::Relaxed);
+	}
+
+	/// Waits until `n` bytes are allowed to pass.
+	pub async fn acquire(&self, n: u64) {
+		if let Some(dur) = self.reserve(n) {
+			tokio::time::sleep(dur).await;
+		}
+	}
+
+	/// Same as [`Self::acquire`], but blocks the current thread.
+	pub fn acquire_blocking(&self, n: u64) {
+		if let Some(dur) = self.reserve(n) {
+			std::thread::sleep(dur);
+		}
+	}
+
+	#[inline]
+	fn reserve(&self, n: u64) -> Option<Duration> { self.reserve_at(n, Instant::now()) }
+
+	fn reserve_at(&self, n: u64, now: Instant) -> Option<Duration> {
+		let limit = self.get() as f64;
+		if limit == 0.0 {
+			return None;
+		}
+
+		let mut bucket = self.0.bucket.lock();
+		let elapsed = bucket.last.replace(now).map_or(0.0, |t| (now - t).as_secs_f64());
+
+		// Bursts are capped at one second worth of bytes
+		bucket.tokens = (bucket.tokens + elapsed * limit).min(limit) - n as f64;
+		if bucket.tokens >= 0.0 { None } else { Some(Duration::from_secs_f64(-bucket.tokens / limit)) }
+	}
+
+	#[cfg(test)]
+	pub(crate) fn drawn(&self) -> bool { self.0.bucket.lock().last.is_some() }
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_reserve() {
+		let rate = Rate::default();
+		let now = Instant::now();
+		assert_eq!(rate.reserve_at(u64::MAX, now), None);
+		assert!(!rate.drawn());
+
+		// Shared by clones, and starting with an empty bucket
+		rate.clone().set(100);
+		assert_eq!(rate.get(), 100);
+		assert_eq!(rate.reserve_at(50, now), Some(Duration::from_millis(500)));
+
+		// Refilled over time, but never past one second worth of bytes
+		let now = now + Duration::from_millis(1600);
+		assert_eq!(rate.reserve_at(50, now), None);
+		assert_eq!(rate.reserve_at(100, now), Some(Duration::from_millis(500)));
+
+		// A new limit starts over with an empty bucket
+		rate.set(200);
+		assert_eq!(rate.reserve_at(100, now), Some(Duration::from_millis(500)));
+	}
+}
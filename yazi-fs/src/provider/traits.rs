@@ -4,7 +4,7 @@ use tokio::{io::{AsyncRead, AsyncSeek, AsyncWrite, AsyncWriteExt}, sync::mpsc};
 use yazi_macro::ok_or_not_found;
 use yazi_shared::{path::{AsPath, PathBufDyn}, strand::{AsStrand, StrandCow}, url::{AsUrl, Url, UrlBuf}};
 
-use crate::{cha::{Cha, ChaMode, ChaType}, provider::{Attrs, Capabilities}};
+use crate::{cha::{Cha, ChaMode, ChaType}, provider::{Attrs, Capabilities, CopyOpt}};
 
 pub trait Provider: Sized {
 	type File: AsyncRead + AsyncSeek + AsyncWrite + Unpin;
@@ -25,14 +25,14 @@ pub trait Provider: Sized {
 	where
 		P: AsPath;
 
-	fn copy_with_progress<P, A>(
+	fn copy_with_progress<P, O>(
 		&self,
 		to: P,
-		attrs: A,
+		opt: O,
 	) -> io::Result<mpsc::Receiver<io::Result<u64>>>
 	where
 		P: AsPath,
-		A: Into<Attrs>;
+		O: Into<CopyOpt>;
 
 	fn create(&self) -> impl Future<Output = io::Result<Self::File>> {
 		async move { self.gate().write(true).create(true).truncate(true).open(self.url()).await }
@@ -0,0 +1,208 @@
+/// How many bytes from the start of a file are needed to sniff its MIME type.
+pub const SNIFF_LEN: usize = 4096;
+
+// Magic numbers at the start of the file, in the same normalized form as the
+// `mime.local` fetcher, i.e. without the `x-` and `vnd.` prefixes.
+#[rustfmt::skip]
+const MAGIC: &[(&[u8], &str)] = &[
+	// Images
+	(b"\x89PNG\r\n\x1a\n", "image/png"),
+	(b"\xff\xd8\xff", "image/jpeg"),
+	(b"GIF87a", "image/gif"),
+	(b"GIF89a", "image/gif"),
+	(b"BM", "image/bmp"),
+	(b"II*\0", "image/tiff"),
+	(b"MM\0*", "image/tiff"),
+	(b"\0\0\x01\0", "image/icon"),
+	(b"\xff\x0a", "image/jxl"),
+	(b"\0\0\0\x0cJXL \r\n\x87\n", "image/jxl"),
+	(b"8BPS", "image/adobe.photoshop"),
+	// Audio
+	(b"ID3", "audio/mpeg"),
+	(b"\xff\xfb", "audio/mpeg"),
+	(b"\xff\xf3", "audio/mpeg"),
+	(b"\xff\xf2", "audio/mpeg"),
+	(b"fLaC", "audio/flac"),
+	(b"OggS", "audio/ogg"),
+	(b"MThd", "audio/midi"),
+	// Video
+	(b"FLV\x01", "video/flv"),
+	// Documents
+	(b"%PDF-", "application/pdf"),
+	(b"{\\rtf", "text/rtf"),
+	// Archives
+	(b"PK\x03\x04", "application/zip"),
+	(b"PK\x05\x06", "application/zip"),
+	(b"Rar!\x1a\x07", "application/rar"),
+	(b"7z\xbc\xaf\x27\x1c", "application/7z-compressed"),
+	(b"\x1f\x8b", "application/gzip"),
+	(b"\x1f\x9d", "application/compress"),
+	(b"\xfd7zXZ\0", "application/xz"),
+	(b"\x28\xb5\x2f\xfd", "application/zstd"),
+	(b"BZh", "application/bzip2"),
+	(b"\x5d\0\0", "application/lzma"),
+	(b"!<arch>\n", "application/archive"),
+	(b"070701", "application/cpio"),
+	(b"070707", "application/cpio"),
+	(b"\x60\xea", "application/arj"),
+	(b"xar!", "application/xar"),
+	(b"MSCF", "application/ms-cab-compressed"),
+	// Executables
+	(b"\x7fELF", "application/executable"),
+	(b"MZ", "application/dosexec"),
+	(b"\xcf\xfa\xed\xfe", "application/mach-binary"),
+	(b"\xce\xfa\xed\xfe", "application/mach-binary"),
+	(b"\0asm", "application/wasm"),
+	(b"SQLite format 3\0", "application/sqlite3"),
+	// Fonts
+	(b"\0\x01\0\0\0", "font/sfnt"),
+	(b"OTTO", "font/sfnt"),
+	(b"ttcf", "font/collection"),
+	(b"wOFF", "font/woff"),
+	(b"wOF2", "font/woff2"),
+];
+
+// Brands of the ISO base media file format, i.e. the `ftyp` box at offset 4.
+#[rustfmt::skip]
+const BRANDS: &[(&[u8], &str)] = &[
+	(b"avif", "image/avif"),
+	(b"avis", "image/avif"),
+	(b"heic", "image/heic"),
+	(b"heix", "image/heic"),
+	(b"mif1", "image/heif"),
+	(b"msf1", "image/heif"),
+	(b"M4A ", "audio/mp4"),
+	(b"qt  ", "video/quicktime"),
+	(b"3gp", "video/3gpp"),
+];
+
+// Text files that are better told apart by their extension.
+#[rustfmt::skip]
+const EXTS: &[(&str, &str)] = &[
+	("css", "text/css"),
+	("csv", "text/csv"),
+	("htm", "text/html"),
+	("html", "text/html"),
+	("js", "application/javascript"),
+	("json", "application/json"),
+	("jsonl", "application/ndjson"),
+	("md", "text/markdown"),
+	("mjs", "application/javascript"),
+	("ndjson", "application/ndjson"),
+	("svg", "image/svg+xml"),
+	("toml", "application/toml"),
+	("xml", "text/xml"),
+	("yaml", "application/yaml"),
+	("yml", "application/yaml"),
+];
+
+pub struct Sniffer;
+
+impl Sniffer {
+	/// Guesses the MIME type from the first [`SNIFF_LEN`] bytes of a file,
+	/// using the extension only to refine text files.
+	pub fn mime(head: &[u8], ext: Option<&str>) -> &'static str {
+		let text = Self::is_text(head);
+		if head.is_empty() {
+			return "inode/empty";
+		} else if let Some(mime) = Self::magic(head, text) {
+			return mime;
+		} else if !text {
+			return "application/octet-stream";
+		}
+
+		let ext = ext.map(|s| s.to_ascii_lowercase());
+		if let Some(&(_, mime)) = EXTS.iter().find(|(e, _)| Some(*e) == ext.as_deref()) {
+			mime
+		} else if Self::is_svg(head) {
+			"image/svg+xml"
+		} else {
+			"text/plain"
+		}
+	}
+
+	fn magic(head: &[u8], text: bool) -> Option<&'static str> {
+		// Short ASCII magics like `BM` or `MZ` are too likely to start a text file
+		let weak = |m: &[u8]| text && m.len() < 4 && m.is_ascii();
+		if let Some(&(_, mime)) = MAGIC.iter().find(|(m, _)| head.starts_with(m) && !weak(m)) {
+			return Some(mime);
+		}
+
+		// RIFF containers
+		if head.starts_with(b"RIFF") && head.len() >= 12 {
+			return match &head[8..12] {
+				b"WEBP" => Some("image/webp"),
+				b"WAVE" => Some("audio/wav"),
+				b"AVI " => Some("video/msvideo"),
+				_ => None,
+			};
+		}
+
+		// EBML, used by Matroska and WebM
+		if head.starts_with(b"\x1a\x45\xdf\xa3") {
+			let webm = head.windows(4).any(|w| w == b"webm");
+			return Some(if webm { "video/webm" } else { "video/matroska" });
+		}
+
+		// ISO base media, e.g. MP4, MOV, AVIF and HEIC
+		if head.get(4..8) == Some(b"ftyp") {
+			let brand = head.get(8..12).unwrap_or_default();
+			return Some(
+				BRANDS.iter().find(|(b, _)| brand.starts_with(b)).map_or("video/mp4", |&(_, mime)| mime),
+			);
+		}
+
+		// Tar has its magic at offset 257
+		if head.get(257..262) == Some(b"ustar") {
+			return Some("application/tar");
+		}
+		None
+	}
+
+	fn is_text(head: &[u8]) -> bool {
+		if head.starts_with(b"\xff\xfe") || head.starts_with(b"\xfe\xff") {
+			return true; // UTF-16 BOM
+		} else if head.contains(&0) {
+			return false;
+		}
+
+		// A multibyte character may be cut off at the end
+		match std::str::from_utf8(head) {
+			Ok(_) => true,
+			Err(e) => e.error_len().is_none(),
+		}
+	}
+
+	fn is_svg(head: &[u8]) -> bool {
+		let s = String::from_utf8_lossy(head);
+		let s = s.trim_start_matches('\u{feff}').trim_start();
+		(s.starts_with("<svg") || s.starts_with("<?xml")) && s.contains("<svg")
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_mime() {
+		assert_eq!(Sniffer::mime(b"", None), "inode/empty");
+		assert_eq!(Sniffer::mime(b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR", None), "image/png");
+		assert_eq!(Sniffer::mime(b"RIFF\0\0\0\0WEBPVP8 ", None), "image/webp");
+		assert_eq!(Sniffer::mime(b"\0\0\0\x20ftypisom\0\0\x02\0", None), "video/mp4");
+		assert_eq!(Sniffer::mime(b"\0\0\0\x1cftypavif\0\0\0\0", None), "image/avif");
+		assert_eq!(Sniffer::mime(b"\x00\x01\x02\x03", None), "application/octet-stream");
+
+		let mut tar = vec![0; 512];
+		tar[..4].copy_from_slice(b"file");
+		tar[257..262].copy_from_slice(b"ustar");
+		assert_eq!(Sniffer::mime(&tar, None), "application/tar");
+
+		assert_eq!(Sniffer::mime(b"hello", None), "text/plain");
+		assert_eq!(Sniffer::mime("caf\u{e9}".as_bytes(), None), "text/plain");
+		assert_eq!(Sniffer::mime(&"\u{e9}".as_bytes()[..1], None), "text/plain");
+		assert_eq!(Sniffer::mime(b"{\"a\": 1}", Some("JSON")), "application/json");
+		assert_eq!(Sniffer::mime(b"<?xml version=\"1.0\"?>\n<svg>", None), "image/svg+xml");
+		assert_eq!(Sniffer::mime(b"MZ is a text file", None), "text/plain");
+	}
+}
@@ -2,13 +2,13 @@ use std::cmp::Ordering;
 
 use hashbrown::HashMap;
 use rand::{Rng, make_rng, rngs::SmallRng};
-use yazi_shared::{natsort, path::PathBufDyn, translit::Transliterator, url::UrlLike};
+use yazi_shared::{natsort, path::PathBufDyn, pool::Symbol, translit::Transliterator, url::UrlLike};
 
-use crate::{File, SortBy, SortFallback};
+use crate::{File, SortBy, SortFallback, SortSpec};
 
-#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct FilesSorter {
-	pub by:        SortBy,
+	pub by:        SortSpec,
 	pub sensitive: bool,
 	pub reverse:   bool,
 	pub dir_first: bool,
@@ -17,67 +17,89 @@ pub struct FilesSorter {
 }
 
 impl FilesSorter {
-	pub(super) fn sort(&self, items: &mut [File], sizes: &HashMap<PathBufDyn, u64>) {
+	pub(super) fn sort(
+		&self,
+		items: &mut [File],
+		sizes: &HashMap<PathBufDyn, u64>,
+		mimes: &HashMap<PathBufDyn, Symbol<str>>,
+		ranks: &HashMap<PathBufDyn, u32>,
+	) {
 		if items.is_empty() {
 			return;
 		}
 
-		macro_rules! promote {
-			($a:ident, $b:ident) => {
-				if self.dir_first {
-					match $b.is_dir().cmp(&$a.is_dir()) {
-						Ordering::Equal => {}
-						not_eq => return not_eq,
-					}
-				}
-			};
-		}
+		let promote = |a: &File, b: &File| {
+			if self.dir_first { b.is_dir().cmp(&a.is_dir()) } else { Ordering::Equal }
+		};
 
-		match self.by {
+		match self.by.first() {
 			SortBy::None => {}
-			SortBy::Mtime => items.sort_unstable_by(|a, b| {
-				promote!(a, b);
-				self.fallback(a, b, self.cmp(a.mtime, b.mtime))
-			}),
-			SortBy::Btime => items.sort_unstable_by(|a, b| {
-				promote!(a, b);
-				self.fallback(a, b, self.cmp(a.btime, b.btime))
+			SortBy::Random => {
+				let mut rng = make_rng::<SmallRng>();
+				items.sort_unstable_by(|a, b| {
+					promote(a, b).then_with(|| self.cmp(rng.next_u64(), rng.next_u64()))
+				})
+			}
+			_ => items.sort_unstable_by(|a, b| {
+				promote(a, b).then_with(|| {
+					let ord = self
+						.by
+						.iter()
+						.map(|by| self.sort_by(by, a, b, sizes, mimes, ranks))
+						.find(|&o| o != Ordering::Equal)
+						.unwrap_or(Ordering::Equal);
+					self.fallback(a, b, ord)
+				})
 			}),
-			SortBy::Extension => items.sort_unstable_by(|a, b| {
-				promote!(a, b);
+		}
+	}
+
+	fn sort_by(
+		&self,
+		by: &SortBy,
+		a: &File,
+		b: &File,
+		sizes: &HashMap<PathBufDyn, u64>,
+		mimes: &HashMap<PathBufDyn, Symbol<str>>,
+		ranks: &HashMap<PathBufDyn, u32>,
+	) -> Ordering {
+		match by {
+			SortBy::None | SortBy::Random => Ordering::Equal,
+			SortBy::Mtime => self.cmp(a.mtime, b.mtime),
+			SortBy::Btime => self.cmp(a.btime, b.btime),
+			SortBy::Atime => self.cmp(a.atime, b.atime),
+			SortBy::Ctime => self.cmp(a.ctime, b.ctime),
+			SortBy::Extension => {
 				let aa = a.url.ext().filter(|_| a.is_file());
 				let bb = b.url.ext().filter(|_| b.is_file());
-				let ord = if self.sensitive {
+				if self.sensitive {
 					self.cmp(aa, bb)
 				} else {
 					self.cmp_insensitive(
 						aa.map_or(&[], |s| s.encoded_bytes()),
 						bb.map_or(&[], |s| s.encoded_bytes()),
 					)
-				};
-				self.fallback(a, b, ord)
-			}),
-			SortBy::Alphabetical => items.sort_unstable_by(|a, b| {
-				promote!(a, b);
-				self.fallback(a, b, self.sort_alphabetically(a, b))
-			}),
-			SortBy::Natural => items.sort_unstable_by(|a, b| {
-				promote!(a, b);
-				self.fallback(a, b, self.sort_naturally(a, b))
-			}),
-			SortBy::Size => items.sort_unstable_by(|a, b| {
-				promote!(a, b);
+				}
+			}
+			SortBy::Alphabetical => self.sort_alphabetically(a, b),
+			SortBy::Natural => self.sort_naturally(a, b),
+			SortBy::Version => self.sort_by_version(a, b),
+			SortBy::Size => {
 				let aa = if a.is_dir() { sizes.get(&a.urn()).copied() } else { None };
 				let bb = if b.is_dir() { sizes.get(&b.urn()).copied() } else { None };
-				self.fallback(a, b, self.cmp(aa.unwrap_or(a.len), bb.unwrap_or(b.len)))
-			}),
-			SortBy::Random => {
-				let mut rng = make_rng::<SmallRng>();
-				items.sort_unstable_by(|a, b| {
-					promote!(a, b);
-					self.cmp(rng.next_u64(), rng.next_u64())
-				})
+				self.cmp(aa.unwrap_or(a.len), bb.unwrap_or(b.len))
+			}
+			SortBy::Owner => self.sort_by_owner(a, b),
+			SortBy::Group => self.sort_by_group(a, b),
+			SortBy::Permissions => self.cmp(a.mode.bits() & 0o7777, b.mode.bits() & 0o7777),
+			SortBy::Mime => {
+				self.cmp(mimes.get(&a.urn()).map(|s| s.as_ref()), mimes.get(&b.urn()).map(|s| s.as_ref()))
 			}
+			// Files not ranked by the plugin yet go last
+			SortBy::Plugin(_) => self.cmp(
+				ranks.get(&a.urn()).copied().unwrap_or(u32::MAX),
+				ranks.get(&b.urn()).copied().unwrap_or(u32::MAX),
+			),
 		}
 	}
 
@@ -105,6 +127,43 @@ impl FilesSorter {
 		if self.reverse { ordering.reverse() } else { ordering }
 	}
 
+	/// Compares the numbers in the names, e.g. `app-1.9.tar` comes before
+	/// `app-1.10.tar`, regardless of what's around them.
+	fn sort_by_version(&self, a: &File, b: &File) -> Ordering {
+		fn numbers(s: &[u8]) -> impl Iterator<Item = u64> {
+			s.split(|b| !b.is_ascii_digit()).filter(|s| !s.is_empty()).map(|s| {
+				s.iter().fold(0u64, |n, &d| n.saturating_mul(10).saturating_add((d - b'0') as u64))
+			})
+		}
+
+		let ord = numbers(a.urn().encoded_bytes()).cmp(numbers(b.urn().encoded_bytes()));
+		if self.reverse { ord.reverse() } else { ord }
+	}
+
+	fn sort_by_owner(&self, a: &File, b: &File) -> Ordering {
+		#[cfg(unix)]
+		{
+			use uzers::Users;
+			use yazi_shared::USERS_CACHE;
+			let (aa, bb) = (USERS_CACHE.get_user_by_uid(a.uid), USERS_CACHE.get_user_by_uid(b.uid));
+			self.cmp(aa.as_ref().map(|u| u.name()), bb.as_ref().map(|u| u.name()))
+		}
+		#[cfg(not(unix))]
+		self.cmp(a.uid, b.uid)
+	}
+
+	fn sort_by_group(&self, a: &File, b: &File) -> Ordering {
+		#[cfg(unix)]
+		{
+			use uzers::Groups;
+			use yazi_shared::USERS_CACHE;
+			let (aa, bb) = (USERS_CACHE.get_group_by_gid(a.gid), USERS_CACHE.get_group_by_gid(b.gid));
+			self.cmp(aa.as_ref().map(|g| g.name()), bb.as_ref().map(|g| g.name()))
+		}
+		#[cfg(not(unix))]
+		self.cmp(a.gid, b.gid)
+	}
+
 	#[inline(always)]
 	fn fallback(&self, a: &File, b: &File, ord: Ordering) -> Ordering {
 		if ord != Ordering::Equal {
@@ -1,22 +1,138 @@
-use serde::{Deserialize, Serialize};
+use std::{fmt::{self, Display}, str::FromStr};
+
+use anyhow::{Result, anyhow, bail};
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
 use strum::{EnumString, IntoStaticStr};
+use yazi_shared::pool::{InternStr, Symbol};
 
 // --- by
-#[derive(
-	Clone, Copy, Debug, Default, Deserialize, EnumString, Eq, IntoStaticStr, PartialEq, Serialize,
-)]
-#[serde(rename_all = "kebab-case")]
+#[derive(Clone, Debug, Default, EnumString, Eq, IntoStaticStr, PartialEq)]
 #[strum(serialize_all = "kebab-case")]
 pub enum SortBy {
 	#[default]
 	None,
 	Mtime,
 	Btime,
+	Atime,
+	Ctime,
 	Extension,
 	Alphabetical,
 	Natural,
+	Version,
 	Size,
+	Owner,
+	Group,
+	Permissions,
+	Mime,
 	Random,
+	/// Sorted by the keys a plugin provides, written as `plugin:<name>`.
+	#[strum(disabled)]
+	Plugin(Symbol<str>),
+}
+
+impl SortBy {
+	fn parse(s: &str) -> Result<Self> {
+		match s.strip_prefix("plugin:") {
+			Some(name) if name.is_empty() || name.contains(char::is_whitespace) => {
+				bail!("invalid sort plugin `{name}`")
+			}
+			Some(name) => Ok(Self::Plugin(name.intern())),
+			None => s.parse().map_err(|_| anyhow!("invalid sort key `{s}`")),
+		}
+	}
+}
+
+impl Display for SortBy {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Plugin(name) => write!(f, "plugin:{name}"),
+			_ => f.write_str(self.into()),
+		}
+	}
+}
+
+// --- spec
+/// Up to [`SortSpec::MAX`] keys like `mtime,extension`, where later keys break
+/// ties of the earlier ones.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SortSpec([SortBy; Self::MAX]);
+
+impl SortSpec {
+	pub const MAX: usize = 4;
+
+	pub fn iter(&self) -> impl Iterator<Item = &SortBy> {
+		self.0.iter().take_while(|&by| *by != SortBy::None)
+	}
+
+	#[inline]
+	pub fn first(&self) -> &SortBy { &self.0[0] }
+
+	#[inline]
+	pub fn contains(&self, by: SortBy) -> bool { self.iter().any(|b| *b == by) }
+
+	/// The plugin providing sort keys, at most one is allowed.
+	pub fn plugin(&self) -> Option<&Symbol<str>> {
+		self.iter().find_map(|by| if let SortBy::Plugin(name) = by { Some(name) } else { None })
+	}
+}
+
+impl From<SortBy> for SortSpec {
+	fn from(value: SortBy) -> Self {
+		let mut keys: [SortBy; Self::MAX] = Default::default();
+		keys[0] = value;
+		Self(keys)
+	}
+}
+
+impl FromStr for SortSpec {
+	type Err = anyhow::Error;
+
+	fn from_str(s: &str) -> Result<Self> {
+		let mut keys: [SortBy; Self::MAX] = Default::default();
+		for (i, key) in s.split(',').map(str::trim).enumerate() {
+			let by = SortBy::parse(key)?;
+			if i >= Self::MAX {
+				bail!("at most {} sort keys are allowed", Self::MAX);
+			} else if by == SortBy::None && s.contains(',') {
+				bail!("`none` can't be combined with other sort keys");
+			} else if keys[..i].contains(&by) {
+				bail!("duplicate sort key `{key}`");
+			} else if matches!(by, SortBy::Plugin(_))
+				&& keys[..i].iter().any(|k| matches!(k, SortBy::Plugin(_)))
+			{
+				bail!("only one plugin is allowed in sort keys");
+			}
+			keys[i] = by;
+		}
+		Ok(Self(keys))
+	}
+}
+
+impl Display for SortSpec {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		if *self.first() == SortBy::None {
+			return SortBy::None.fmt(f);
+		}
+		for (i, by) in self.iter().enumerate() {
+			if i > 0 {
+				f.write_str(",")?;
+			}
+			by.fmt(f)?;
+		}
+		Ok(())
+	}
+}
+
+impl Serialize for SortSpec {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.collect_str(self)
+	}
+}
+
+impl<'de> Deserialize<'de> for SortSpec {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		String::deserialize(deserializer)?.parse().map_err(de::Error::custom)
+	}
 }
 
 // --- fallback
@@ -30,3 +146,23 @@ pub enum SortFallback {
 	Alphabetical,
 	Natural,
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_parse() {
+		yazi_shared::init_tests();
+		let spec: SortSpec = "mtime, plugin:git-status".parse().unwrap();
+		assert_eq!(spec.plugin().map(|s| &**s), Some("git-status"));
+		assert_eq!(spec.to_string(), "mtime,plugin:git-status");
+
+		// Unknown words are typos, not plugins
+		assert!("mtiem".parse::<SortSpec>().is_err());
+		assert!("plugin:".parse::<SortSpec>().is_err());
+		assert!("plugin:a,plugin:b".parse::<SortSpec>().is_err());
+		assert!("none,size".parse::<SortSpec>().is_err());
+		assert!("size,size".parse::<SortSpec>().is_err());
+	}
+}
@@ -2,7 +2,7 @@
 macro_rules! confirm {
 	($cx:ident, $cfg:expr) => {{
 		let token = yazi_shared::CompletionToken::default();
-		match $crate::act!(confirm:show, $cx, yazi_parser::confirm::ShowForm { cfg: $cfg, token: token.clone() }) {
+		match $crate::act!(confirm:show, $cx, yazi_parser::confirm::ShowForm { cfg: $cfg, token: token.clone(), replier: None }) {
 			Ok(_) => Ok(token),
 			Err(e) => Err(e)
 		}
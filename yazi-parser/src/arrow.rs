@@ -12,7 +12,15 @@ pub struct ArrowForm {
 impl TryFrom<ActionCow> for ArrowForm {
 	type Error = anyhow::Error;
 
-	fn try_from(a: ActionCow) -> Result<Self, Self::Error> { Ok(a.deserialize()?) }
+	fn try_from(a: ActionCow) -> Result<Self, Self::Error> {
+		let count = a.get("count").ok();
+		let mut me: Self = a.deserialize()?;
+
+		if let Some(n) = count {
+			me.step = me.step.times(n);
+		}
+		Ok(me)
+	}
 }
 
 impl From<isize> for ArrowForm {
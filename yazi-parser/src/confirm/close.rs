@@ -1,17 +1,21 @@
 use mlua::{ExternalError, FromLua, IntoLua, Lua, Value};
-use yazi_shared::event::ActionCow;
+use yazi_shared::{SStr, event::ActionCow};
 
 #[derive(Debug, Default)]
 pub struct CloseForm {
 	pub submit: bool,
+	pub choice: Option<SStr>,
+	pub all:    bool,
 }
 
 impl From<ActionCow> for CloseForm {
-	fn from(a: ActionCow) -> Self { Self { submit: a.bool("submit") } }
+	fn from(mut a: ActionCow) -> Self {
+		Self { submit: a.bool("submit"), choice: a.take("choice").ok(), all: a.bool("all") }
+	}
 }
 
 impl From<bool> for CloseForm {
-	fn from(submit: bool) -> Self { Self { submit } }
+	fn from(submit: bool) -> Self { Self { submit, ..Default::default() } }
 }
 
 impl FromLua for CloseForm {
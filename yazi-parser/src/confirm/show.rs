@@ -1,12 +1,14 @@
 use anyhow::bail;
 use mlua::{ExternalError, FromLua, IntoLua, Lua, Value};
+use tokio::sync::mpsc;
 use yazi_config::popup::ConfirmCfg;
 use yazi_shared::{CompletionToken, event::ActionCow};
 
 #[derive(Debug)]
 pub struct ShowForm {
-	pub cfg:   ConfirmCfg,
-	pub token: CompletionToken,
+	pub cfg:     ConfirmCfg,
+	pub token:   CompletionToken,
+	pub replier: Option<mpsc::UnboundedSender<(&'static str, bool)>>,
 }
 
 impl TryFrom<ActionCow> for ShowForm {
@@ -21,7 +23,7 @@ impl TryFrom<ActionCow> for ShowForm {
 			bail!("Invalid 'token' in ShowForm");
 		};
 
-		Ok(Self { cfg, token })
+		Ok(Self { cfg, token, replier: a.take_any("choice") })
 	}
 }
 
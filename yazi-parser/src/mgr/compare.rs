@@ -0,0 +1,28 @@
+use mlua::{ExternalError, FromLua, IntoLua, Lua, Value};
+use serde::Deserialize;
+use yazi_shared::event::ActionCow;
+
+#[derive(Debug, Default, Deserialize)]
+pub struct CompareForm {
+	/// Index of the tab to compare with, the next one if not specified.
+	#[serde(alias = "0")]
+	pub tab:     Option<usize>,
+	#[serde(default)]
+	pub content: bool,
+	#[serde(default)]
+	pub select:  bool,
+}
+
+impl TryFrom<ActionCow> for CompareForm {
+	type Error = anyhow::Error;
+
+	fn try_from(a: ActionCow) -> Result<Self, Self::Error> { Ok(a.deserialize()?) }
+}
+
+impl FromLua for CompareForm {
+	fn from_lua(_: Value, _: &Lua) -> mlua::Result<Self> { Err("unsupported".into_lua_err()) }
+}
+
+impl IntoLua for CompareForm {
+	fn into_lua(self, _: &Lua) -> mlua::Result<Value> { Err("unsupported".into_lua_err()) }
+}
@@ -0,0 +1,25 @@
+use anyhow::anyhow;
+use mlua::{ExternalError, FromLua, IntoLua, Lua, Value};
+use yazi_core::mgr::CompareOpt;
+use yazi_shared::event::ActionCow;
+
+#[derive(Clone, Debug)]
+pub struct CompareDoForm {
+	pub opt: CompareOpt,
+}
+
+impl TryFrom<ActionCow> for CompareDoForm {
+	type Error = anyhow::Error;
+
+	fn try_from(mut a: ActionCow) -> Result<Self, Self::Error> {
+		Ok(Self { opt: a.take_any("opt").ok_or_else(|| anyhow!("Invalid 'opt' in CompareDoForm"))? })
+	}
+}
+
+impl FromLua for CompareDoForm {
+	fn from_lua(_: Value, _: &Lua) -> mlua::Result<Self> { Err("unsupported".into_lua_err()) }
+}
+
+impl IntoLua for CompareDoForm {
+	fn into_lua(self, _: &Lua) -> mlua::Result<Value> { Err("unsupported".into_lua_err()) }
+}
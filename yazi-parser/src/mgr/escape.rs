@@ -5,11 +5,12 @@ use yazi_shared::event::ActionCow;
 bitflags! {
 	#[derive(Debug)]
 	pub struct EscapeForm: u8 {
-		const FIND   = 0b00001;
-		const VISUAL = 0b00010;
-		const FILTER = 0b00100;
-		const SELECT = 0b01000;
-		const SEARCH = 0b10000;
+		const FIND    = 0b000001;
+		const VISUAL  = 0b000010;
+		const FILTER  = 0b000100;
+		const SELECT  = 0b001000;
+		const SEARCH  = 0b010000;
+		const COMPARE = 0b100000;
 	}
 }
 
@@ -23,6 +24,7 @@ impl From<ActionCow> for EscapeForm {
 				("filter", true) => acc | Self::FILTER,
 				("select", true) => acc | Self::SELECT,
 				("search", true) => acc | Self::SEARCH,
+				("compare", true) => acc | Self::COMPARE,
 				_ => acc,
 			}
 		})
@@ -4,15 +4,16 @@ use yazi_shared::event::ActionCow;
 
 #[derive(Debug)]
 pub struct FindForm {
-	pub prev: bool,
-	pub case: FilterCase,
+	pub prev:  bool,
+	pub case:  FilterCase,
+	pub fuzzy: bool,
 }
 
 impl TryFrom<ActionCow> for FindForm {
 	type Error = anyhow::Error;
 
 	fn try_from(a: ActionCow) -> Result<Self, Self::Error> {
-		Ok(Self { prev: a.bool("previous"), case: FilterCase::from(&*a) })
+		Ok(Self { prev: a.bool("previous"), case: FilterCase::from(&*a), fuzzy: a.bool("fuzzy") })
 	}
 }
 
@@ -3,11 +3,14 @@ use yazi_shared::event::ActionCow;
 
 #[derive(Debug)]
 pub struct FindArrowForm {
-	pub prev: bool,
+	pub prev:  bool,
+	pub count: usize,
 }
 
 impl From<ActionCow> for FindArrowForm {
-	fn from(a: ActionCow) -> Self { Self { prev: a.bool("previous") } }
+	fn from(a: ActionCow) -> Self {
+		Self { prev: a.bool("previous"), count: a.get("count").unwrap_or(1) }
+	}
 }
 
 impl FromLua for FindArrowForm {
@@ -0,0 +1,27 @@
+use mlua::{FromLua, IntoLua, Lua, LuaSerdeExt, Value};
+use serde::{Deserialize, Serialize};
+use yazi_binding::SER_OPT;
+use yazi_shared::event::ActionCow;
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct MacroPlayForm {
+	#[serde(alias = "0")]
+	pub reg:   char,
+	/// Number of times to replay the macro.
+	#[serde(default)]
+	pub count: Option<usize>,
+}
+
+impl TryFrom<ActionCow> for MacroPlayForm {
+	type Error = anyhow::Error;
+
+	fn try_from(a: ActionCow) -> Result<Self, Self::Error> { Ok(a.deserialize()?) }
+}
+
+impl FromLua for MacroPlayForm {
+	fn from_lua(value: Value, lua: &Lua) -> mlua::Result<Self> { lua.from_value(value) }
+}
+
+impl IntoLua for MacroPlayForm {
+	fn into_lua(self, lua: &Lua) -> mlua::Result<Value> { lua.to_value_with(&self, SER_OPT) }
+}
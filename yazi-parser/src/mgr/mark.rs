@@ -0,0 +1,46 @@
+use mlua::{FromLua, IntoLua, Lua, LuaSerdeExt, Value};
+use serde::{Deserialize, Serialize};
+use yazi_binding::SER_OPT;
+use yazi_shared::event::ActionCow;
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct MarkForm {
+	#[serde(alias = "0")]
+	pub action: MarkFormAction,
+	/// The mark to act on, asked for interactively if not specified.
+	#[serde(alias = "1")]
+	pub key:    Option<char>,
+}
+
+impl TryFrom<ActionCow> for MarkForm {
+	type Error = anyhow::Error;
+
+	fn try_from(a: ActionCow) -> Result<Self, Self::Error> { Ok(a.deserialize()?) }
+}
+
+impl FromLua for MarkForm {
+	fn from_lua(value: Value, lua: &Lua) -> mlua::Result<Self> { lua.from_value(value) }
+}
+
+impl IntoLua for MarkForm {
+	fn into_lua(self, lua: &Lua) -> mlua::Result<Value> { lua.to_value_with(&self, SER_OPT) }
+}
+
+// --- Action
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum MarkFormAction {
+	Set,
+	Jump,
+	Delete,
+}
+
+impl MarkFormAction {
+	pub fn as_str(self) -> &'static str {
+		match self {
+			Self::Set => "set",
+			Self::Jump => "jump",
+			Self::Delete => "delete",
+		}
+	}
+}
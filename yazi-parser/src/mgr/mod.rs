@@ -2,6 +2,8 @@ yazi_macro::mod_flat!(
 	bulk_exit
 	cd
 	close
+	compare
+	compare_do
 	copy
 	create
 	displace_do
@@ -16,6 +18,9 @@ yazi_macro::mod_flat!(
 	hover
 	linemode
 	link
+	macro_play
+	macro_record
+	mark
 	open
 	open_do
 	paste
@@ -29,6 +34,7 @@ yazi_macro::mod_flat!(
 	sort
 	spot
 	stash
+	sync
 	tab_close
 	tab_create
 	tab_rename
@@ -1,13 +1,29 @@
 use mlua::{ExternalError, FromLua, IntoLua, Lua, Value};
 use serde::Deserialize;
+use yazi_scheduler::file::Conflict;
 use yazi_shared::event::ActionCow;
 
 #[derive(Debug, Deserialize)]
 pub struct PasteForm {
 	#[serde(default)]
-	pub force:  bool,
+	pub force:    bool,
 	#[serde(default)]
-	pub follow: bool,
+	pub follow:   bool,
+	#[serde(default)]
+	pub conflict: Option<Conflict>,
+	/// Compares each copied file with its source by checksum, a mismatch fails
+	/// the file and leaves the source of a cut in place.
+	#[serde(default)]
+	pub verify:   bool,
+}
+
+impl PasteForm {
+	/// `--force` is a shorthand for `--conflict=overwrite`, otherwise each
+	/// conflicting file is prompted for, after merging into existing directories
+	/// of the same name, use `--conflict=rename` to paste them alongside instead.
+	pub fn conflict(&self) -> Conflict {
+		self.conflict.unwrap_or(if self.force { Conflict::Overwrite } else { Conflict::Ask })
+	}
 }
 
 impl TryFrom<ActionCow> for PasteForm {
@@ -11,7 +11,15 @@ pub struct SeekForm {
 impl TryFrom<ActionCow> for SeekForm {
 	type Error = anyhow::Error;
 
-	fn try_from(a: ActionCow) -> Result<Self, Self::Error> { Ok(a.deserialize()?) }
+	fn try_from(a: ActionCow) -> Result<Self, Self::Error> {
+		let count: Option<i16> = a.get("count").ok();
+		let mut me: Self = a.deserialize()?;
+
+		if let Some(n) = count {
+			me.units = me.units.saturating_mul(n);
+		}
+		Ok(me)
+	}
 }
 
 impl FromLua for SeekForm {
@@ -1,13 +1,13 @@
 use mlua::{FromLua, IntoLua, Lua, LuaSerdeExt, Value};
 use serde::{Deserialize, Serialize};
 use yazi_binding::SER_OPT;
-use yazi_fs::{SortBy, SortFallback};
+use yazi_fs::{SortFallback, SortSpec};
 use yazi_shared::event::ActionCow;
 
 #[derive(Debug, Default, Deserialize, Serialize)]
 pub struct SortForm {
 	#[serde(alias = "0")]
-	pub by:        Option<SortBy>,
+	pub by:        Option<SortSpec>,
 	pub reverse:   Option<bool>,
 	#[serde(alias = "dir-first")]
 	pub dir_first: Option<bool>,
@@ -0,0 +1,30 @@
+use mlua::{ExternalError, FromLua, IntoLua, Lua, Value};
+use serde::Deserialize;
+use yazi_shared::event::ActionCow;
+
+#[derive(Debug, Deserialize)]
+pub struct SyncForm {
+	/// Index of the tab to sync into, the next one if not specified.
+	#[serde(alias = "0")]
+	pub tab:     Option<usize>,
+	#[serde(default)]
+	pub delete:  bool,
+	#[serde(alias = "dry-run", default)]
+	pub dry_run: bool,
+	#[serde(default)]
+	pub verify:  bool,
+}
+
+impl TryFrom<ActionCow> for SyncForm {
+	type Error = anyhow::Error;
+
+	fn try_from(a: ActionCow) -> Result<Self, Self::Error> { Ok(a.deserialize()?) }
+}
+
+impl FromLua for SyncForm {
+	fn from_lua(_: Value, _: &Lua) -> mlua::Result<Self> { Err("unsupported".into_lua_err()) }
+}
+
+impl IntoLua for SyncForm {
+	fn into_lua(self, _: &Lua) -> mlua::Result<Value> { Err("unsupported".into_lua_err()) }
+}
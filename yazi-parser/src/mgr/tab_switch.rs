@@ -8,6 +8,9 @@ pub struct TabSwitchForm {
 	pub step:     isize,
 	#[serde(default)]
 	pub relative: bool,
+	/// Repeats a relative step, or switches to the `count`th tab otherwise.
+	#[serde(default)]
+	pub count:    Option<usize>,
 }
 
 impl TryFrom<ActionCow> for TabSwitchForm {
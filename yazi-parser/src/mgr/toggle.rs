@@ -5,6 +5,8 @@ use yazi_shared::{event::ActionCow, url::UrlBuf};
 pub struct ToggleForm {
 	pub url:   Option<UrlBuf>,
 	pub state: Option<bool>,
+	/// Number of files to toggle from the hovered one, ignored if `url` is given.
+	pub count: usize,
 }
 
 impl From<ActionCow> for ToggleForm {
@@ -16,6 +18,7 @@ impl From<ActionCow> for ToggleForm {
 				Ok("off") => Some(false),
 				_ => None,
 			},
+			count: a.get("count").unwrap_or(1),
 		}
 	}
 }
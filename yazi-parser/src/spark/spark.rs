@@ -32,6 +32,8 @@ pub enum Spark<'a> {
 	BulkRename(crate::VoidForm),
 	Cd(crate::mgr::CdForm),
 	Close(crate::mgr::CloseForm),
+	Compare(crate::mgr::CompareForm),
+	CompareDo(crate::mgr::CompareDoForm),
 	Copy(crate::mgr::CopyForm),
 	Create(crate::mgr::CreateForm),
 	Displace(crate::VoidForm),
@@ -39,6 +41,7 @@ pub enum Spark<'a> {
 	Download(crate::mgr::DownloadForm),
 	Enter(crate::VoidForm),
 	Escape(crate::mgr::EscapeForm),
+	EscapeCompare(crate::VoidForm),
 	EscapeFilter(crate::VoidForm),
 	EscapeFind(crate::VoidForm),
 	EscapeSearch(crate::VoidForm),
@@ -57,11 +60,16 @@ pub enum Spark<'a> {
 	Leave(crate::VoidForm),
 	Linemode(crate::mgr::LinemodeForm),
 	Link(crate::mgr::LinkForm),
+	MacroPlay(crate::mgr::MacroPlayForm),
+	MacroRecord(crate::mgr::MacroRecordForm),
+	MacroStop(crate::VoidForm),
+	Mark(crate::mgr::MarkForm),
 	Open(crate::mgr::OpenForm),
 	OpenDo(crate::mgr::OpenDoForm),
 	Paste(crate::mgr::PasteForm),
 	Peek(crate::mgr::PeekForm),
 	Quit(crate::app::QuitForm),
+	Redo(crate::VoidForm),
 	Refresh(crate::VoidForm),
 	Remove(crate::mgr::RemoveForm),
 	RemoveDo(crate::mgr::RemoveForm),
@@ -76,6 +84,7 @@ pub enum Spark<'a> {
 	Spot(crate::mgr::SpotOpt),
 	Stash(crate::mgr::StashForm),
 	Suspend(crate::VoidForm),
+	Sync(crate::mgr::SyncForm),
 	TabClose(crate::mgr::TabCloseForm),
 	TabCreate(crate::mgr::TabCreateForm),
 	TabRename(crate::mgr::TabRenameForm),
@@ -83,6 +92,10 @@ pub enum Spark<'a> {
 	TabSwitch(crate::mgr::TabSwitchForm),
 	Toggle(crate::mgr::ToggleForm),
 	ToggleAll(crate::mgr::ToggleAllForm),
+	Trash(crate::VoidForm),
+	TrashEmpty(crate::VoidForm),
+	TrashRestore(crate::VoidForm),
+	Undo(crate::VoidForm),
 	Unyank(crate::VoidForm),
 	UpdateFiles(crate::mgr::UpdateFilesForm),
 	UpdateMimes(crate::mgr::UpdateMimesForm),
@@ -147,9 +160,12 @@ pub enum Spark<'a> {
 	TasksClose(crate::VoidForm),
 	TasksInspect(crate::VoidForm),
 	TasksOpenShellCompat(crate::tasks::ProcessOpenForm),
+	TasksPause(crate::VoidForm),
 	TasksProcessOpen(crate::tasks::ProcessOpenForm),
+	TasksResume(crate::VoidForm),
 	TasksShow(crate::VoidForm),
 	TasksSpawn(crate::tasks::SpawnForm),
+	TasksThrottle(crate::VoidForm),
 	TasksUpdateSucceed(crate::tasks::UpdateSucceedForm),
 
 	// Which
@@ -219,6 +235,8 @@ impl<'a> IntoLua for Spark<'a> {
 			Self::BulkRename(b) => b.into_lua(lua),
 			Self::Cd(b) => b.into_lua(lua),
 			Self::Close(b) => b.into_lua(lua),
+			Self::Compare(b) => b.into_lua(lua),
+			Self::CompareDo(b) => b.into_lua(lua),
 			Self::Copy(b) => b.into_lua(lua),
 			Self::Create(b) => b.into_lua(lua),
 			Self::Displace(b) => b.into_lua(lua),
@@ -226,6 +244,7 @@ impl<'a> IntoLua for Spark<'a> {
 			Self::Download(b) => b.into_lua(lua),
 			Self::Enter(b) => b.into_lua(lua),
 			Self::Escape(b) => b.into_lua(lua),
+			Self::EscapeCompare(b) => b.into_lua(lua),
 			Self::EscapeFilter(b) => b.into_lua(lua),
 			Self::EscapeFind(b) => b.into_lua(lua),
 			Self::EscapeSearch(b) => b.into_lua(lua),
@@ -244,11 +263,16 @@ impl<'a> IntoLua for Spark<'a> {
 			Self::Leave(b) => b.into_lua(lua),
 			Self::Linemode(b) => b.into_lua(lua),
 			Self::Link(b) => b.into_lua(lua),
+			Self::MacroPlay(b) => b.into_lua(lua),
+			Self::MacroRecord(b) => b.into_lua(lua),
+			Self::MacroStop(b) => b.into_lua(lua),
+			Self::Mark(b) => b.into_lua(lua),
 			Self::Open(b) => b.into_lua(lua),
 			Self::OpenDo(b) => b.into_lua(lua),
 			Self::Paste(b) => b.into_lua(lua),
 			Self::Peek(b) => b.into_lua(lua),
 			Self::Quit(b) => b.into_lua(lua),
+			Self::Redo(b) => b.into_lua(lua),
 			Self::Refresh(b) => b.into_lua(lua),
 			Self::Remove(b) => b.into_lua(lua),
 			Self::RemoveDo(b) => b.into_lua(lua),
@@ -263,6 +287,7 @@ impl<'a> IntoLua for Spark<'a> {
 			Self::Spot(b) => b.into_lua(lua),
 			Self::Stash(b) => b.into_lua(lua),
 			Self::Suspend(b) => b.into_lua(lua),
+			Self::Sync(b) => b.into_lua(lua),
 			Self::TabClose(b) => b.into_lua(lua),
 			Self::TabCreate(b) => b.into_lua(lua),
 			Self::TabRename(b) => b.into_lua(lua),
@@ -270,6 +295,10 @@ impl<'a> IntoLua for Spark<'a> {
 			Self::TabSwitch(b) => b.into_lua(lua),
 			Self::Toggle(b) => b.into_lua(lua),
 			Self::ToggleAll(b) => b.into_lua(lua),
+			Self::Trash(b) => b.into_lua(lua),
+			Self::TrashEmpty(b) => b.into_lua(lua),
+			Self::TrashRestore(b) => b.into_lua(lua),
+			Self::Undo(b) => b.into_lua(lua),
 			Self::Unyank(b) => b.into_lua(lua),
 			Self::UpdateFiles(b) => b.into_lua(lua),
 			Self::UpdateMimes(b) => b.into_lua(lua),
@@ -334,9 +363,12 @@ impl<'a> IntoLua for Spark<'a> {
 			Self::TasksClose(b) => b.into_lua(lua),
 			Self::TasksInspect(b) => b.into_lua(lua),
 			Self::TasksOpenShellCompat(b) => b.into_lua(lua),
+			Self::TasksPause(b) => b.into_lua(lua),
 			Self::TasksProcessOpen(b) => b.into_lua(lua),
+			Self::TasksResume(b) => b.into_lua(lua),
 			Self::TasksShow(b) => b.into_lua(lua),
 			Self::TasksSpawn(b) => b.into_lua(lua),
+			Self::TasksThrottle(b) => b.into_lua(lua),
 			Self::TasksUpdateSucceed(b) => b.into_lua(lua),
 
 			// Which
@@ -354,6 +386,7 @@ try_from_spark!(
 	mgr:back,
 	mgr:bulk_rename,
 	mgr:enter,
+	mgr:escape_compare,
 	mgr:escape_filter,
 	mgr:escape_find,
 	mgr:escape_search,
@@ -362,9 +395,15 @@ try_from_spark!(
 	mgr:follow,
 	mgr:forward,
 	mgr:leave,
+	mgr:macro_stop,
+	mgr:redo,
 	mgr:refresh,
 	mgr:search_stop,
 	mgr:suspend,
+	mgr:trash,
+	mgr:trash_empty,
+	mgr:trash_restore,
+	mgr:undo,
 	mgr:unyank,
 	mgr:watch,
 	which:dismiss
@@ -392,6 +431,8 @@ try_from_spark!(crate::input::CloseForm, input:close);
 try_from_spark!(crate::mgr::BulkExitForm, mgr:bulk_exit);
 try_from_spark!(crate::mgr::CdForm, mgr:cd);
 try_from_spark!(crate::mgr::CloseForm, mgr:close);
+try_from_spark!(crate::mgr::CompareDoForm, mgr:compare_do);
+try_from_spark!(crate::mgr::CompareForm, mgr:compare);
 try_from_spark!(crate::mgr::CopyForm, mgr:copy);
 try_from_spark!(crate::mgr::CreateForm, mgr:create);
 try_from_spark!(crate::mgr::DisplaceDoForm, mgr:displace_do);
@@ -406,6 +447,9 @@ try_from_spark!(crate::mgr::HiddenForm, mgr:hidden);
 try_from_spark!(crate::mgr::HoverForm, mgr:hover);
 try_from_spark!(crate::mgr::LinemodeForm, mgr:linemode);
 try_from_spark!(crate::mgr::LinkForm, mgr:link);
+try_from_spark!(crate::mgr::MacroPlayForm, mgr:macro_play);
+try_from_spark!(crate::mgr::MacroRecordForm, mgr:macro_record);
+try_from_spark!(crate::mgr::MarkForm, mgr:mark);
 try_from_spark!(crate::mgr::OpenDoForm, mgr:open_do);
 try_from_spark!(crate::mgr::OpenForm, mgr:open);
 try_from_spark!(crate::mgr::PasteForm, mgr:paste);
@@ -419,6 +463,7 @@ try_from_spark!(crate::mgr::ShellForm, mgr:shell);
 try_from_spark!(crate::mgr::SortForm, mgr:sort);
 try_from_spark!(crate::mgr::SpotOpt, mgr:spot);
 try_from_spark!(crate::mgr::StashForm, mgr:stash);
+try_from_spark!(crate::mgr::SyncForm, mgr:sync);
 try_from_spark!(crate::mgr::TabCloseForm, mgr:tab_close);
 try_from_spark!(crate::mgr::TabCreateForm, mgr:tab_create);
 try_from_spark!(crate::mgr::TabRenameForm, mgr:tab_rename);
@@ -7,6 +7,7 @@ Entity = {
 		{ "highlights", id = 4, order = 4000 },
 		{ "found", id = 5, order = 5000 },
 		{ "symlink", id = 6, order = 6000 },
+		{ "git", id = 7, order = 7000 },
 	},
 }
 
@@ -87,6 +88,16 @@ function Entity:symlink()
 	return to and ui.Span(string.format(" -> %s", to)):style(th.mgr.symlink_target) or ""
 end
 
+function Entity:git()
+	local status = self._file:git()
+	if not status or (self._file.in_current and cx.active.pref.linemode == "git") then
+		return ""
+	end
+
+	local sign = th.git[status .. "_sign"]
+	return sign ~= "" and ui.Span(" " .. sign):style(th.git[status]) or ""
+end
+
 function Entity:redraw()
 	local lines = {}
 	for _, c in ipairs(self._children) do
@@ -99,6 +110,11 @@ end
 ---@return ui.Style
 function Entity:style()
 	local s = self._file:style() or ui.Style()
+	local compared = self._file:compared()
+	if compared then
+		s = s:patch(th.compare[compared])
+	end
+
 	if not self._file.is_hovered then
 		return s
 	elseif self._file.in_current then
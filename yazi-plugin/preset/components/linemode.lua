@@ -1,6 +1,7 @@
 Linemode = {
 	_inc = 1000,
 	_children = {
+		{ "trash", id = 3, order = 500 },
 		{ "solo", id = 1, order = 1000 },
 		{ "padding", id = 2, order = 2000 },
 	},
@@ -24,6 +25,23 @@ function Linemode:solo()
 	end
 end
 
+function Linemode:trash()
+	local trashed = self._file.in_current and self._file:trashed()
+	if not trashed then
+		return ""
+	end
+
+	local time = math.floor(trashed.deleted or 0)
+	if time == 0 then
+		time = ""
+	elseif os.date("%Y", time) == os.date("%Y") then
+		time = os.date("%m/%d %H:%M", time)
+	else
+		time = os.date("%m/%d  %Y", time)
+	end
+	return ui.Line { " ", ui.Span(tostring(trashed.origin.parent or "")):fg("gray"), " ", time }
+end
+
 function Linemode:size()
 	local size = self._file:size()
 	if size then
@@ -64,6 +82,14 @@ function Linemode:owner()
 	return string.format("%s:%s", user, group)
 end
 
+function Linemode:git()
+	local status = self._file:git()
+	if not status then
+		return ""
+	end
+	return ui.Span(th.git[status .. "_sign"]):style(th.git[status])
+end
+
 function Linemode:padding()
 	if not self._file.is_hovered then
 		return " "
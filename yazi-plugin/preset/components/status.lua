@@ -11,6 +11,7 @@ Status = {
 		{ "name", id = 3, order = 3000 },
 	},
 	_right = {
+		{ "count", id = 7, order = 500 },
 		{ "perm", id = 4, order = 1000 },
 		{ "percent", id = 5, order = 2000 },
 		{ "position", id = 6, order = 3000 },
@@ -67,6 +68,15 @@ function Status:name()
 	return " " .. ui.printable(h.name)
 end
 
+function Status:count()
+	local count = cx.which.count
+	if count == 0 then
+		return ""
+	end
+
+	return ui.Span(count .. " ")
+end
+
 function Status:perm()
 	local h = self._current.hovered
 	if not h then
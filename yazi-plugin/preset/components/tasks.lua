@@ -53,6 +53,37 @@ function Tasks:redraw()
 		end
 	end
 
+	for _, e in ipairs(self:journal_redraw(#cx.tasks.snaps)) do
+		elements[#elements + 1] = e
+	end
+
+	return elements
+end
+
+function Tasks:journal_redraw(n)
+	local journal = cx.tasks.journal
+	local y = self._area.y + n * 3
+	if #journal == 0 or y + 1 >= self._area.bottom then
+		return {}
+	end
+
+	local elements = {
+		ui.Line("History"):fg("gray"):area(self._area { y = y, h = 1 }),
+	}
+	for i, entry in ipairs(journal) do
+		if y + i >= self._area.bottom then
+			break
+		end
+
+		local line = ui.Line {
+			ui.Span(os.date("%m/%d %H:%M  ", entry.time)):fg("gray"),
+			ui.Span(entry.title),
+		}
+		if entry.undone then
+			line = line:fg("darkgray")
+		end
+		elements[#elements + 1] = line:area(self._area { y = y + i, h = 1 })
+	end
 	return elements
 end
 
@@ -61,12 +92,14 @@ function Tasks:icon(snap)
 		return "  "
 	elseif snap.prog.kind == "FileCut" then
 		return "  "
-	elseif snap.prog.kind == "FileDelete" then
+	elseif snap.prog.kind == "FileDelete" or snap.prog.kind == "FileEmpty" then
 		return "  "
 	elseif snap.prog.kind == "FileDownload" then
 		return "  "
 	elseif snap.prog.kind == "FileUpload" then
 		return "  "
+	elseif snap.prog.kind == "FileSync" then
+		return "  "
 	else
 		return "  "
 	end
@@ -80,10 +113,13 @@ function Tasks:progress_redraw(snap, y)
 		or kind == "FileDelete"
 		or kind == "FileDownload"
 		or kind == "FileUpload"
+		or kind == "FileSync"
 	then
 		local percent
 		if snap.cooked then
 			percent = "Cleaning…"
+		elseif snap.paused then
+			percent = "Paused"
 		else
 			percent = string.format("%3d%%", math.floor(snap.percent))
 		end
@@ -112,10 +148,30 @@ function Tasks:progress_redraw(snap, y)
 				:area(self._chunks[2] { y = y, h = 1 })
 				:align(ui.Align.RIGHT),
 		}
+	elseif kind == "FileEmpty" then
+		local label = snap.paused and "Paused" or string.format("%3d%%", math.floor(snap.percent))
+		local style = th.status.progress_normal
+		if snap.failed or snap.prog.failed_files > 0 then
+			style = th.status.progress_error
+		end
+
+		return {
+			ui.Gauge()
+				:area(self._chunks[1] { y = y, h = 1 })
+				:percent(snap.percent)
+				:label(ui.Span(label):style(th.status.progress_label))
+				:gauge_style(style),
+			ui.Line(string.format("%d/%d", snap.prog.success_files, snap.prog.total_files))
+				:fg("gray")
+				:area(self._chunks[2] { y = y, h = 1 })
+				:align(ui.Align.RIGHT),
+		}
 	else
 		local text
 		if snap.cooked then
 			text = "Cleaning…"
+		elseif snap.paused then
+			text = "Paused"
 		elseif snap.running then
 			text = "Running…"
 		else
@@ -0,0 +1,24 @@
+local M = {}
+
+function M:fetch(job)
+	local dirs = {}
+	for _, file in ipairs(job.files) do
+		local parent = file.url.parent
+		if parent then
+			dirs[tostring(parent)] = parent
+		end
+	end
+
+	-- Entries of the parent directory are shown in the parent pane
+	for _, dir in pairs(dirs) do
+		for _, url in ipairs { dir, dir.parent } do
+			local statuses = fs.git_status(url)
+			if statuses then
+				ya.emit("update_files", { op = fs.op("git", { url = url, statuses = statuses }) })
+			end
+		end
+	end
+	return true
+end
+
+return M
@@ -14,7 +14,11 @@ function M:fetch(job)
 	end
 
 	local child, err = M.spawn_file1(paths)
-	if not child then
+	if not child and err.kind == "NotFound" then
+		local state = require("mime.native"):fetch(job)
+		M.placeholder(err, urls, paths, state)
+		return state
+	elseif not child then
 		M.placeholder(err, urls, paths)
 		return true, err
 	end
@@ -96,17 +100,21 @@ function M.spawn_file1(paths)
 	return child
 end
 
-function M.placeholder(err, urls, paths)
+function M.placeholder(err, urls, paths, state)
 	if err.kind ~= "NotFound" then
 		return
 	end
 
 	local updates = {}
 	for i = 1, #paths do
-		updates[urls[i] or paths[i]] = "null/file1-not-found"
+		if not (state and state[i]) then
+			updates[urls[i] or paths[i]] = "null/file1-not-found"
+		end
 	end
 
-	ya.emit("update_mimes", { updates = updates })
+	if next(updates) then
+		ya.emit("update_mimes", { updates = updates })
+	end
 end
 
 return M
@@ -0,0 +1,83 @@
+local M = {}
+
+-- Kept in two generations of up to 500 entries, the older one is dropped once the newer fills up
+local remember = ya.sync(function(st, url, hash, mime)
+	st.sniffed, st.count = st.sniffed or {}, (st.count or 0) + 1
+	if st.count > 500 then
+		st.stale, st.sniffed, st.count = st.sniffed, {}, 1
+	end
+	st.sniffed[url] = { hash = hash, mime = mime }
+end)
+
+local recall = ya.sync(function(st, url, hash)
+	local entry = st.sniffed and st.sniffed[url] or st.stale and st.stale[url]
+	if entry and entry.hash == hash then
+		return entry.mime
+	end
+end)
+
+-- MIME type sniffed from the head of a remote file that isn't downloaded yet,
+-- as long as the file hasn't changed since
+function M.sniffed(file) return recall(tostring(file.url), file.cha:hash(true)) end
+
+local function stale_cache(file)
+	local url = file.url
+	local lock = url.scheme.cache:join(string.format("%%lock/%s", url:hash(true)))
+
+	local fd = fs.access():read(true):open(Url(lock))
+	if not fd then
+		return true
+	end
+
+	local hash = fd:read(32)
+	ya.drop(fd)
+	return hash ~= file.cha:hash(true)
+end
+
+local function sniff(file, mime)
+	remember(tostring(file.url), file.cha:hash(true), fs.mime(file.url))
+	return mime
+end
+
+function M.detect(file)
+	if not file.cache then
+		return fs.mime(file.url)
+	elseif not fs.cha(Url(file.cache)) then
+		return sniff(file, "vfs/absent")
+	elseif stale_cache(file) then
+		return sniff(file, "vfs/stale")
+	else
+		return fs.mime(Url(file.cache))
+	end
+end
+
+function M:fetch(job)
+	local updates, state, last = {}, {}, ya.time()
+	local flush = function(force)
+		if not force and ya.time() - last < 0.3 then
+			return
+		end
+		if next(updates) then
+			ya.emit("update_mimes", { updates = updates })
+			updates, last = {}, ya.time()
+		end
+	end
+
+	for i, file in ipairs(job.files) do
+		-- Skip dummy files
+		if not file.cha.is_dummy then
+			local mime = M.detect(file)
+			if mime then
+				updates[file.url], state[i] = mime, true
+				flush(false)
+			else
+				state[i] = false
+			end
+		end
+	end
+
+	flush(true)
+	return state
+end
+
+return M
@@ -0,0 +1,16 @@
+local M = {}
+
+function M:peek(job)
+	local err, bound = ya.preview_outline(job, { depth = tonumber(job.args.depth) })
+	if bound then
+		ya.emit("peek", { bound, only_if = job.file.url, upper_bound = true })
+	elseif err and not err:find("cancelled", 1, true) then
+		require("code"):peek(job)
+	end
+end
+
+function M:seek(job) require("code"):seek(job) end
+
+function M:spot(job) require("file"):spot(job) end
+
+return M
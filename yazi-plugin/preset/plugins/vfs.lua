@@ -1,11 +1,26 @@
 local M = {}
 
+-- Previewers that read through the VFS provider, so they work before the file is downloaded
+local STREAMED = { code = true, outline = true }
+
+local function previewer(file, mime)
+	for _, v in pairs(rt.plugin.previewers:match { file = file, mime = mime }) do
+		return v
+	end
+end
+
 function M:peek(job)
+	local mime = require("mime.native").sniffed(job.file)
+	local p = mime and previewer(job.file, mime)
+	if p and STREAMED[p.name] then
+		return require(p.name):peek(ya.dict_merge(job, { mime = mime }))
+	end
+
 	local line = ui.Line("Remote file, download to preview"):reverse()
 	ya.preview_widget(job, ui.Text(line):area(job.area):wrap(ui.Wrap.YES))
 end
 
-function M:seek() end
+function M:seek(job) require("code"):seek(job) end
 
 function M:spot(job) require("file"):spot(job) end
 
@@ -0,0 +1,264 @@
+use std::{collections::VecDeque, sync::Arc};
+
+use anyhow::{Result, bail};
+use globset::{GlobBuilder, GlobMatcher};
+use regex::{Regex, RegexBuilder, bytes};
+use tokio::{io::{AsyncBufReadExt, AsyncReadExt, BufReader}, sync::mpsc::{self, UnboundedReceiver, UnboundedSender}};
+use yazi_fs::{File, Ignore, cha::ChaType, provider::{DirReader, FileHolder}};
+use yazi_shared::{strand::StrandLike, url::{UrlBuf, UrlLike}};
+use yazi_vfs::{VfsFile, provider};
+
+pub struct BuiltinOpt {
+	/// The `search://` folder results are streamed into, results of non-local
+	/// searches are emitted with their own URLs instead of under it.
+	pub cwd:     UrlBuf,
+	/// The directory to search, can be of any provider.
+	pub root:    UrlBuf,
+	pub hidden:  bool,
+	pub subject: String,
+	pub args:    Vec<String>,
+}
+
+enum Matcher {
+	Name(Regex),
+	Glob(GlobMatcher),
+	Content(bytes::Regex),
+}
+
+struct Walker {
+	matcher:   Matcher,
+	hidden:    bool,
+	ignore:    bool,
+	max_depth: usize,
+	cwd:       UrlBuf,
+	local:     bool,
+	tx:        UnboundedSender<File>,
+}
+
+struct Dir {
+	url:    UrlBuf,
+	rel:    String,
+	depth:  usize,
+	ignore: Arc<Vec<Arc<Ignore>>>,
+}
+
+/// Searches through [`yazi_vfs::provider`], matching filenames by regex
+/// (default) or `--glob`, or file contents with `--content`.
+///
+/// Hidden files follow `hidden`, `.gitignore` and `.ignore` files are respected
+/// unless `--no-ignore`, and `--max-depth=N` limits how deep to descend.
+pub fn builtin(opt: BuiltinOpt) -> Result<UnboundedReceiver<File>> {
+	let (mut glob, mut content, mut ignore, mut max_depth, mut case) =
+		(false, false, true, usize::MAX, None);
+
+	for arg in &opt.args {
+		match arg.as_str() {
+			"-g" | "--glob" => glob = true,
+			"-c" | "--content" => content = true,
+			"--no-ignore" => ignore = false,
+			"-s" | "--case-sensitive" => case = Some(true),
+			"-i" | "--ignore-case" => case = Some(false),
+			s => match s.strip_prefix("--max-depth=").or_else(|| s.strip_prefix("-d")) {
+				Some(n) => max_depth = n.parse()?,
+				None => bail!("Unknown argument `{s}` for the built-in search"),
+			},
+		}
+	}
+
+	// Smart case: insensitive unless the subject contains uppercase letters
+	let insensitive = !case.unwrap_or_else(|| opt.subject.chars().any(char::is_uppercase));
+	let matcher = if content {
+		Matcher::Content(bytes::RegexBuilder::new(&opt.subject).case_insensitive(insensitive).build()?)
+	} else if glob {
+		Matcher::Glob(
+			GlobBuilder::new(&opt.subject).case_insensitive(insensitive).build()?.compile_matcher(),
+		)
+	} else {
+		Matcher::Name(RegexBuilder::new(&opt.subject).case_insensitive(insensitive).build()?)
+	};
+
+	let (tx, rx) = mpsc::unbounded_channel();
+	let local = opt.root.kind().is_local();
+	let walker = Walker { matcher, hidden: opt.hidden, ignore, max_depth, cwd: opt.cwd, local, tx };
+
+	tokio::spawn(walker.walk(opt.root));
+	Ok(rx)
+}
+
+impl Walker {
+	async fn walk(self, root: UrlBuf) {
+		let mut queue = VecDeque::from([Dir {
+			url:    root,
+			rel:    String::new(),
+			depth:  1,
+			ignore: Default::default(),
+		}]);
+
+		while let Some(dir) = queue.pop_front() {
+			if self.tx.is_closed() {
+				break;
+			}
+
+			let Ok(entries) = Self::read_dir(&dir.url).await else { continue };
+			let ignore = self.ignore_of(&dir, &entries).await;
+
+			for (entry, name, kind) in entries {
+				if !self.hidden && name.starts_with('.') {
+					continue;
+				}
+
+				let rel = if dir.rel.is_empty() { name.clone() } else { format!("{}/{name}", dir.rel) };
+				let is_dir = kind == ChaType::Dir;
+				if self.ignore
+					&& ((is_dir && name == ".git")
+						|| Ignore::ignored(ignore.iter().map(|i| &**i), &rel, is_dir))
+				{
+					continue;
+				}
+
+				if self.matches(&entry, &name, kind).await {
+					self.emit(&entry, &rel).await;
+				}
+				if is_dir && dir.depth < self.max_depth {
+					queue.push_back(Dir { url: entry, rel, depth: dir.depth + 1, ignore: ignore.clone() });
+				}
+			}
+		}
+	}
+
+	async fn read_dir(url: &UrlBuf) -> std::io::Result<Vec<(UrlBuf, String, ChaType)>> {
+		let mut it = provider::read_dir(url).await?;
+		let mut entries = vec![];
+
+		while let Some(entry) = it.next().await? {
+			let kind = entry.file_type().await.unwrap_or(ChaType::Unknown);
+			entries.push((entry.url(), entry.name().to_string_lossy().into_owned(), kind));
+		}
+		Ok(entries)
+	}
+
+	async fn ignore_of(
+		&self,
+		dir: &Dir,
+		entries: &[(UrlBuf, String, ChaType)],
+	) -> Arc<Vec<Arc<Ignore>>> {
+		if !self.ignore {
+			return dir.ignore.clone();
+		}
+
+		let mut stack = None;
+		for (url, ..) in entries.iter().filter(|(_, n, _)| n == ".gitignore" || n == ".ignore") {
+			let Ok(content) = Self::read_to_string(url).await else { continue };
+			if let Some(ignore) = Ignore::parse(dir.rel.clone(), &content) {
+				stack.get_or_insert_with(|| (*dir.ignore).clone()).push(Arc::new(ignore));
+			}
+		}
+		stack.map(Arc::new).unwrap_or_else(|| dir.ignore.clone())
+	}
+
+	async fn matches(&self, url: &UrlBuf, name: &str, kind: ChaType) -> bool {
+		match &self.matcher {
+			Matcher::Name(re) => re.is_match(name),
+			Matcher::Glob(glob) => glob.is_match(name),
+			Matcher::Content(re) => kind == ChaType::File && Self::grep(url, re).await.unwrap_or(false),
+		}
+	}
+
+	async fn emit(&self, entry: &UrlBuf, rel: &str) {
+		let url = if self.local {
+			let Ok(url) = self.cwd.try_join(rel) else { return };
+			url
+		} else {
+			entry.clone()
+		};
+
+		if let Ok(file) = File::new(url).await {
+			self.tx.send(file).ok();
+		}
+	}
+
+	/// Whether any line of the file matches, binary files are skipped.
+	async fn grep(url: &UrlBuf, re: &bytes::Regex) -> std::io::Result<bool> {
+		let mut reader = BufReader::with_capacity(64 * 1024, provider::open(url).await?);
+		if reader.fill_buf().await?.contains(&0) {
+			return Ok(false);
+		}
+
+		let mut line = Vec::new();
+		while reader.read_until(b'\n', &mut line).await? > 0 {
+			if re.is_match(&line) {
+				return Ok(true);
+			}
+			line.clear();
+		}
+		Ok(false)
+	}
+
+	async fn read_to_string(url: &UrlBuf) -> std::io::Result<String> {
+		let mut s = String::new();
+		provider::open(url).await?.read_to_string(&mut s).await?;
+		Ok(s)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::path::Path;
+
+	use yazi_shared::url::AsUrl;
+
+	use super::*;
+
+	async fn search(root: &Path, subject: &str, args: &[&str]) -> Result<Vec<String>> {
+		let url = UrlBuf::from(root);
+		let mut rx = builtin(BuiltinOpt {
+			cwd:     url.as_url().to_search(subject)?,
+			root:    url,
+			hidden:  false,
+			subject: subject.to_owned(),
+			args:    args.iter().map(|s| s.to_string()).collect(),
+		})?;
+
+		let mut found = vec![];
+		while let Some(file) = rx.recv().await {
+			let path = file.url.as_local().unwrap().strip_prefix(root)?;
+			found.push(path.to_string_lossy().into_owned());
+		}
+		found.sort();
+		Ok(found)
+	}
+
+	#[tokio::test]
+	async fn test_builtin() -> Result<()> {
+		yazi_shared::init_tests();
+		let tmp = tempfile::tempdir()?;
+		for (path, content) in [
+			("a.txt", "hello"),
+			("b.rs", "fn main() {}"),
+			(".hidden.txt", "hello"),
+			("sub/c.txt", "Hello world"),
+			("sub/deep/d.txt", ""),
+			("ignored/e.txt", "hello"),
+			("x.log", "hello"),
+			(".gitignore", "ignored/\n*.log\n"),
+		] {
+			let path = tmp.path().join(path);
+			std::fs::create_dir_all(path.parent().unwrap())?;
+			std::fs::write(path, content)?;
+		}
+
+		let root = tmp.path();
+		assert_eq!(search(root, "txt$", &[]).await?, ["a.txt", "sub/c.txt", "sub/deep/d.txt"]);
+		assert_eq!(search(root, "TXT$", &["-i"]).await?.len(), 3);
+		assert_eq!(search(root, "*.rs", &["--glob"]).await?, ["b.rs"]);
+		assert_eq!(search(root, "txt$", &["--max-depth=1"]).await?, ["a.txt"]);
+		assert_eq!(search(root, "e.txt$", &["--no-ignore"]).await?, ["ignored/e.txt"]);
+
+		// Smart case: insensitive unless the subject contains uppercase letters
+		assert_eq!(search(root, "hello", &["--content"]).await?, ["a.txt", "sub/c.txt"]);
+		assert_eq!(search(root, "Hello", &["--content"]).await?, ["sub/c.txt"]);
+
+		assert!(search(root, "a", &["--unknown"]).await.is_err());
+		Ok(())
+	}
+}
@@ -1 +1 @@
-yazi_macro::mod_flat!(fd rg rga);
+yazi_macro::mod_flat!(builtin fd rg rga);
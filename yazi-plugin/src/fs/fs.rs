@@ -1,9 +1,10 @@
 use std::str::FromStr;
 
-use mlua::{ExternalError, Function, IntoLua, IntoLuaMulti, Lua, Table, Value};
-use yazi_binding::{Cha, Composer, ComposerGet, ComposerSet, Error, File, SizeCalculator, Url, UrlRef, deprecate};
+use mlua::{ExternalError, ExternalResult, Function, IntoLua, IntoLuaMulti, Lua, Table, Value};
+use tokio::io::AsyncReadExt;
+use yazi_binding::{Cha, Composer, ComposerGet, ComposerSet, Error, File, Path, SizeCalculator, Url, UrlRef, deprecate};
 use yazi_config::Pattern;
-use yazi_fs::{mounts::PARTITIONS, provider::{Attrs, DirReader, FileHolder}};
+use yazi_fs::{SNIFF_LEN, Sniffer, git::Git, mounts::PARTITIONS, provider::{Attrs, DirReader, FileHolder}};
 use yazi_shared::url::{UrlCow, UrlLike};
 use yazi_vfs::{VfsFile, provider};
 
@@ -17,6 +18,8 @@ pub fn compose() -> Composer<ComposerGet, ComposerSet> {
 			b"create" => create(lua)?,
 			b"cwd" => cwd(lua)?,
 			b"expand_url" => expand_url(lua)?,
+			b"git_status" => git_status(lua)?,
+			b"mime" => mime(lua)?,
 			b"op" => op(lua)?,
 			b"partitions" => partitions(lua)?,
 			b"read_dir" => read_dir(lua)?,
@@ -120,11 +123,43 @@ fn expand_url(lua: &Lua) -> mlua::Result<Function> {
 	})
 }
 
+fn git_status(lua: &Lua) -> mlua::Result<Function> {
+	lua.create_async_function(|lua, dir: UrlRef| async move {
+		let Some(path) = dir.as_local().map(ToOwned::to_owned) else {
+			return Value::Nil.into_lua_multi(&lua);
+		};
+
+		match tokio::task::spawn_blocking(move || Git::status(&path)).await.into_lua_err()? {
+			Ok(Some(statuses)) => lua
+				.create_table_from(statuses.into_iter().map(|(urn, s)| (Path::new(urn), <&str>::from(s))))?
+				.into_lua_multi(&lua),
+			Ok(None) => Value::Nil.into_lua_multi(&lua),
+			Err(e) => (Value::Nil, Error::Io(e)).into_lua_multi(&lua),
+		}
+	})
+}
+
+fn mime(lua: &Lua) -> mlua::Result<Function> {
+	lua.create_async_function(|lua, url: UrlRef| async move {
+		let mut head = Vec::with_capacity(SNIFF_LEN);
+		let result = async {
+			let file = provider::open(&*url).await?;
+			file.take(SNIFF_LEN as u64).read_to_end(&mut head).await
+		};
+
+		match result.await {
+			Ok(_) => Sniffer::mime(&head, url.ext().and_then(|s| s.to_str().ok())).into_lua_multi(&lua),
+			Err(e) => (Value::Nil, Error::Io(e)).into_lua_multi(&lua),
+		}
+	})
+}
+
 fn op(lua: &Lua) -> mlua::Result<Function> {
 	lua.create_function(|lua, (name, t): (mlua::String, Table)| match &*name.as_bytes() {
 		b"part" => super::FilesOp::part(lua, t),
 		b"done" => super::FilesOp::done(lua, t),
 		b"size" => super::FilesOp::size(lua, t),
+		b"git" => super::FilesOp::git(lua, t),
 		_ => Err("Unknown operation".into_lua_err())?,
 	})
 }
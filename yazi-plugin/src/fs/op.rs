@@ -1,4 +1,4 @@
-use mlua::{IntoLua, Lua, Table, Value};
+use mlua::{ExternalResult, IntoLua, Lua, Table, Value};
 use yazi_binding::{Cha, File, Id, Path, Url};
 
 pub(super) struct FilesOp(yazi_fs::FilesOp);
@@ -39,6 +39,22 @@ impl FilesOp {
 				.collect::<mlua::Result<_>>()?,
 		)))
 	}
+
+	pub(super) fn git(_: &Lua, t: Table) -> mlua::Result<Self> {
+		let url: Url = t.raw_get("url")?;
+		let statuses: Table = t.raw_get("statuses")?;
+
+		Ok(Self(yazi_fs::FilesOp::Git(
+			url.into(),
+			statuses
+				.pairs::<Path, mlua::String>()
+				.map(|r| {
+					let (urn, status) = r?;
+					Ok((urn.into(), status.to_str()?.parse().into_lua_err()?))
+				})
+				.collect::<mlua::Result<_>>()?,
+		)))
+	}
 }
 
 impl IntoLua for FilesOp {
@@ -58,7 +58,7 @@ fn mgr() -> Composer<ComposerGet, ComposerSet> {
 		match key {
 			b"ratio" => lua.to_value_with(&m.ratio, SER_OPT)?,
 
-			b"sort_by" => lua.to_value_with(&m.sort_by, SER_OPT)?,
+			b"sort_by" => lua.to_value_with(&**m.sort_by.load(), SER_OPT)?,
 			b"sort_sensitive" => m.sort_sensitive.get().into_lua(lua)?,
 			b"sort_reverse" => m.sort_reverse.get().into_lua(lua)?,
 			b"sort_dir_first" => m.sort_dir_first.get().into_lua(lua)?,
@@ -70,6 +70,8 @@ fn mgr() -> Composer<ComposerGet, ComposerSet> {
 			b"show_symlink" => m.show_symlink.get().into_lua(lua)?,
 			b"scrolloff" => m.scrolloff.get().into_lua(lua)?,
 			b"mouse_events" => lua.to_value_with(&m.mouse_events, SER_OPT)?,
+
+			b"session" => m.session.get().into_lua(lua)?,
 			_ => return Ok(Value::Nil),
 		}
 		.into_lua(lua)
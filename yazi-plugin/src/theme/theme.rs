@@ -22,6 +22,8 @@ pub fn compose() -> Composer<ComposerGet, ComposerSet> {
 			b"cmp" => cmp(),
 			b"tasks" => tasks(),
 			b"help" => help(),
+			b"git" => git(),
+			b"compare" => compare(),
 			_ => return custom(lua, key),
 		}
 		.into_lua(lua)
@@ -376,6 +378,49 @@ fn help() -> Composer<ComposerGet, ComposerSet> {
 	Composer::new(get, set)
 }
 
+fn git() -> Composer<ComposerGet, ComposerSet> {
+	fn get(lua: &Lua, key: &[u8]) -> mlua::Result<Value> {
+		let t = &THEME.git;
+		match key {
+			b"modified" => Style::from(&t.modified).into_lua(lua),
+			b"staged" => Style::from(&t.staged).into_lua(lua),
+			b"untracked" => Style::from(&t.untracked).into_lua(lua),
+			b"ignored" => Style::from(&t.ignored).into_lua(lua),
+			b"conflicted" => Style::from(&t.conflicted).into_lua(lua),
+
+			b"modified_sign" => lua.create_string(&**t.modified_sign.load())?.into_lua(lua),
+			b"staged_sign" => lua.create_string(&**t.staged_sign.load())?.into_lua(lua),
+			b"untracked_sign" => lua.create_string(&**t.untracked_sign.load())?.into_lua(lua),
+			b"ignored_sign" => lua.create_string(&**t.ignored_sign.load())?.into_lua(lua),
+			b"conflicted_sign" => lua.create_string(&**t.conflicted_sign.load())?.into_lua(lua),
+
+			_ => Ok(Value::Nil),
+		}
+	}
+
+	fn set(_: &Lua, _: &[u8], value: Value) -> mlua::Result<Value> { Ok(value) }
+
+	Composer::new(get, set)
+}
+
+fn compare() -> Composer<ComposerGet, ComposerSet> {
+	fn get(lua: &Lua, key: &[u8]) -> mlua::Result<Value> {
+		let t = &THEME.compare;
+		match key {
+			b"unique" => Style::from(&t.unique).into_lua(lua),
+			b"identical" => Style::from(&t.identical).into_lua(lua),
+			b"newer" => Style::from(&t.newer).into_lua(lua),
+			b"older" => Style::from(&t.older).into_lua(lua),
+			b"differing" => Style::from(&t.differing).into_lua(lua),
+			_ => Ok(Value::Nil),
+		}
+	}
+
+	fn set(_: &Lua, _: &[u8], value: Value) -> mlua::Result<Value> { Ok(value) }
+
+	Composer::new(get, set)
+}
+
 fn custom(lua: &Lua, key: &[u8]) -> mlua::Result<Value> {
 	match THEME.custom.load().get(str::from_utf8(key)?) {
 		Some(section) => CustomSection::new(section.load_full()).into_lua(lua),
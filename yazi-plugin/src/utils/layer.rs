@@ -29,6 +29,7 @@ impl Utils {
 						run:   vec![relay!(which:callback, [i + 1])],
 						desc:  cand.raw_get("desc").unwrap_or_default(),
 						r#for: Platform::All,
+						when:  None,
 					}))
 				})
 				.collect::<mlua::Result<_>>()?;
@@ -87,6 +88,7 @@ impl Utils {
 				title:    t.raw_get::<Line>("title")?.into(),
 				body:     t.raw_get::<Option<Text>>("body")?.unwrap_or_default().into(),
 				list:     Default::default(), // TODO
+				choices:  Default::default(),
 			});
 
 			Ok(result.await)
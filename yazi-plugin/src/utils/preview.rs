@@ -1,9 +1,7 @@
 use mlua::{ExternalError, Function, IntoLuaMulti, Lua, Table, Value};
 use yazi_binding::{Error, elements::{Area, Renderable, Text}};
-use yazi_core::{Highlighter, MgrProxy, tab::PreviewLock};
-use yazi_fs::FsUrl;
+use yazi_core::{Highlighter, MgrProxy, Outliner, tab::PreviewLock};
 use yazi_runner::previewer::PeekError;
-use yazi_shared::url::AsUrl;
 
 use super::Utils;
 
@@ -16,8 +14,35 @@ impl Utils {
 			let area: Area = t.raw_get("area")?;
 			let mut lock = PreviewLock::try_from(t)?;
 
-			let path = lock.url.as_url().unified_path();
-			let inner = match Highlighter::oneshot(path, lock.skip, area.size()).await {
+			let inner = match Highlighter::oneshot(lock.url.clone(), lock.skip, area.size()).await {
+				Ok(text) => text,
+				Err(e @ PeekError::Exceeded(max)) => return (e, max).into_lua_multi(&lua),
+				Err(e) => {
+					return e.into_lua_multi(&lua);
+				}
+			};
+
+			lock.data = vec![Renderable::Text(Text { area, inner, ..Default::default() })];
+
+			MgrProxy::update_peeked(lock);
+			().into_lua_multi(&lua)
+		})
+	}
+
+	pub(super) fn preview_outline(lua: &Lua) -> mlua::Result<Function> {
+		lua.create_async_function(|lua, (t, opts): (Table, Option<Table>)| async move {
+			let area: Area = t.raw_get("area")?;
+			let (depth, mime) = match opts {
+				Some(o) => (o.raw_get("depth")?, o.raw_get("mime")?),
+				None => (None, None),
+			};
+			let mime: String = match mime {
+				Some(m) => m,
+				None => t.raw_get::<Option<String>>("mime")?.unwrap_or_default(),
+			};
+			let mut lock = PreviewLock::try_from(t)?;
+
+			let inner = match Outliner::oneshot(lock.url.clone(), &mime, lock.skip, area.size(), depth).await {
 				Ok(text) => text,
 				Err(e @ PeekError::Exceeded(max)) => return (e, max).into_lua_multi(&lua),
 				Err(e) => {
@@ -41,6 +41,7 @@ pub fn compose(
 
 			// Preview
 			b"preview_code" => Utils::preview_code(lua)?,
+			b"preview_outline" => Utils::preview_outline(lua)?,
 			b"preview_widget" => Utils::preview_widget(lua)?,
 
 			// Process
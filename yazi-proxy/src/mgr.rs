@@ -1,4 +1,4 @@
-use yazi_core::{mgr::{CdSource, DisplaceOpt, FilterOpt, FindDoOpt, OpenDoOpt, OpenOpt, SearchOpt}, spot::SpotLock};
+use yazi_core::{mgr::{CdSource, CompareOpt, DisplaceOpt, FilterOpt, FindDoOpt, OpenDoOpt, OpenOpt, SearchOpt}, spot::SpotLock};
 use yazi_macro::{emit, relay};
 use yazi_shared::{Id, SStr, url::UrlBuf};
 use yazi_shim::strum::IntoStr;
@@ -16,6 +16,10 @@ impl MgrProxy {
 		));
 	}
 
+	pub fn compare_do(opt: CompareOpt) {
+		emit!(Call(relay!(mgr:compare_do).with_any("opt", opt)));
+	}
+
 	pub fn displace_do(tab: Id, opt: DisplaceOpt) {
 		emit!(Call(relay!(mgr:displace_do).with("tab", tab).with_any("opt", opt)));
 	}
@@ -42,6 +46,10 @@ impl MgrProxy {
 		));
 	}
 
+	pub fn mark(action: &'static str, key: char) {
+		emit!(Call(relay!(mgr:mark, [SStr::from(action), key.to_string().into()])));
+	}
+
 	pub fn reveal(target: impl Into<UrlBuf>) {
 		emit!(Call(relay!(mgr:reveal, [target.into()]).with("raw", true).with("no-dummy", true)));
 	}
@@ -1,4 +1,4 @@
-yazi_macro::mod_pub!(entry fetcher loader preloader previewer);
+yazi_macro::mod_pub!(entry fetcher loader preloader previewer sorter);
 
 yazi_macro::mod_flat!(runner spot);
 
@@ -36,6 +36,7 @@ impl Default for Loader {
 			("folder".to_owned(), preset!("plugins/folder").into()),
 			("font".to_owned(), preset!("plugins/font").into()),
 			("fzf".to_owned(), preset!("plugins/fzf").into()),
+			("git".to_owned(), preset!("plugins/git").into()),
 			("image".to_owned(), preset!("plugins/image").into()),
 			("init".to_owned(), preset!("plugins/init").into()),
 			("json".to_owned(), preset!("plugins/json").into()),
@@ -43,10 +44,12 @@ impl Default for Loader {
 			("mime".to_owned(), preset!("plugins/mime").into()),
 			("mime.dir".to_owned(), preset!("plugins/mime-dir").into()),
 			("mime.local".to_owned(), preset!("plugins/mime-local").into()),
+			("mime.native".to_owned(), preset!("plugins/mime-native").into()),
 			("mime.remote".to_owned(), preset!("plugins/mime-remote").into()),
 			("multi".to_owned(), preset!("plugins/multi").into()),
 			("noop".to_owned(), preset!("plugins/noop").into()),
 			("null".to_owned(), preset!("plugins/null").into()),
+			("outline".to_owned(), preset!("plugins/outline").into()),
 			("pdf".to_owned(), preset!("plugins/pdf").into()),
 			("session".to_owned(), preset!("plugins/session").into()),
 			("svg".to_owned(), preset!("plugins/svg").into()),
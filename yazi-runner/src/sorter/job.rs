@@ -0,0 +1,6 @@
+use yazi_shared::pool::Symbol;
+
+pub struct SortJob {
+	pub plugin: Symbol<str>,
+	pub files:  Vec<yazi_fs::File>,
+}
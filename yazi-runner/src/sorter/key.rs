@@ -0,0 +1,76 @@
+use std::cmp::Ordering;
+
+use mlua::{FromLua, Lua, Value};
+
+/// A sort key returned by a plugin, numbers go before strings, and files
+/// without a key go last.
+#[derive(PartialEq)]
+pub(super) enum SortKey {
+	Number(f64),
+	String(Vec<u8>),
+	Nil,
+}
+
+impl SortKey {
+	pub(super) fn cmp(&self, other: &Self) -> Ordering {
+		match (self, other) {
+			(Self::Number(a), Self::Number(b)) => a.total_cmp(b),
+			(Self::String(a), Self::String(b)) => a.cmp(b),
+			(Self::Number(_), _) | (Self::String(_), Self::Nil) => Ordering::Less,
+			(Self::Nil, Self::Nil) => Ordering::Equal,
+			_ => Ordering::Greater,
+		}
+	}
+}
+
+impl FromLua for SortKey {
+	fn from_lua(value: Value, _: &Lua) -> mlua::Result<Self> {
+		Ok(match value {
+			Value::Nil => Self::Nil,
+			Value::Integer(n) => Self::Number(n as f64),
+			Value::Number(n) => Self::Number(n),
+			Value::String(s) => Self::String(s.as_bytes().to_vec()),
+			_ => Err(mlua::Error::FromLuaConversionError {
+				from:    value.type_name(),
+				to:      "SortKey".to_owned(),
+				message: Some("expected a number, a string or nil".to_owned()),
+			})?,
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_cmp() {
+		let mut keys = vec![
+			SortKey::Nil,
+			SortKey::String(b"b".to_vec()),
+			SortKey::Number(2.0),
+			SortKey::String(b"a".to_vec()),
+			SortKey::Number(-1.5),
+		];
+		keys.sort_by(SortKey::cmp);
+		assert!(keys == [
+			SortKey::Number(-1.5),
+			SortKey::Number(2.0),
+			SortKey::String(b"a".to_vec()),
+			SortKey::String(b"b".to_vec()),
+			SortKey::Nil,
+		]);
+	}
+
+	#[test]
+	fn test_from_lua() {
+		let lua = Lua::new();
+		let key = |code: &str| lua.load(code).eval::<SortKey>();
+
+		assert!(key("42").unwrap() == SortKey::Number(42.0));
+		assert!(key("0.5").unwrap() == SortKey::Number(0.5));
+		assert!(key("'x'").unwrap() == SortKey::String(b"x".to_vec()));
+		assert!(key("nil").unwrap() == SortKey::Nil);
+		assert!(key("{}").is_err());
+	}
+}
@@ -0,0 +1,98 @@
+use std::cmp::Ordering;
+
+use mlua::{ExternalResult, Function, IntoLua, Lua, Table, Value};
+use tokio::runtime::Handle;
+use yazi_binding::File;
+
+use crate::{Runner, loader::LOADER, sorter::{SortJob, SortKey}};
+
+impl Runner {
+	/// Ranks the files with a sort plugin, where files of the same rank are
+	/// equal in its order.
+	///
+	/// The plugin either provides `key(self, file)` returning a number, a string
+	/// or nil, or `cmp(self, a, b)` returning whether `a` goes before `b`.
+	pub async fn sort(&'static self, job: SortJob) -> mlua::Result<Vec<u32>> {
+		if job.files.is_empty() {
+			return Ok(vec![]);
+		}
+		LOADER.ensure(&job.plugin, |_| ()).await.into_lua_err()?;
+
+		tokio::task::spawn_blocking(move || {
+			let lua = self.spawn(&job.plugin)?;
+			Handle::current().block_on(async {
+				let plugin = LOADER.load(&lua, &job.plugin).await?;
+				let files = Self::files(&lua, job.files)?;
+
+				if let Some(key) = plugin.raw_get::<Option<Function>>("key")? {
+					let mut keys = Vec::with_capacity(files.len());
+					for file in &files {
+						keys.push(key.call_async::<SortKey>((&plugin, file)).await?);
+					}
+					Ok(Self::rank(files.len(), |a, b| keys[a].cmp(&keys[b])))
+				} else if let Some(cmp) = plugin.raw_get::<Option<Function>>("cmp")? {
+					Self::rank_by_cmp(&plugin, &cmp, &files)
+				} else {
+					Err(format!("Sort plugin `{}` has neither `key` nor `cmp`", job.plugin)).into_lua_err()
+				}
+			})
+		})
+		.await
+		.into_lua_err()?
+	}
+
+	fn files(lua: &Lua, files: Vec<yazi_fs::File>) -> mlua::Result<Vec<Value>> {
+		files.into_iter().map(|f| File::new(f).into_lua(lua)).collect()
+	}
+
+	fn rank_by_cmp(plugin: &Table, cmp: &Function, files: &[Value]) -> mlua::Result<Vec<u32>> {
+		let mut err = None;
+		let ranks = Self::rank(files.len(), |a, b| {
+			let mut less = |x: usize, y: usize| -> bool {
+				match cmp.call::<bool>((plugin, &files[x], &files[y])) {
+					Ok(b) => b,
+					Err(e) => {
+						err.get_or_insert(e);
+						false
+					}
+				}
+			};
+			if less(a, b) {
+				Ordering::Less
+			} else if less(b, a) {
+				Ordering::Greater
+			} else {
+				Ordering::Equal
+			}
+		});
+		err.map_or(Ok(ranks), Err)
+	}
+
+	fn rank(len: usize, mut f: impl FnMut(usize, usize) -> Ordering) -> Vec<u32> {
+		let mut indices: Vec<_> = (0..len).collect();
+		indices.sort_by(|&a, &b| f(a, b));
+
+		let mut ranks = vec![0; len];
+		for (i, w) in indices.windows(2).enumerate() {
+			let prev = ranks[w[0]];
+			ranks[w[1]] = if f(w[0], w[1]) == Ordering::Equal { prev } else { i as u32 + 1 };
+		}
+		ranks
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_rank() {
+		let keys = [3, 1, 3, 2, 1];
+		assert_eq!(Runner::rank(keys.len(), |a, b| keys[a].cmp(&keys[b])), [3, 0, 3, 2, 0]);
+		assert_eq!(Runner::rank(0, |_, _| Ordering::Equal), Vec::<u32>::new());
+
+		// Ties share the rank of the first one, and the next rank skips over them
+		let keys = ["b", "a", "a", "a", "c"];
+		assert_eq!(Runner::rank(keys.len(), |a, b| keys[a].cmp(keys[b])), [3, 0, 0, 0, 4]);
+	}
+}
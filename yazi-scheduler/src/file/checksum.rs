@@ -0,0 +1,82 @@
+use std::{hash::Hasher, io};
+
+use tokio::io::AsyncReadExt;
+use yazi_shared::url::AsUrl;
+use yazi_shim::Twox128;
+use yazi_vfs::provider;
+
+pub(super) struct Checksum;
+
+impl Checksum {
+	/// Hashes both files in a streaming fashion, and tells whether they
+	/// have the same content. `adv` is called with the number of bytes
+	/// read from `to`.
+	pub(super) async fn verify<U, V>(from: U, to: V, adv: impl Fn(u64)) -> io::Result<bool>
+	where
+		U: AsUrl,
+		V: AsUrl,
+	{
+		let (a, b) = tokio::try_join!(Self::of(from, |_| ()), Self::of(to, adv))?;
+		Ok(a == b)
+	}
+
+	async fn of<U>(url: U, adv: impl Fn(u64)) -> io::Result<u128>
+	where
+		U: AsUrl,
+	{
+		let mut file = provider::open(url).await?;
+		let mut buf = vec![0; 64 * 1024];
+		let mut h = Twox128::default();
+
+		loop {
+			match file.read(&mut buf).await? {
+				0 => break,
+				n => {
+					h.write(&buf[..n]);
+					adv(n as u64);
+				}
+			}
+		}
+
+		Ok(h.finish_128())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::sync::atomic::{AtomicU64, Ordering};
+
+	use yazi_shared::url::UrlBuf;
+
+	use super::*;
+
+	#[tokio::test]
+	async fn test_verify() {
+		yazi_shared::init_tests();
+		let dir = tempfile::tempdir().unwrap();
+		let url = |name: &str| -> UrlBuf { dir.path().join(name).into() };
+
+		// Spans several reads
+		let content: Vec<u8> = (0..200_000u32).map(|i| i as u8).collect();
+		std::fs::write(dir.path().join("a"), &content).unwrap();
+		std::fs::write(dir.path().join("b"), &content).unwrap();
+
+		let read = AtomicU64::new(0);
+		let adv = |n| _ = read.fetch_add(n, Ordering::Relaxed);
+		assert!(Checksum::verify(url("a"), url("b"), adv).await.unwrap());
+		assert_eq!(read.into_inner(), content.len() as u64);
+
+		// Same length, one byte off
+		let mut other = content.clone();
+		other[100_000] ^= 1;
+		std::fs::write(dir.path().join("c"), &other).unwrap();
+		assert!(!Checksum::verify(url("a"), url("c"), |_| ()).await.unwrap());
+
+		// Truncated
+		std::fs::write(dir.path().join("d"), &content[..1000]).unwrap();
+		assert!(!Checksum::verify(url("a"), url("d"), |_| ()).await.unwrap());
+
+		let e = Checksum::verify(url("a"), url("absent"), |_| ()).await.unwrap_err();
+		assert_eq!(e.kind(), io::ErrorKind::NotFound);
+	}
+}
@@ -0,0 +1,212 @@
+use std::{io, sync::Arc};
+
+use hashbrown::HashSet;
+use serde::{Deserialize, Serialize};
+use strum::{EnumString, IntoStaticStr};
+use tokio::sync::Mutex;
+use yazi_config::popup::ConfirmCfg;
+use yazi_fs::cha::Cha;
+use yazi_shared::url::UrlBuf;
+use yazi_vfs::{provider, unique_file};
+
+use crate::ConfirmProxy;
+
+#[derive(
+	Clone, Copy, Debug, Default, Deserialize, EnumString, Eq, IntoStaticStr, PartialEq, Serialize,
+)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub enum Conflict {
+	/// Rename the top-level destination to a unique name.
+	#[default]
+	Rename,
+	/// Merge into existing directories, overwriting existing files.
+	Overwrite,
+	Skip,
+	/// Keep whichever of the two files was modified more recently.
+	Newer,
+	/// Keep whichever of the two files is larger.
+	Larger,
+	/// Prompt for each conflicting file.
+	Ask,
+	/// Skip files already copied, i.e. of the same size and modification time,
+	/// used to resume an interrupted task into its own destination.
+	Resume,
+}
+
+/// Conflict policy shared by all tasks of a batch, so that
+/// "apply to all" in a prompt takes effect on the remaining files.
+#[derive(Clone, Debug, Default)]
+pub struct Resolver {
+	initial: Conflict,
+	current: Arc<Mutex<Conflict>>,
+	/// Destinations left half-written by an interrupted run of the task.
+	partial: Arc<HashSet<UrlBuf>>,
+}
+
+impl From<Conflict> for Resolver {
+	fn from(value: Conflict) -> Self {
+		Self { initial: value, current: Arc::new(Mutex::new(value)), partial: Default::default() }
+	}
+}
+
+impl Resolver {
+	pub(crate) fn with_partial(mut self, partial: HashSet<UrlBuf>) -> Self {
+		self.partial = Arc::new(partial);
+		self
+	}
+
+	#[inline]
+	pub(crate) fn conflict(&self) -> Conflict { self.initial }
+
+	#[inline]
+	pub(crate) fn partial(&self) -> &HashSet<UrlBuf> { &self.partial }
+
+	/// Whether an existing `to` is what an interrupted run of the task left
+	/// behind, which is resumed regardless of the policy.
+	#[inline]
+	pub(crate) fn resumes(&self, to: &UrlBuf) -> bool {
+		self.initial == Conflict::Resume || self.partial.contains(to)
+	}
+
+	/// Whether existing directories are merged into,
+	/// instead of renaming the top-level destination.
+	#[inline]
+	pub(crate) fn merge(&self) -> bool { self.initial != Conflict::Rename }
+
+	/// Whether each existing file needs a decision before it can be replaced.
+	#[inline]
+	pub(crate) fn selective(&self) -> bool {
+		!matches!(self.initial, Conflict::Rename | Conflict::Overwrite)
+	}
+
+	/// Resolves the destination of the file described by `cha`,
+	/// returns `None` if it should be skipped.
+	pub(crate) async fn resolve(&self, cha: Cha, to: UrlBuf) -> io::Result<Option<UrlBuf>> {
+		let resumes = self.resumes(&to);
+		if !resumes && !self.selective() {
+			return Ok(Some(to));
+		}
+
+		let dest = match provider::symlink_metadata(&to).await {
+			Ok(c) => c,
+			Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Some(to)),
+			Err(e) => Err(e)?,
+		};
+
+		// A file can never replace a directory
+		if dest.is_dir() {
+			return unique_file(to, false).await.map(Some);
+		}
+
+		let conflict = if resumes {
+			Conflict::Resume
+		} else {
+			// Held across the prompt, so that only one prompt is shown at a time
+			let mut current = self.current.lock().await;
+			match *current {
+				Conflict::Ask => match Self::ask(&to).await {
+					Some((c, all)) => {
+						if all {
+							*current = c;
+						}
+						c
+					}
+					None => Conflict::Skip,
+				},
+				c => c,
+			}
+		};
+
+		Ok(match conflict {
+			Conflict::Rename => Some(unique_file(to, false).await?),
+			Conflict::Overwrite | Conflict::Ask => Some(to),
+			Conflict::Skip => None,
+			Conflict::Newer => (cha.mtime > dest.mtime).then_some(to),
+			Conflict::Larger => (cha.len > dest.len).then_some(to),
+			Conflict::Resume => (cha.len != dest.len || cha.mtime != dest.mtime).then_some(to),
+		})
+	}
+
+	async fn ask(to: &UrlBuf) -> Option<(Conflict, bool)> {
+		let (name, all) = ConfirmProxy::choose(ConfirmCfg::conflict(to)).await?;
+		Some((name.parse().ok()?, all))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::time::{Duration, SystemTime};
+
+	use tempfile::TempDir;
+
+	use super::*;
+
+	fn setup() -> (TempDir, UrlBuf, UrlBuf) {
+		let dir = tempfile::tempdir().unwrap();
+		let (from, to) = (dir.path().join("from"), dir.path().join("to"));
+		std::fs::write(&from, "abc").unwrap();
+		std::fs::write(&to, "abcdef").unwrap();
+
+		let old = SystemTime::now() - Duration::from_secs(3600);
+		std::fs::File::options().write(true).open(&to).unwrap().set_modified(old).unwrap();
+
+		(dir, from.into(), to.into())
+	}
+
+	async fn resolve(conflict: Conflict, from: &UrlBuf, to: &UrlBuf) -> Option<UrlBuf> {
+		let cha = provider::symlink_metadata(from).await.unwrap();
+		Resolver::from(conflict).resolve(cha, to.clone()).await.unwrap()
+	}
+
+	#[tokio::test]
+	async fn test_resolve() {
+		let (_dir, from, to) = setup();
+
+		assert_eq!(resolve(Conflict::Overwrite, &from, &to).await, Some(to.clone()));
+		assert_eq!(resolve(Conflict::Skip, &from, &to).await, None);
+		assert_eq!(resolve(Conflict::Newer, &from, &to).await, Some(to.clone()));
+		assert_eq!(resolve(Conflict::Larger, &from, &to).await, None);
+		assert_eq!(resolve(Conflict::Resume, &from, &to).await, Some(to.clone()));
+		assert_eq!(resolve(Conflict::Resume, &from, &from).await, None);
+
+		// Renaming happens once for the top-level destination, not per file
+		assert_eq!(resolve(Conflict::Rename, &from, &to).await, Some(to.clone()));
+
+		// Left half-written by an interrupted run, so resumed whatever the policy
+		let cha = provider::symlink_metadata(&from).await.unwrap();
+		let r = Resolver::from(Conflict::Skip).with_partial([to.clone()].into_iter().collect());
+		assert_eq!(r.resolve(cha, to.clone()).await.unwrap(), Some(to));
+	}
+
+	#[tokio::test]
+	async fn test_resolve_absent_or_dir() {
+		let (dir, from, _) = setup();
+
+		let absent: UrlBuf = dir.path().join("absent").into();
+		assert_eq!(resolve(Conflict::Skip, &from, &absent).await, Some(absent));
+
+		// A file never replaces a directory, it's given a unique name instead
+		std::fs::create_dir(dir.path().join("sub")).unwrap();
+		let sub: UrlBuf = dir.path().join("sub").into();
+		let sub_1: UrlBuf = dir.path().join("sub_1").into();
+		let sub_2: UrlBuf = dir.path().join("sub_2").into();
+		assert_eq!(resolve(Conflict::Skip, &from, &sub).await, Some(sub_1));
+		assert_eq!(resolve(Conflict::Newer, &from, &sub).await, Some(sub_2));
+
+		// Unless nothing is checked, then it's left to the copy to fail
+		assert_eq!(resolve(Conflict::Overwrite, &from, &sub).await, Some(sub));
+	}
+
+	#[test]
+	fn test_resolver_policy() {
+		let r = Resolver::from(Conflict::Rename);
+		assert!(!r.merge() && !r.selective());
+
+		let r = Resolver::from(Conflict::Overwrite);
+		assert!(r.merge() && !r.selective());
+
+		let r = Resolver::from(Conflict::Ask);
+		assert!(r.merge() && r.selective());
+	}
+}
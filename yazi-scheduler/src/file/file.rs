@@ -1,39 +1,46 @@
-use std::mem;
+use std::{collections::VecDeque, mem, sync::Arc, time::Duration};
 
 use anyhow::{Context, Result, anyhow};
+use hashbrown::HashMap;
+use parking_lot::Mutex;
 use tokio::{io::{self, ErrorKind::NotFound}, sync::mpsc};
 use tracing::warn;
 use yazi_config::YAZI;
-use yazi_fs::{Cwd, FsHash128, FsUrl, cha::Cha, ok_or_not_found, path::path_relative_to, provider::{Attrs, FileHolder, Provider, local::Local}};
-use yazi_shared::{path::PathCow, url::{AsUrl, UrlCow, UrlLike}};
+use yazi_fs::{Cwd, FilesOp, FsHash128, FsUrl, cha::Cha, ok_or_not_found, path::path_relative_to, provider::{Attrs, CopyOpt, DirReader, FileHolder, Pause, Provider, local::{Local, Trash}}};
+use yazi_shared::{Id, path::{PathBufDyn, PathCow}, url::{AsUrl, UrlBuf, UrlCow, UrlLike}};
 use yazi_vfs::{VfsCha, maybe_exists, provider::{self, DirEntry}, unique_file};
 
-use super::{FileInCopy, FileInDelete, FileInHardlink, FileInLink, FileInTrash};
-use crate::{LOW, NORMAL, TaskOp, TaskOps, TasksProxy, ctx, file::{FileIn, FileInCut, FileInDownload, FileInUpload, FileOutCopy, FileOutCopyDo, FileOutCut, FileOutCutDo, FileOutDelete, FileOutDeleteDo, FileOutDownload, FileOutDownloadDo, FileOutHardlink, FileOutHardlinkDo, FileOutLink, FileOutTrash, FileOutUpload, FileOutUploadDo, Transaction, Traverse}, hook::{HookInOutCopy, HookInOutCut, HookInOutHardlink, HookInOutLink}, ok_or_not_found};
+use super::{Checksum, FileInCopy, FileInDelete, FileInEmpty, FileInHardlink, FileInLink, FileInRestore, FileInSync, FileInTrash, Resume};
+use crate::{LOW, NORMAL, NotifyProxy, Ongoing, TaskOp, TaskOps, TasksProxy, ctx, file::{FileIn, FileInCut, FileInDownload, FileInUpload, FileOutCopy, FileOutCopyDo, FileOutCut, FileOutCutDo, FileOutDelete, FileOutDeleteDo, FileOutDownload, FileOutDownloadDo, FileOutEmpty, FileOutEmptyDo, FileOutHardlink, FileOutHardlinkDo, FileOutLink, FileOutRestore, FileOutSync, FileOutTrash, FileOutUpload, FileOutUploadDo, RATES, Transaction, Traverse}, hook::{HookInOutCopy, HookInOutCut, HookInOutHardlink, HookInOutLink, HookInOutSync}, ok_or_not_found};
 
 pub(crate) struct File {
-	ops: TaskOps,
-	tx:  async_priority_channel::Sender<FileIn, u8>,
+	ops:     TaskOps,
+	ongoing: Arc<Mutex<Ongoing>>,
+	tx:      async_priority_channel::Sender<FileIn, u8>,
 }
 
 impl File {
 	pub(crate) fn new(
 		ops: &mpsc::UnboundedSender<TaskOp>,
+		ongoing: &Arc<Mutex<Ongoing>>,
 		tx: async_priority_channel::Sender<FileIn, u8>,
 	) -> Self {
-		Self { ops: ops.into(), tx }
+		Self { ops: ops.into(), ongoing: ongoing.clone(), tx }
 	}
 
 	pub(crate) async fn copy(&self, mut task: FileInCopy) -> Result<(), FileOutCopy> {
 		let id = task.id;
 
-		if !task.force {
+		let merged = if task.resolver.merge() {
+			maybe_exists(&task.to).await
+		} else {
 			task.to = unique_file(mem::take(&mut task.to), task.init().await?.is_dir())
 				.await
 				.context("Cannot determine unique destination name")?;
-		}
+			false
+		};
 
-		self.ops.out(id, HookInOutCopy::new(&task.from, &task.to));
+		self.ops.out(id, HookInOutCopy::new(&task.from, &task.to, merged));
 		TasksProxy::update_succeed(id, [&task.to], true);
 
 		super::traverse::<FileOutCopy, _, _, _, _, _>(
@@ -47,7 +54,7 @@ impl File {
 					self.ops.out(id, FileOutCopy::New(0));
 					self.requeue(task.into_link(), NORMAL);
 				} else {
-					self.ops.out(id, FileOutCopy::New(cha.len));
+					self.ops.out(id, FileOutCopy::New(task.cost(cha.len)));
 					self.requeue(task, LOW);
 				})
 			},
@@ -61,17 +68,53 @@ impl File {
 	}
 
 	pub(crate) async fn copy_do(&self, mut task: FileInCopy) -> Result<(), FileOutCopyDo> {
+		if task.retry == 0 && !task.resume.resumed() {
+			let cha = task.cha.unwrap();
+			let Some(to) = ctx!(task, task.resolver.resolve(cha, task.to.clone()).await)? else {
+				if task.resolver.partial().contains(&task.to) {
+					self.ops.out(task.id, FileOutCopyDo::Close(task.to.clone()));
+				}
+				self.ops.out(task.id, FileOutCopyDo::Adv(task.cost(cha.len)));
+				return Ok(self.ops.out(task.id, FileOutCopyDo::Succ));
+			};
+			if task.resolver.resumes(&to) {
+				task.resume.reuse(&to).await;
+			}
+			task.to = to;
+			self.ops.out(task.id, FileOutCopyDo::Open(task.to.clone()));
+		}
+
 		ok_or_not_found!(task, Transaction::unlink(&task.to).await);
-		let mut rx =
-			ctx!(task, provider::copy_with_progress(&task.from, &task.to, task.cha.unwrap()).await)?;
+		let mut rx = ctx!(
+			task,
+			provider::resume_with_progress(
+				&task.from,
+				&task.to,
+				task.resume.offset,
+				CopyOpt::from(task.cha.unwrap()).with_rate(&RATES.file).with_pause(self.pause(task.id)),
+			)
+			.await
+		)?;
 
 		loop {
 			match rx.recv().await.unwrap_or(Ok(0)) {
 				Ok(0) => break,
-				Ok(n) => self.ops.out(task.id, FileOutCopyDo::Adv(n)),
+				Ok(n) => match task.resume.advance(n) {
+					0 => {}
+					n => self.ops.out(task.id, FileOutCopyDo::Adv(n)),
+				},
 				Err(e) if e.kind() == NotFound => {
 					warn!("Copy task partially done: {task:?}");
-					break;
+					return Ok(self.ops.out(task.id, FileOutCopyDo::Succ));
+				}
+				Err(e) if Resume::suspended(&e) => {
+					task.resume.suspend(&e);
+					return Ok(self.requeue(task, LOW));
+				}
+				Err(e) if task.resume.resumable(&e) => {
+					self.ops.out(task.id, FileOutCopyDo::Log(format!("Resuming due to error: {e}")));
+					let delay = task.resume.restart(&e);
+					return Ok(self.requeue_after(task, LOW, delay));
 				}
 				// Operation not permitted (os error 1)
 				// Attribute not found (os error 93)
@@ -86,22 +129,41 @@ impl File {
 				Err(e) => ctx!(task, Err(e))?,
 			}
 		}
+
+		if task.verify {
+			let adv = |n| self.ops.out(task.id, FileOutCopyDo::Adv(n));
+			if !ctx!(task, Checksum::verify(&task.from, &task.to, adv).await)? {
+				Err(anyhow!(
+					"Checksum mismatch between {} and {}",
+					task.from.display(),
+					task.to.display()
+				))?;
+			}
+		}
+
+		self.ops.out(task.id, FileOutCopyDo::Close(task.to.clone()));
 		Ok(self.ops.out(task.id, FileOutCopyDo::Succ))
 	}
 
 	pub(crate) async fn cut(&self, mut task: FileInCut) -> Result<(), FileOutCut> {
 		let id = task.id;
 
-		if !task.force {
+		let merged = if task.resolver.merge() {
+			maybe_exists(&task.to).await
+		} else {
 			task.to = unique_file(mem::take(&mut task.to), task.init().await?.is_dir())
 				.await
 				.context("Cannot determine unique destination name")?;
-		}
+			false
+		};
 
-		self.ops.out(id, HookInOutCut::new(&task.from, &task.to));
+		self.ops.out(id, HookInOutCut::new(&task.from, &task.to, merged));
 		TasksProxy::update_succeed(id, [&task.to], true);
 
-		if !task.follow && ok_or_not_found(provider::rename(&task.from, &task.to).await).is_ok() {
+		if !task.follow
+			&& (!task.resolver.selective() || !maybe_exists(&task.to).await)
+			&& ok_or_not_found(provider::rename(&task.from, &task.to).await).is_ok()
+		{
 			return Ok(self.ops.out(id, FileOutCut::Succ));
 		}
 
@@ -116,7 +178,7 @@ impl File {
 			},
 			|task, cha| {
 				let nofollow = cha.is_orphan() || (cha.is_link() && !task.follow);
-				self.ops.out(id, FileOutCut::New(if nofollow { 0 } else { cha.len }));
+				self.ops.out(id, FileOutCut::New(if nofollow { 0 } else { task.cost(cha.len) }));
 
 				if nofollow {
 					self.requeue(task.into_link(), NORMAL);
@@ -153,20 +215,55 @@ impl File {
 	}
 
 	pub(crate) async fn cut_do(&self, mut task: FileInCut) -> Result<(), FileOutCutDo> {
+		if task.retry == 0 && !task.resume.resumed() {
+			let cha = task.cha.unwrap();
+			let Some(to) = ctx!(task, task.resolver.resolve(cha, task.to.clone()).await)? else {
+				// Moved already before the interruption, only the source is left behind
+				if task.resolver.resumes(&task.to) {
+					provider::remove_file(&task.from).await.ok();
+					self.ops.out(task.id, FileOutCutDo::Close(task.to.clone()));
+				}
+				self.ops.out(task.id, FileOutCutDo::Adv(task.cost(cha.len)));
+				return Ok(self.ops.out(task.id, FileOutCutDo::Succ));
+			};
+			if task.resolver.resumes(&to) {
+				task.resume.reuse(&to).await;
+			}
+			task.to = to;
+			self.ops.out(task.id, FileOutCutDo::Open(task.to.clone()));
+		}
+
 		ok_or_not_found!(task, Transaction::unlink(&task.to).await);
-		let mut rx =
-			ctx!(task, provider::copy_with_progress(&task.from, &task.to, task.cha.unwrap()).await)?;
+		let mut rx = ctx!(
+			task,
+			provider::resume_with_progress(
+				&task.from,
+				&task.to,
+				task.resume.offset,
+				CopyOpt::from(task.cha.unwrap()).with_rate(&RATES.file).with_pause(self.pause(task.id)),
+			)
+			.await
+		)?;
 
 		loop {
 			match rx.recv().await.unwrap_or(Ok(0)) {
-				Ok(0) => {
-					provider::remove_file(&task.from).await.ok();
-					break;
-				}
-				Ok(n) => self.ops.out(task.id, FileOutCutDo::Adv(n)),
+				Ok(0) => break,
+				Ok(n) => match task.resume.advance(n) {
+					0 => {}
+					n => self.ops.out(task.id, FileOutCutDo::Adv(n)),
+				},
 				Err(e) if e.kind() == NotFound => {
 					warn!("Cut task partially done: {task:?}");
-					break;
+					return Ok(self.ops.out(task.id, FileOutCutDo::Succ));
+				}
+				Err(e) if Resume::suspended(&e) => {
+					task.resume.suspend(&e);
+					return Ok(self.requeue(task, LOW));
+				}
+				Err(e) if task.resume.resumable(&e) => {
+					self.ops.out(task.id, FileOutCutDo::Log(format!("Resuming due to error: {e}")));
+					let delay = task.resume.restart(&e);
+					return Ok(self.requeue_after(task, LOW, delay));
 				}
 				// Operation not permitted (os error 1)
 				// Attribute not found (os error 93)
@@ -181,6 +278,21 @@ impl File {
 				Err(e) => ctx!(task, Err(e))?,
 			}
 		}
+
+		// The source is only deleted once the destination is known to be intact
+		if task.verify {
+			let adv = |n| self.ops.out(task.id, FileOutCutDo::Adv(n));
+			if !ctx!(task, Checksum::verify(&task.from, &task.to, adv).await)? {
+				Err(anyhow!(
+					"Checksum mismatch between {} and {}, source kept",
+					task.from.display(),
+					task.to.display()
+				))?;
+			}
+		}
+
+		provider::remove_file(&task.from).await.ok();
+		self.ops.out(task.id, FileOutCutDo::Close(task.to.clone()));
 		Ok(self.ops.out(task.id, FileOutCutDo::Succ))
 	}
 
@@ -309,6 +421,50 @@ impl File {
 		Ok(self.ops.out(task.id, FileOutTrash::Succ))
 	}
 
+	pub(crate) async fn restore(&self, task: FileInRestore) -> Result<(), FileOutRestore> {
+		Ok(self.requeue(task, LOW))
+	}
+
+	pub(crate) async fn restore_do(&self, task: FileInRestore) -> Result<(), FileOutRestore> {
+		let Some(path) = task.target.as_local() else {
+			Err(anyhow!("Failed to work on {task:?}: not a local file"))?
+		};
+
+		let origin = ctx!(task, Trash::recover(path).await)?;
+		if let Some((parent, urn)) = task.target.pair() {
+			FilesOp::Deleting(parent.into(), [urn.into()].into()).emit();
+		}
+
+		TasksProxy::update_succeed(task.id, [UrlBuf::from(origin)], false);
+		Ok(self.ops.out(task.id, FileOutRestore::Succ))
+	}
+
+	pub(crate) async fn empty(&self, task: FileInEmpty) -> Result<(), FileOutEmpty> {
+		let id = task.id;
+
+		for trashed in ctx!(task, Trash::list().await)? {
+			let Some(target) = trashed.url() else { continue };
+
+			self.ops.out(id, FileOutEmpty::New);
+			self.requeue(FileInEmpty { id, target }, NORMAL);
+		}
+
+		Ok(self.ops.out(id, FileOutEmpty::Succ))
+	}
+
+	pub(crate) async fn empty_do(&self, task: FileInEmpty) -> Result<(), FileOutEmptyDo> {
+		let Some(path) = task.target.as_local() else {
+			Err(anyhow!("Failed to work on {task:?}: not a local file"))?
+		};
+
+		ctx!(task, Trash::purge(path).await)?;
+		if let Some((parent, urn)) = task.target.pair() {
+			FilesOp::Deleting(parent.into(), [urn.into()].into()).emit();
+		}
+
+		Ok(self.ops.out(task.id, FileOutEmptyDo::Succ))
+	}
+
 	pub(crate) async fn download(&self, task: FileInDownload) -> Result<(), FileOutDownload> {
 		let id = task.id;
 
@@ -343,9 +499,22 @@ impl File {
 		let cha = task.cha.unwrap();
 
 		let cache = ctx!(task, task.target.cache(), "Cannot determine cache path")?;
-		let cache_tmp = ctx!(task, Transaction::tmp(&cache).await, "Cannot determine download cache")?;
+		let cache_tmp = match task.resume.tmp.clone() {
+			Some(tmp) => tmp,
+			None => ctx!(task, Transaction::tmp(&cache).await, "Cannot determine download cache")?,
+		};
+		task.resume.tmp = Some(cache_tmp.clone());
 
-		let mut rx = ctx!(task, provider::copy_with_progress(&task.target, &cache_tmp, cha).await)?;
+		let mut rx = ctx!(
+			task,
+			provider::resume_with_progress(
+				&task.target,
+				&cache_tmp,
+				task.resume.offset,
+				CopyOpt::from(cha).with_rate(&RATES.download).with_pause(self.pause(task.id)),
+			)
+			.await
+		)?;
 		loop {
 			match rx.recv().await.unwrap_or(Ok(0)) {
 				Ok(0) => {
@@ -358,11 +527,23 @@ impl File {
 
 					break;
 				}
-				Ok(n) => self.ops.out(task.id, FileOutDownloadDo::Adv(n)),
+				Ok(n) => match task.resume.advance(n) {
+					0 => {}
+					n => self.ops.out(task.id, FileOutDownloadDo::Adv(n)),
+				},
 				Err(e) if e.kind() == NotFound => {
 					warn!("Download task partially done: {task:?}");
 					break;
 				}
+				Err(e) if Resume::suspended(&e) => {
+					task.resume.suspend(&e);
+					return Ok(self.requeue(task, LOW));
+				}
+				Err(e) if task.resume.resumable(&e) => {
+					self.ops.out(task.id, FileOutDownloadDo::Log(format!("Resuming due to error: {e}")));
+					let delay = task.resume.restart(&e);
+					return Ok(self.requeue_after(task, LOW, delay));
+				}
 				// Operation not permitted (os error 1)
 				// Attribute not found (os error 93)
 				Err(e)
@@ -407,7 +588,7 @@ impl File {
 		Ok(self.ops.out(id, FileOutUpload::Succ))
 	}
 
-	pub(crate) async fn upload_do(&self, task: FileInUpload) -> Result<(), FileOutUploadDo> {
+	pub(crate) async fn upload_do(&self, mut task: FileInUpload) -> Result<(), FileOutUploadDo> {
 		let cha = task.cha.unwrap();
 		let cache = ctx!(task, task.cache.as_ref(), "Cannot determine cache path")?;
 		let lock = ctx!(task, task.target.cache_lock(), "Cannot determine cache lock")?;
@@ -418,16 +599,23 @@ impl File {
 			Err(anyhow!("Failed to work on: {task:?}: remote file has changed since last download"))?;
 		}
 
-		let tmp =
-			ctx!(task, Transaction::tmp(&task.target).await, "Cannot determine temporary upload path")?;
+		let tmp = match task.resume.tmp.clone() {
+			Some(tmp) => tmp,
+			None => {
+				ctx!(task, Transaction::tmp(&task.target).await, "Cannot determine temporary upload path")?
+			}
+		};
+		task.resume.tmp = Some(tmp.clone());
+
+		let attrs = Attrs { mode: Some(cha.mode), atime: None, btime: None, mtime: None };
 		let mut rx = ctx!(
 			task,
-			provider::copy_with_progress(cache, &tmp, Attrs {
-				mode:  Some(cha.mode),
-				atime: None,
-				btime: None,
-				mtime: None,
-			})
+			provider::resume_with_progress(
+				cache,
+				&tmp,
+				task.resume.offset,
+				CopyOpt::from(attrs).with_rate(&RATES.upload).with_pause(self.pause(task.id)),
+			)
 			.await
 		)?;
 
@@ -449,13 +637,141 @@ impl File {
 
 					break;
 				}
-				Ok(n) => self.ops.out(task.id, FileOutUploadDo::Adv(n)),
+				Ok(n) => match task.resume.advance(n) {
+					0 => {}
+					n => self.ops.out(task.id, FileOutUploadDo::Adv(n)),
+				},
+				Err(e) if Resume::suspended(&e) => {
+					task.resume.suspend(&e);
+					return Ok(self.requeue(task, LOW));
+				}
+				Err(e) if task.resume.resumable(&e) => {
+					self.ops.out(task.id, FileOutUploadDo::Log(format!("Resuming due to error: {e}")));
+					let delay = task.resume.restart(&e);
+					return Ok(self.requeue_after(task, LOW, delay));
+				}
 				Err(e) => ctx!(task, Err(e))?,
 			}
 		}
 		Ok(self.ops.out(task.id, FileOutUploadDo::Succ))
 	}
 
+	pub(crate) async fn sync(&self, task: FileInSync) -> Result<(), FileOutSync> {
+		let id = task.id;
+		if !ctx!(task, Self::cha(&task.from, task.follow, None).await)?.is_dir() {
+			Err(anyhow!("Failed to work on {task:?}: source is not a directory"))?;
+		}
+
+		macro_rules! err {
+			($result:expr, $($args:tt)*) => {
+				match $result {
+					Ok(v) => v,
+					Err(e) => {
+						self.ops.out(id, FileOutSync::Deform(format!("{}: {e:?}", format_args!($($args)*))));
+						continue;
+					}
+				}
+			};
+		}
+
+		let mut plan = vec![];
+		macro_rules! plan {
+			($($args:tt)*) => {{
+				let line = format!($($args)*);
+				self.ops.out(id, FileOutSync::Plan(line.clone()));
+				plan.push(line);
+			}};
+		}
+
+		let mut dirs = VecDeque::from([(task.from.clone(), task.to.clone())]);
+		let mut extraneous = vec![];
+		while let Some((src, dest)) = dirs.pop_front() {
+			let mut existing = match Self::entries(&dest, false).await {
+				Ok(entries) => entries,
+				Err(e) if e.kind() == NotFound => {
+					if task.dry {
+						plan!("Create {}", dest.display());
+					} else {
+						err!(provider::create_dir(&dest).await, "Cannot create directory {dest:?}");
+					}
+					HashMap::new()
+				}
+				Err(e) => err!(Err(e), "Cannot read directory {dest:?}"),
+			};
+
+			// Sorted so that a dry run lists the planned operations in a stable order
+			let mut entries: Vec<_> =
+				err!(Self::entries(&src, task.follow).await, "Cannot read directory {src:?}")
+					.into_iter()
+					.collect();
+			entries.sort_unstable_by(|(_, (a, _)), (_, (b, _))| {
+				a.urn().encoded_bytes().cmp(b.urn().encoded_bytes())
+			});
+
+			for (urn, (from, cha)) in entries {
+				let old = existing.remove(&urn).map(|(_, c)| c);
+				let to = err!(dest.try_join(&urn), "Cannot join {dest:?} with {urn:?}");
+
+				match (cha.is_dir(), old) {
+					(true, Some(c)) if !c.is_dir() => {
+						err!(Err(anyhow!("a file is in the way")), "Cannot sync directory {from:?} to {to:?}")
+					}
+					(true, _) => dirs.push_back((from, to)),
+					(false, Some(c)) if c.is_dir() => {
+						err!(Err(anyhow!("a directory is in the way")), "Cannot sync file {from:?} to {to:?}")
+					}
+					(false, Some(c)) if FileInSync::unchanged(cha, c) => {}
+					(false, _) if task.dry => plan!("Copy {} to {}", from.display(), to.display()),
+					(false, _) => {
+						let task = task.copy(from, to, cha);
+						if cha.is_orphan() || (cha.is_link() && !task.follow) {
+							self.ops.out(id, FileOutSync::New(0));
+							self.requeue(task.into_link(), NORMAL);
+						} else {
+							self.ops.out(id, FileOutSync::New(task.cost(cha.len)));
+							self.requeue(task, LOW);
+						}
+					}
+				}
+			}
+
+			let mut existing: Vec<_> = existing.into_values().filter(|_| task.delete).collect();
+			existing
+				.sort_unstable_by(|(a, _), (b, _)| a.urn().encoded_bytes().cmp(b.urn().encoded_bytes()));
+
+			for (url, cha) in existing {
+				if task.dry {
+					plan!("Delete {}", url.display());
+				} else if cha.is_dir() {
+					extraneous.push(url);
+				} else {
+					self.ops.out(id, FileOutSync::New(cha.len));
+					self.requeue(task.delete(url, cha), NORMAL);
+				}
+			}
+		}
+
+		if task.dry {
+			NotifyProxy::push_info(format!("Sync {}", task.to.display()), FileInSync::summarize(&plan));
+			self.ops.out(id, FileOutSync::Clean(Ok(())));
+		} else {
+			self.ops.out(id, HookInOutSync::new(&task.to, extraneous));
+		}
+		Ok(self.ops.out(id, FileOutSync::Succ))
+	}
+
+	async fn entries(dir: &UrlBuf, follow: bool) -> io::Result<HashMap<PathBufDyn, (UrlBuf, Cha)>> {
+		let mut it = provider::read_dir(dir).await?;
+		let mut entries = HashMap::new();
+
+		while let Some(entry) = it.next().await? {
+			let url = entry.url();
+			let cha = Self::cha(&url, follow, Some(entry)).await?;
+			entries.insert(url.urn().into(), (url, cha));
+		}
+		Ok(entries)
+	}
+
 	pub(super) async fn cha<U>(url: U, follow: bool, entry: Option<DirEntry>) -> io::Result<Cha>
 	where
 		U: AsUrl,
@@ -475,8 +791,22 @@ impl File {
 		_ = self.tx.try_send(r#in.into(), priority);
 	}
 
+	/// The pause switch of the task `id`, held by its copies in progress.
+	fn pause(&self, id: Id) -> Pause {
+		self.ongoing.lock().get_mut(id).map(|t| t.pause.clone()).unwrap_or_default()
+	}
+
 	#[inline]
 	fn requeue(&self, r#in: impl Into<FileIn>, priority: u8) {
 		_ = self.tx.try_send(r#in.into().into_doable(), priority);
 	}
+
+	/// Requeues once `delay` has passed, without holding a worker meanwhile.
+	fn requeue_after(&self, r#in: impl Into<FileIn>, priority: u8, delay: Duration) {
+		let (tx, r#in) = (self.tx.clone(), r#in.into().into_doable());
+		tokio::spawn(async move {
+			tokio::time::sleep(delay).await;
+			_ = tx.try_send(r#in, priority);
+		});
+	}
 }
@@ -1,10 +1,10 @@
-use std::{borrow::Cow, mem, path::PathBuf};
+use std::{borrow::Cow, mem, path::PathBuf, time::UNIX_EPOCH};
 
 use tokio::sync::mpsc;
 use yazi_fs::cha::Cha;
 use yazi_shared::{Id, url::{UrlBuf, UrlLike}};
 
-use crate::{TaskIn, file::{FileProgCopy, FileProgCut, FileProgDelete, FileProgDownload, FileProgHardlink, FileProgLink, FileProgTrash, FileProgUpload}};
+use crate::{TaskIn, file::{Conflict, FileProgCopy, FileProgCut, FileProgDelete, FileProgDownload, FileProgEmpty, FileProgHardlink, FileProgLink, FileProgRestore, FileProgSync, FileProgTrash, FileProgUpload, Resolver, Resume}};
 
 #[derive(Debug)]
 pub(crate) enum FileIn {
@@ -20,10 +20,15 @@ pub(crate) enum FileIn {
 	DeleteDo(FileInDelete),
 	Trash(FileInTrash),
 	TrashDo(FileInTrash),
+	Restore(FileInRestore),
+	RestoreDo(FileInRestore),
+	Empty(FileInEmpty),
+	EmptyDo(FileInEmpty),
 	Download(FileInDownload),
 	DownloadDo(FileInDownload),
 	Upload(FileInUpload),
 	UploadDo(FileInUpload),
+	Sync(FileInSync),
 }
 
 impl TaskIn for FileIn {
@@ -43,10 +48,15 @@ impl TaskIn for FileIn {
 			Self::DeleteDo(r#in) => r#in.id(),
 			Self::Trash(r#in) => r#in.id(),
 			Self::TrashDo(r#in) => r#in.id(),
+			Self::Restore(r#in) => r#in.id(),
+			Self::RestoreDo(r#in) => r#in.id(),
+			Self::Empty(r#in) => r#in.id(),
+			Self::EmptyDo(r#in) => r#in.id(),
 			Self::Download(r#in) => r#in.id(),
 			Self::DownloadDo(r#in) => r#in.id(),
 			Self::Upload(r#in) => r#in.id(),
 			Self::UploadDo(r#in) => r#in.id(),
+			Self::Sync(r#in) => r#in.id(),
 		}
 	}
 
@@ -64,10 +74,15 @@ impl TaskIn for FileIn {
 			Self::DeleteDo(r#in) => _ = r#in.set_id(id),
 			Self::Trash(r#in) => _ = r#in.set_id(id),
 			Self::TrashDo(r#in) => _ = r#in.set_id(id),
+			Self::Restore(r#in) => _ = r#in.set_id(id),
+			Self::RestoreDo(r#in) => _ = r#in.set_id(id),
+			Self::Empty(r#in) => _ = r#in.set_id(id),
+			Self::EmptyDo(r#in) => _ = r#in.set_id(id),
 			Self::Download(r#in) => _ = r#in.set_id(id),
 			Self::DownloadDo(r#in) => _ = r#in.set_id(id),
 			Self::Upload(r#in) => _ = r#in.set_id(id),
 			Self::UploadDo(r#in) => _ = r#in.set_id(id),
+			Self::Sync(r#in) => _ = r#in.set_id(id),
 		}
 		self
 	}
@@ -86,10 +101,15 @@ impl TaskIn for FileIn {
 			Self::DeleteDo(r#in) => r#in.title(),
 			Self::Trash(r#in) => r#in.title(),
 			Self::TrashDo(r#in) => r#in.title(),
+			Self::Restore(r#in) => r#in.title(),
+			Self::RestoreDo(r#in) => r#in.title(),
+			Self::Empty(r#in) => r#in.title(),
+			Self::EmptyDo(r#in) => r#in.title(),
 			Self::Download(r#in) => r#in.title(),
 			Self::DownloadDo(r#in) => r#in.title(),
 			Self::Upload(r#in) => r#in.title(),
 			Self::UploadDo(r#in) => r#in.title(),
+			Self::Sync(r#in) => r#in.title(),
 		}
 	}
 }
@@ -101,8 +121,11 @@ impl_from_in! {
 	Hardlink(FileInHardlink),
 	Delete(FileInDelete),
 	Trash(FileInTrash),
+	Restore(FileInRestore),
+	Empty(FileInEmpty),
 	Download(FileInDownload),
 	Upload(FileInUpload),
+	Sync(FileInSync),
 }
 
 impl FileIn {
@@ -120,10 +143,16 @@ impl FileIn {
 			Self::DeleteDo(_) => self,
 			Self::Trash(r#in) => Self::TrashDo(r#in),
 			Self::TrashDo(_) => self,
+			Self::Restore(r#in) => Self::RestoreDo(r#in),
+			Self::RestoreDo(_) => self,
+			Self::Empty(r#in) => Self::EmptyDo(r#in),
+			Self::EmptyDo(_) => self,
 			Self::Download(r#in) => Self::DownloadDo(r#in),
 			Self::DownloadDo(_) => self,
 			Self::Upload(r#in) => Self::UploadDo(r#in),
 			Self::UploadDo(_) => self,
+			// Syncing is split into copy and delete work, so it has no doable of its own
+			Self::Sync(_) => self,
 		}
 	}
 }
@@ -131,13 +160,15 @@ impl FileIn {
 // --- Copy
 #[derive(Clone, Debug)]
 pub(crate) struct FileInCopy {
-	pub(crate) id:     Id,
-	pub(crate) from:   UrlBuf,
-	pub(crate) to:     UrlBuf,
-	pub(crate) force:  bool,
-	pub(crate) cha:    Option<Cha>,
-	pub(crate) follow: bool,
-	pub(crate) retry:  u8,
+	pub(crate) id:       Id,
+	pub(crate) from:     UrlBuf,
+	pub(crate) to:       UrlBuf,
+	pub(crate) resolver: Resolver,
+	pub(crate) cha:      Option<Cha>,
+	pub(crate) follow:   bool,
+	pub(crate) verify:   bool,
+	pub(crate) retry:    u8,
+	pub(crate) resume:   Resume,
 }
 
 impl TaskIn for FileInCopy {
@@ -156,6 +187,11 @@ impl TaskIn for FileInCopy {
 }
 
 impl FileInCopy {
+	/// Bytes of work for a file of `len`, which is read back once more when
+	/// verifying.
+	#[inline]
+	pub(super) fn cost(&self, len: u64) -> u64 { if self.verify { len * 2 } else { len } }
+
 	pub(super) fn into_link(self) -> FileInLink {
 		FileInLink {
 			id:       self.id,
@@ -173,14 +209,16 @@ impl FileInCopy {
 // --- Cut
 #[derive(Clone, Debug)]
 pub(crate) struct FileInCut {
-	pub(crate) id:     Id,
-	pub(crate) from:   UrlBuf,
-	pub(crate) to:     UrlBuf,
-	pub(crate) force:  bool,
-	pub(crate) cha:    Option<Cha>,
-	pub(crate) follow: bool,
-	pub(crate) retry:  u8,
-	pub(crate) drop:   Option<mpsc::Sender<()>>,
+	pub(crate) id:       Id,
+	pub(crate) from:     UrlBuf,
+	pub(crate) to:       UrlBuf,
+	pub(crate) resolver: Resolver,
+	pub(crate) cha:      Option<Cha>,
+	pub(crate) follow:   bool,
+	pub(crate) verify:   bool,
+	pub(crate) retry:    u8,
+	pub(crate) resume:   Resume,
+	pub(crate) drop:     Option<mpsc::Sender<()>>,
 }
 
 impl TaskIn for FileInCut {
@@ -203,6 +241,9 @@ impl Drop for FileInCut {
 }
 
 impl FileInCut {
+	#[inline]
+	pub(super) fn cost(&self, len: u64) -> u64 { if self.verify { len * 2 } else { len } }
+
 	pub(super) fn into_link(mut self) -> FileInLink {
 		FileInLink {
 			id:       self.id,
@@ -317,6 +358,46 @@ impl TaskIn for FileInTrash {
 	fn title(&self) -> Cow<'_, str> { format!("Trash {}", self.target.display()).into() }
 }
 
+// --- Restore
+#[derive(Clone, Debug)]
+pub(crate) struct FileInRestore {
+	pub(crate) id:     Id,
+	pub(crate) target: UrlBuf,
+}
+
+impl TaskIn for FileInRestore {
+	type Prog = FileProgRestore;
+
+	fn id(&self) -> Id { self.id }
+
+	fn set_id(&mut self, id: Id) -> &mut Self {
+		self.id = id;
+		self
+	}
+
+	fn title(&self) -> Cow<'_, str> { format!("Restore {}", self.target.display()).into() }
+}
+
+// --- Empty
+#[derive(Clone, Debug)]
+pub(crate) struct FileInEmpty {
+	pub(crate) id:     Id,
+	pub(crate) target: UrlBuf,
+}
+
+impl TaskIn for FileInEmpty {
+	type Prog = FileProgEmpty;
+
+	fn id(&self) -> Id { self.id }
+
+	fn set_id(&mut self, id: Id) -> &mut Self {
+		self.id = id;
+		self
+	}
+
+	fn title(&self) -> Cow<'_, str> { "Empty trash".into() }
+}
+
 // --- Download
 #[derive(Clone, Debug)]
 pub(crate) struct FileInDownload {
@@ -324,6 +405,7 @@ pub(crate) struct FileInDownload {
 	pub(crate) target: UrlBuf,
 	pub(crate) cha:    Option<Cha>,
 	pub(crate) retry:  u8,
+	pub(crate) resume: Resume,
 }
 
 impl TaskIn for FileInDownload {
@@ -346,6 +428,8 @@ pub(crate) struct FileInUpload {
 	pub(crate) target: UrlBuf,
 	pub(crate) cha:    Option<Cha>,
 	pub(crate) cache:  Option<PathBuf>,
+	pub(crate) retry:  u8,
+	pub(crate) resume: Resume,
 }
 
 impl TaskIn for FileInUpload {
@@ -360,3 +444,96 @@ impl TaskIn for FileInUpload {
 
 	fn title(&self) -> Cow<'_, str> { format!("Upload {}", self.target.display()).into() }
 }
+
+// --- Sync
+#[derive(Clone, Debug)]
+pub(crate) struct FileInSync {
+	pub(crate) id:     Id,
+	pub(crate) from:   UrlBuf,
+	pub(crate) to:     UrlBuf,
+	pub(crate) follow: bool,
+	pub(crate) verify: bool,
+	/// Whether to delete files in the destination that aren't in the source.
+	pub(crate) delete: bool,
+	/// Whether to only list the planned operations, without carrying them out.
+	pub(crate) dry:    bool,
+}
+
+impl TaskIn for FileInSync {
+	type Prog = FileProgSync;
+
+	fn id(&self) -> Id { self.id }
+
+	fn set_id(&mut self, id: Id) -> &mut Self {
+		self.id = id;
+		self
+	}
+
+	fn title(&self) -> Cow<'_, str> {
+		let dry = if self.dry { " (dry run)" } else { "" };
+		format!("Sync {} to {}{dry}", self.from.display(), self.to.display()).into()
+	}
+}
+
+impl FileInSync {
+	/// Whether the destination file `dest` is up to date with the source `src`.
+	///
+	/// Only whole seconds of the mtime are compared, as remote providers like
+	/// SFTP don't keep finer precision.
+	pub(super) fn unchanged(src: Cha, dest: Cha) -> bool {
+		let secs =
+			|c: Cha| c.mtime.and_then(|t| t.duration_since(UNIX_EPOCH).ok()).map(|d| d.as_secs());
+		!dest.is_dir() && src.len == dest.len && secs(src) == secs(dest)
+	}
+
+	pub(super) fn copy(&self, from: UrlBuf, to: UrlBuf, cha: Cha) -> FileInCopy {
+		FileInCopy {
+			id: self.id,
+			from,
+			to,
+			resolver: Conflict::Overwrite.into(),
+			cha: Some(cha),
+			follow: self.follow,
+			verify: self.verify,
+			retry: 0,
+			resume: Default::default(),
+		}
+	}
+
+	pub(super) fn delete(&self, target: UrlBuf, cha: Cha) -> FileInDelete {
+		FileInDelete { id: self.id, target, cha: Some(cha) }
+	}
+
+	/// What a dry run would do, shown to the user once it's done, the full list
+	/// is in the task log.
+	pub(super) fn summarize(plan: &[String]) -> String {
+		const MAX: usize = 10;
+		if plan.is_empty() {
+			return "Already up to date".to_owned();
+		}
+
+		let mut lines: Vec<_> = plan.iter().take(MAX).map(String::as_str).collect();
+		let more = format!("...and {} more, see the task log", plan.len().saturating_sub(MAX));
+		if plan.len() > MAX {
+			lines.push(&more);
+		}
+		lines.join("\n")
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_summarize() {
+		assert_eq!(FileInSync::summarize(&[]), "Already up to date");
+
+		let plan: Vec<_> = (0..12).map(|i| format!("Copy {i}")).collect();
+		assert_eq!(FileInSync::summarize(&plan[..2]), "Copy 0\nCopy 1");
+
+		let summary = FileInSync::summarize(&plan);
+		assert_eq!(summary.lines().count(), 11);
+		assert!(summary.ends_with("Copy 9\n...and 2 more, see the task log"));
+	}
+}
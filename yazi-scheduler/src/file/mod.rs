@@ -1,4 +1,4 @@
 #[macro_use]
 mod macros;
 
-yazi_macro::mod_flat!(file out progress r#in transaction traverse);
+yazi_macro::mod_flat!(checksum conflict file out progress r#in rates resume transaction traverse);
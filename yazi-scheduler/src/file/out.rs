@@ -1,5 +1,7 @@
 use std::io;
 
+use yazi_shared::url::UrlBuf;
+
 use crate::{CleanupState, Task, TaskProg};
 
 // --- Copy
@@ -50,8 +52,10 @@ impl FileOutCopy {
 // --- CopyDo
 #[derive(Debug)]
 pub(crate) enum FileOutCopyDo {
+	Open(UrlBuf),
 	Adv(u64),
 	Log(String),
+	Close(UrlBuf),
 	Succ,
 	Fail(String),
 }
@@ -62,19 +66,25 @@ impl From<anyhow::Error> for FileOutCopyDo {
 
 impl FileOutCopyDo {
 	pub(crate) fn reduce(self, task: &mut Task) {
-		let TaskProg::FileCopy(prog) = &mut task.prog else { return };
+		let Some(prog) = task.prog.tally() else { return };
 		match self {
+			Self::Open(dest) => {
+				task.pending.as_mut().map(|p| p.open(dest));
+			}
 			Self::Adv(size) => {
-				prog.processed_bytes += size;
+				prog.adv(size);
 			}
 			Self::Log(line) => {
 				task.log(line);
 			}
+			Self::Close(dest) => {
+				task.pending.as_mut().map(|p| p.close(&dest));
+			}
 			Self::Succ => {
-				prog.success_files += 1;
+				prog.succ();
 			}
 			Self::Fail(reason) => {
-				prog.failed_files += 1;
+				prog.fail();
 				task.log(reason);
 			}
 		}
@@ -133,8 +143,10 @@ impl FileOutCut {
 // --- CutDo
 #[derive(Debug)]
 pub(crate) enum FileOutCutDo {
+	Open(UrlBuf),
 	Adv(u64),
 	Log(String),
+	Close(UrlBuf),
 	Succ,
 	Fail(String),
 }
@@ -147,12 +159,18 @@ impl FileOutCutDo {
 	pub(crate) fn reduce(self, task: &mut Task) {
 		let TaskProg::FileCut(prog) = &mut task.prog else { return };
 		match self {
+			Self::Open(dest) => {
+				task.pending.as_mut().map(|p| p.open(dest));
+			}
 			Self::Adv(size) => {
 				prog.processed_bytes += size;
 			}
 			Self::Log(line) => {
 				task.log(line);
 			}
+			Self::Close(dest) => {
+				task.pending.as_mut().map(|p| p.close(&dest));
+			}
 			Self::Succ => {
 				prog.success_files += 1;
 			}
@@ -191,24 +209,14 @@ impl FileOutLink {
 					prog.cleaned = CleanupState::Success;
 				}
 			}
-		} else if let TaskProg::FileCopy(prog) = &mut task.prog {
+		} else if let Some(prog) = task.prog.tally() {
+			// Links made on behalf of a copy, cut or sync
 			match self {
 				Self::Succ => {
-					prog.success_files += 1;
+					prog.succ();
 				}
 				Self::Fail(reason) => {
-					prog.failed_files += 1;
-					task.log(reason);
-				}
-				Self::Clean => {}
-			}
-		} else if let TaskProg::FileCut(prog) = &mut task.prog {
-			match self {
-				Self::Succ => {
-					prog.success_files += 1;
-				}
-				Self::Fail(reason) => {
-					prog.failed_files += 1;
+					prog.fail();
 					task.log(reason);
 				}
 				Self::Clean => {}
@@ -339,14 +347,14 @@ impl From<anyhow::Error> for FileOutDeleteDo {
 
 impl FileOutDeleteDo {
 	pub(crate) fn reduce(self, task: &mut Task) {
-		let TaskProg::FileDelete(prog) = &mut task.prog else { return };
+		let Some(prog) = task.prog.tally() else { return };
 		match self {
 			Self::Succ(size) => {
-				prog.success_files += 1;
-				prog.processed_bytes += size;
+				prog.succ();
+				prog.adv(size);
 			}
 			Self::Fail(reason) => {
-				prog.failed_files += 1;
+				prog.fail();
 				task.log(reason);
 			}
 		}
@@ -383,6 +391,88 @@ impl FileOutTrash {
 	}
 }
 
+// --- Restore
+#[derive(Debug)]
+pub(crate) enum FileOutRestore {
+	Succ,
+	Fail(String),
+}
+
+impl From<anyhow::Error> for FileOutRestore {
+	fn from(value: anyhow::Error) -> Self { Self::Fail(format!("{value:?}")) }
+}
+
+impl FileOutRestore {
+	pub(crate) fn reduce(self, task: &mut Task) {
+		let TaskProg::FileRestore(prog) = &mut task.prog else { return };
+		match self {
+			Self::Succ => {
+				prog.state = Some(true);
+			}
+			Self::Fail(reason) => {
+				prog.state = Some(false);
+				task.log(reason);
+			}
+		}
+	}
+}
+
+// --- Empty
+#[derive(Debug)]
+pub(crate) enum FileOutEmpty {
+	New,
+	Succ,
+	Fail(String),
+}
+
+impl From<anyhow::Error> for FileOutEmpty {
+	fn from(value: anyhow::Error) -> Self { Self::Fail(format!("{value:?}")) }
+}
+
+impl FileOutEmpty {
+	pub(crate) fn reduce(self, task: &mut Task) {
+		let TaskProg::FileEmpty(prog) = &mut task.prog else { return };
+		match self {
+			Self::New => {
+				prog.total_files += 1;
+			}
+			Self::Succ => {
+				prog.collected = Some(true);
+			}
+			Self::Fail(reason) => {
+				prog.collected = Some(false);
+				task.log(reason);
+			}
+		}
+	}
+}
+
+// --- EmptyDo
+#[derive(Debug)]
+pub(crate) enum FileOutEmptyDo {
+	Succ,
+	Fail(String),
+}
+
+impl From<anyhow::Error> for FileOutEmptyDo {
+	fn from(value: anyhow::Error) -> Self { Self::Fail(format!("{value:?}")) }
+}
+
+impl FileOutEmptyDo {
+	pub(crate) fn reduce(self, task: &mut Task) {
+		let TaskProg::FileEmpty(prog) = &mut task.prog else { return };
+		match self {
+			Self::Succ => {
+				prog.success_files += 1;
+			}
+			Self::Fail(reason) => {
+				prog.failed_files += 1;
+				task.log(reason);
+			}
+		}
+	}
+}
+
 // --- Download
 #[derive(Debug)]
 pub(crate) enum FileOutDownload {
@@ -503,6 +593,7 @@ impl FileOutUpload {
 #[derive(Debug)]
 pub(crate) enum FileOutUploadDo {
 	Adv(u64),
+	Log(String),
 	Succ,
 	Fail(String),
 }
@@ -518,6 +609,9 @@ impl FileOutUploadDo {
 			Self::Adv(size) => {
 				prog.processed_bytes += size;
 			}
+			Self::Log(line) => {
+				task.log(line);
+			}
 			Self::Succ => {
 				prog.success_files += 1;
 			}
@@ -528,3 +622,102 @@ impl FileOutUploadDo {
 		}
 	}
 }
+
+// --- Sync
+#[derive(Debug)]
+pub(crate) enum FileOutSync {
+	New(u64),
+	Plan(String),
+	Deform(String),
+	Succ,
+	Fail(String),
+	Clean(io::Result<()>),
+}
+
+impl From<anyhow::Error> for FileOutSync {
+	fn from(value: anyhow::Error) -> Self { Self::Fail(format!("{value:?}")) }
+}
+
+impl From<std::io::Error> for FileOutSync {
+	fn from(value: std::io::Error) -> Self { Self::Fail(format!("{value:?}")) }
+}
+
+impl FileOutSync {
+	pub(crate) fn reduce(self, task: &mut Task) {
+		let TaskProg::FileSync(prog) = &mut task.prog else { return };
+		match self {
+			Self::New(bytes) => {
+				prog.total_files += 1;
+				prog.total_bytes += bytes;
+			}
+			Self::Plan(line) => {
+				task.log(line);
+			}
+			Self::Deform(reason) => {
+				prog.total_files += 1;
+				prog.failed_files += 1;
+				task.log(reason);
+			}
+			Self::Succ => {
+				prog.collected = Some(true);
+			}
+			Self::Fail(reason) => {
+				prog.collected = Some(false);
+				task.log(reason);
+			}
+			Self::Clean(Ok(())) => {
+				prog.cleaned = CleanupState::Success;
+			}
+			Self::Clean(Err(reason)) => {
+				prog.cleaned = CleanupState::Failed;
+				task.log(format!("Failed cleaning up extraneous directories: {reason:?}"));
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use yazi_shared::Id;
+
+	use super::*;
+	use crate::file::{FileProgCopy, FileProgLink, FileProgSync, FileProgTrash};
+
+	fn reduce(prog: impl Into<TaskProg>) -> Task {
+		let mut task = Task::new(Id::ZERO, String::new(), prog.into());
+		FileOutCopyDo::Adv(5).reduce(&mut task);
+		FileOutCopyDo::Succ.reduce(&mut task);
+		FileOutCopyDo::Fail("copy".to_owned()).reduce(&mut task);
+		FileOutLink::Succ.reduce(&mut task);
+		FileOutLink::Clean.reduce(&mut task);
+		FileOutDeleteDo::Succ(3).reduce(&mut task);
+		FileOutDeleteDo::Fail("delete".to_owned()).reduce(&mut task);
+		task
+	}
+
+	#[test]
+	fn test_reduce() {
+		// Sync counts the transfers it's made of the same as a copy
+		let task = reduce(FileProgCopy::default());
+		let TaskProg::FileCopy(copy) = task.prog else { panic!() };
+		assert_eq!((copy.success_files, copy.failed_files, copy.processed_bytes), (3, 2, 8));
+		assert_eq!(task.logs, "copy\ndelete\n");
+
+		let task = reduce(FileProgSync::default());
+		let TaskProg::FileSync(sync) = task.prog else { panic!() };
+		assert_eq!((sync.success_files, sync.failed_files, sync.processed_bytes), (3, 2, 8));
+		assert_eq!(task.logs, "copy\ndelete\n");
+
+		// A link on its own
+		let task = reduce(FileProgLink::default());
+		assert_eq!(
+			task.prog,
+			TaskProg::FileLink(FileProgLink { state: Some(true), cleaned: CleanupState::Success })
+		);
+
+		// Not a transfer
+		let task = reduce(FileProgTrash::default());
+		assert_eq!(task.prog, TaskProg::FileTrash(FileProgTrash::default()));
+		assert!(task.logs.is_empty());
+	}
+}
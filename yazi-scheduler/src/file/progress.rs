@@ -214,6 +214,73 @@ impl Progress for FileProgTrash {
 	fn cleaned(self) -> Option<CleanupState> { Some(self.cleaned) }
 }
 
+// --- Restore
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize)]
+pub struct FileProgRestore {
+	pub state: Option<bool>,
+}
+
+impl From<FileProgRestore> for TaskSummary {
+	fn from(value: FileProgRestore) -> Self {
+		Self {
+			total:   1,
+			success: value.success() as u32,
+			failed:  value.failed() as u32,
+			percent: value.percent().map(Into::into),
+		}
+	}
+}
+
+impl Progress for FileProgRestore {
+	fn running(self) -> bool { self.state.is_none() }
+
+	fn cooked(self) -> bool { self.state == Some(true) }
+
+	fn failed(self) -> bool { self.state == Some(false) }
+}
+
+// --- Empty
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize)]
+pub struct FileProgEmpty {
+	pub total_files:   u32,
+	pub success_files: u32,
+	pub failed_files:  u32,
+	pub collected:     Option<bool>,
+}
+
+impl From<FileProgEmpty> for TaskSummary {
+	fn from(value: FileProgEmpty) -> Self {
+		Self {
+			total:   value.total_files,
+			success: value.success_files,
+			failed:  value.failed_files,
+			percent: value.percent().map(Into::into),
+		}
+	}
+}
+
+impl Progress for FileProgEmpty {
+	fn running(self) -> bool {
+		self.collected.is_none() || self.success_files + self.failed_files != self.total_files
+	}
+
+	fn cooked(self) -> bool { self.collected == Some(true) && self.success_files == self.total_files }
+
+	fn failed(self) -> bool { self.collected == Some(false) }
+
+	fn percent(self) -> Option<f32> {
+		Some(if self.success() {
+			100.0
+		} else if self.failed() {
+			0.0
+		} else if self.total_files != 0 {
+			99.99f32.min(self.success_files as f32 / self.total_files as f32 * 100.0)
+		} else {
+			99.99
+		})
+	}
+}
+
 // --- Download
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize)]
 pub struct FileProgDownload {
@@ -295,3 +362,71 @@ impl Progress for FileProgUpload {
 		Some(self.byte_percent(self.processed_bytes, self.total_bytes))
 	}
 }
+
+// --- Sync
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize)]
+pub struct FileProgSync {
+	pub total_files:     u32,
+	pub success_files:   u32,
+	pub failed_files:    u32,
+	pub total_bytes:     u64,
+	pub processed_bytes: u64,
+	pub collected:       Option<bool>,
+	pub cleaned:         CleanupState,
+}
+
+impl From<FileProgSync> for TaskSummary {
+	fn from(value: FileProgSync) -> Self {
+		Self {
+			total:   value.total_files,
+			success: value.success_files,
+			failed:  value.failed_files,
+			percent: value.percent().map(Into::into),
+		}
+	}
+}
+
+impl Progress for FileProgSync {
+	fn running(self) -> bool {
+		self.cooking_or_cleaning(
+			self.collected.is_none() || self.success_files + self.failed_files != self.total_files,
+		)
+	}
+
+	fn cooked(self) -> bool { self.collected == Some(true) && self.success_files == self.total_files }
+
+	fn failed(self) -> bool { self.cleaned.is_failed() || self.collected == Some(false) }
+
+	fn cleaned(self) -> Option<CleanupState> { Some(self.cleaned) }
+
+	fn percent(self) -> Option<f32> {
+		Some(self.byte_percent(self.processed_bytes, self.total_bytes))
+	}
+}
+
+// --- Tally
+/// Per-file counters of the tasks that transfer files one by one, sync reuses
+/// the transfers of copy, link and delete, so their results count the same.
+pub(crate) trait FileTally {
+	fn adv(&mut self, bytes: u64);
+
+	fn succ(&mut self);
+
+	fn fail(&mut self);
+}
+
+macro_rules! impl_file_tally {
+	($($type:ty),* $(,)?) => {
+		$(
+			impl FileTally for $type {
+				fn adv(&mut self, bytes: u64) { self.processed_bytes += bytes; }
+
+				fn succ(&mut self) { self.success_files += 1; }
+
+				fn fail(&mut self) { self.failed_files += 1; }
+			}
+		)*
+	};
+}
+
+impl_file_tally!(FileProgCopy, FileProgCut, FileProgDelete, FileProgSync);
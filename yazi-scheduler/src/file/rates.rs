@@ -0,0 +1,31 @@
+use std::sync::LazyLock;
+
+use yazi_config::YAZI;
+use yazi_fs::provider::Rate;
+
+use crate::{Task, TaskProg};
+
+pub static RATES: LazyLock<Rates> = LazyLock::new(|| Rates {
+	file:     Rate::new(YAZI.tasks.file_rate),
+	download: Rate::new(YAZI.tasks.download_rate),
+	upload:   Rate::new(YAZI.tasks.upload_rate),
+});
+
+pub struct Rates {
+	pub file:     Rate,
+	pub download: Rate,
+	pub upload:   Rate,
+}
+
+impl Rates {
+	/// The bandwidth limit shared by all tasks of the same kind as `task`,
+	/// along with the name of that kind.
+	pub fn of(task: &Task) -> Option<(&'static str, &'static Rate)> {
+		Some(match task.prog {
+			TaskProg::FileCopy(_) | TaskProg::FileCut(_) => ("file", &RATES.file),
+			TaskProg::FileDownload(_) => ("download", &RATES.download),
+			TaskProg::FileUpload(_) => ("upload", &RATES.upload),
+			_ => None?,
+		})
+	}
+}
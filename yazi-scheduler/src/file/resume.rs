@@ -0,0 +1,124 @@
+use std::{io, time::Duration};
+
+use yazi_shared::url::UrlBuf;
+use yazi_fs::provider::Interrupted;
+use yazi_vfs::provider;
+
+/// Times a transfer is resumed before giving up.
+const MAX_ATTEMPTS: u8 = 5;
+
+/// Progress of a transfer across attempts, so that an interrupted one can
+/// continue from where it left off without reporting the same bytes twice.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Resume {
+	pub(super) offset: u64,
+	copied:            u64,
+	reported:          u64,
+	attempts:          u8,
+	resumed:           bool,
+	/// Partial destination of transfers going through a temporary file.
+	pub(super) tmp:    Option<UrlBuf>,
+}
+
+impl Resume {
+	/// Records `n` bytes copied, returns how many of them are new.
+	pub(super) fn advance(&mut self, n: u64) -> u64 {
+		self.copied += n;
+		let new = (self.offset + self.copied).saturating_sub(self.reported);
+		self.reported += new;
+		new
+	}
+
+	/// Whether it continues an earlier attempt that was interrupted or paused.
+	#[inline]
+	pub(super) fn resumed(&self) -> bool { self.resumed }
+
+	/// Whether `e` is the transfer being stopped by pausing its task.
+	#[inline]
+	pub(super) fn suspended(e: &io::Error) -> bool {
+		e.kind() == io::ErrorKind::Interrupted && Interrupted::offset_of(e).is_some()
+	}
+
+	/// Whether the transfer can continue from where `e` interrupted it.
+	#[inline]
+	pub(super) fn resumable(&self, e: &io::Error) -> bool {
+		self.attempts < MAX_ATTEMPTS && !Self::suspended(e) && Interrupted::offset_of(e).is_some()
+	}
+
+	/// Prepares continuing from where `e` stopped it.
+	pub(super) fn suspend(&mut self, e: &io::Error) {
+		self.offset = Interrupted::offset_of(e).unwrap_or_default();
+		self.copied = 0;
+		self.resumed = true;
+	}
+
+	/// Prepares the next attempt to continue from where `e` interrupted,
+	/// returns how long to wait for the connection to come back before it,
+	/// which is a bit longer each time.
+	pub(super) fn restart(&mut self, e: &io::Error) -> Duration {
+		self.attempts += 1;
+		self.suspend(e);
+		Duration::from_secs(1 << self.attempts.min(5))
+	}
+
+	/// Continues from the destination `to` left behind by a previous run.
+	pub(super) async fn reuse(&mut self, to: &UrlBuf) {
+		self.offset = provider::partial_offset(to).await;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn interrupted(offset: u64) -> io::Error {
+		Interrupted::wrap(io::ErrorKind::ConnectionReset.into(), offset)
+	}
+
+	#[test]
+	fn test_advance() {
+		let mut resume = Resume::default();
+		assert_eq!(resume.advance(10), 10);
+		assert_eq!(resume.advance(5), 5);
+
+		// Bytes copied again after restarting from an earlier offset aren't reported
+		resume.restart(&interrupted(8));
+		assert_eq!(resume.advance(4), 0);
+		assert_eq!(resume.advance(4), 1);
+		assert_eq!(resume.advance(3), 3);
+
+		// Those before the offset are reported at once
+		let mut resume = Resume { offset: 100, ..Default::default() };
+		assert_eq!(resume.advance(1), 101);
+	}
+
+	#[test]
+	fn test_restart() {
+		let mut resume = Resume::default();
+		let e = interrupted(1);
+		assert!(!resume.resumable(&io::Error::other("other")));
+
+		let mut delays = vec![];
+		while resume.resumable(&e) {
+			delays.push(resume.restart(&e).as_secs());
+		}
+		assert_eq!(delays, [2, 4, 8, 16, 32]);
+		assert!(resume.resumed());
+	}
+
+	#[test]
+	fn test_suspend() {
+		let mut resume = Resume::default();
+		let e = Interrupted::wrap(io::ErrorKind::Interrupted.into(), 8);
+		assert!(Resume::suspended(&e));
+		assert!(!resume.resumable(&e));
+
+		// Pausing isn't an attempt, however many times it's done
+		for _ in 0..10 {
+			resume.suspend(&e);
+		}
+		assert!(resume.resumed());
+		assert!(resume.resumable(&interrupted(8)));
+		assert_eq!(resume.advance(4), 12);
+	}
+}
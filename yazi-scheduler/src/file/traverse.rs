@@ -37,10 +37,12 @@ impl Traverse for FileInCopy {
 			id: self.id,
 			from,
 			to: to.unwrap(),
-			force: self.force,
+			resolver: self.resolver.clone(),
 			cha: Some(cha),
 			follow: self.follow,
+			verify: self.verify,
 			retry: self.retry,
+			resume: Default::default(),
 		}
 	}
 
@@ -59,10 +61,12 @@ impl Traverse for FileInCut {
 			id: self.id,
 			from,
 			to: to.unwrap(),
-			force: self.force,
+			resolver: self.resolver.clone(),
 			cha: Some(cha),
 			follow: self.follow,
+			verify: self.verify,
 			retry: self.retry,
+			resume: Default::default(),
 			drop: self.drop.clone(),
 		}
 	}
@@ -113,7 +117,13 @@ impl Traverse for FileInDownload {
 	fn from(&self) -> Url<'_> { self.target.as_url() }
 
 	fn spawn(&self, from: UrlBuf, _to: Option<UrlBuf>, cha: Cha) -> Self {
-		Self { id: self.id, target: from, cha: Some(cha), retry: self.retry }
+		Self {
+			id:     self.id,
+			target: from,
+			cha:    Some(cha),
+			retry:  self.retry,
+			resume: Default::default(),
+		}
 	}
 
 	fn to(&self) -> Option<Url<'_>> { None }
@@ -137,7 +147,14 @@ impl Traverse for FileInUpload {
 	}
 
 	fn spawn(&self, from: UrlBuf, _to: Option<UrlBuf>, cha: Cha) -> Self {
-		Self { id: self.id, cha: Some(cha), cache: from.cache(), target: from }
+		Self {
+			id:     self.id,
+			cha:    Some(cha),
+			cache:  from.cache(),
+			target: from,
+			retry:  self.retry,
+			resume: Default::default(),
+		}
 	}
 
 	fn to(&self) -> Option<Url<'_>> { None }
@@ -4,13 +4,15 @@ use parking_lot::Mutex;
 use tokio::sync::mpsc;
 use yazi_dds::Pump;
 use yazi_fs::ok_or_not_found;
+use yazi_shared::Id;
 use yazi_vfs::provider;
 
-use crate::{Ongoing, TaskOp, TaskOps, TasksProxy, file::{FileOutCopy, FileOutCut, FileOutDelete, FileOutDownload, FileOutHardlink, FileOutLink, FileOutTrash, FileOutUpload}, hook::{HookIn, HookInDelete, HookInDownload, HookInOutCopy, HookInOutCut, HookInOutHardlink, HookInOutLink, HookInPreload, HookInTrash, HookInUpload}, preload::{Preload, PreloadOut}};
+use crate::{Ongoing, TaskOp, TaskOps, TasksProxy, file::{FileOutCopy, FileOutCut, FileOutDelete, FileOutDownload, FileOutHardlink, FileOutLink, FileOutSync, FileOutTrash, FileOutUpload}, hook::{HookIn, HookInDelete, HookInDownload, HookInOutCopy, HookInOutCut, HookInOutHardlink, HookInOutLink, HookInOutSync, HookInPreload, HookInTrash, HookInUpload}, journal::{Journal, Record}, preload::{Preload, PreloadOut}};
 
 pub(crate) struct Hook {
 	ops:     TaskOps,
 	ongoing: Arc<Mutex<Ongoing>>,
+	journal: Arc<Journal>,
 	preload: Arc<Preload>,
 	tx:      async_priority_channel::Sender<HookIn, u8>,
 }
@@ -19,10 +21,17 @@ impl Hook {
 	pub(crate) fn new(
 		ops: &mpsc::UnboundedSender<TaskOp>,
 		ongoing: &Arc<Mutex<Ongoing>>,
+		journal: &Arc<Journal>,
 		preload: &Arc<Preload>,
 		tx: async_priority_channel::Sender<HookIn, u8>,
 	) -> Self {
-		Self { ops: ops.into(), ongoing: ongoing.clone(), preload: preload.clone(), tx }
+		Self {
+			ops: ops.into(),
+			ongoing: ongoing.clone(),
+			journal: journal.clone(),
+			preload: preload.clone(),
+			tx,
+		}
 	}
 
 	// --- File
@@ -33,6 +42,11 @@ impl Hook {
 
 		let result = ok_or_not_found(provider::remove_dir_clean(&task.from).await);
 		TasksProxy::update_succeed(task.id, [&task.to, &task.from], true);
+		self.record(task.id, Record::Cut {
+			from:   task.from.clone(),
+			to:     task.to.clone(),
+			merged: task.merged,
+		});
 		Pump::push_move(task.from, task.to);
 
 		self.ops.out(task.id, FileOutCut::Clean(result));
@@ -41,6 +55,11 @@ impl Hook {
 	pub(crate) async fn copy(&self, task: HookInOutCopy) {
 		if self.ongoing.lock().intact(task.id) {
 			TasksProxy::update_succeed(task.id, [&task.to], true);
+			self.record(task.id, Record::Copy {
+				from:   task.from.clone(),
+				to:     task.to.clone(),
+				merged: task.merged,
+			});
 			Pump::push_duplicate(task.from, task.to);
 		}
 
@@ -63,6 +82,7 @@ impl Hook {
 		let intact = self.ongoing.lock().intact(task.id);
 		if intact {
 			TasksProxy::update_succeed(task.id, [&task.target], false);
+			self.record(task.id, Record::Trash { target: task.target.clone() });
 			Pump::push_trash(task.target);
 		}
 		self.ops.out(task.id, FileOutTrash::Clean);
@@ -71,6 +91,7 @@ impl Hook {
 	pub(crate) async fn link(&self, task: HookInOutLink) {
 		if self.ongoing.lock().intact(task.id) {
 			TasksProxy::update_succeed(task.id, [&task.to], true);
+			self.record(task.id, Record::Link { from: task.from, to: task.to });
 		}
 
 		self.ops.out(task.id, FileOutLink::Clean);
@@ -79,11 +100,28 @@ impl Hook {
 	pub(crate) async fn hardlink(&self, task: HookInOutHardlink) {
 		if self.ongoing.lock().intact(task.id) {
 			TasksProxy::update_succeed(task.id, [&task.to], true);
+			self.record(task.id, Record::Hardlink { from: task.from, to: task.to });
 		}
 
 		self.ops.out(task.id, FileOutHardlink::Clean);
 	}
 
+	pub(crate) async fn sync(&self, task: HookInOutSync) {
+		if !self.ongoing.lock().intact(task.id) {
+			return self.ops.out(task.id, FileOutSync::Clean(Ok(())));
+		}
+
+		let mut result = Ok(());
+		for dir in &task.extraneous {
+			if let Err(e) = ok_or_not_found(provider::remove_dir_all(dir).await) {
+				result = result.and(Err(e));
+			}
+		}
+
+		TasksProxy::update_succeed(task.id, [&task.to], true);
+		self.ops.out(task.id, FileOutSync::Clean(result));
+	}
+
 	pub(crate) async fn download(&self, task: HookInDownload) {
 		let intact = self.ongoing.lock().intact(task.id);
 		if intact {
@@ -96,7 +134,8 @@ impl Hook {
 	pub(crate) async fn upload(&self, task: HookInUpload) {
 		let intact = self.ongoing.lock().intact(task.id);
 		if intact {
-			TasksProxy::update_succeed(task.id, [task.target], false);
+			TasksProxy::update_succeed(task.id, [&task.target], false);
+			self.record(task.id, Record::Upload { target: task.target });
 		}
 		self.ops.out(task.id, FileOutUpload::Clean);
 	}
@@ -116,4 +155,10 @@ impl Hook {
 	pub(crate) fn submit(&self, r#in: impl Into<HookIn>, priority: u8) {
 		_ = self.tx.try_send(r#in.into(), priority);
 	}
+
+	fn record(&self, id: Id, record: Record) {
+		if let Some(batch) = self.ongoing.lock().get_mut(id).map(|t| t.batch) {
+			self.journal.record(batch, record);
+		}
+	}
 }
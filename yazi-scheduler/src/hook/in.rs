@@ -12,6 +12,7 @@ pub(crate) enum HookIn {
 	Trash(HookInTrash),
 	Link(HookInOutLink),
 	Hardlink(HookInOutHardlink),
+	Sync(HookInOutSync),
 	Download(HookInDownload),
 	Upload(HookInUpload),
 	Preload(HookInPreload),
@@ -24,6 +25,7 @@ impl_from_in!(
 	Trash(HookInTrash),
 	Link(HookInOutLink),
 	Hardlink(HookInOutHardlink),
+	Sync(HookInOutSync),
 	Download(HookInDownload),
 	Upload(HookInUpload),
 	Preload(HookInPreload),
@@ -40,6 +42,7 @@ impl TaskIn for HookIn {
 			Self::Trash(r#in) => r#in.id(),
 			Self::Link(r#in) => r#in.id(),
 			Self::Hardlink(r#in) => r#in.id(),
+			Self::Sync(r#in) => r#in.id(),
 			Self::Download(r#in) => r#in.id(),
 			Self::Upload(r#in) => r#in.id(),
 			Self::Preload(r#in) => r#in.id(),
@@ -54,6 +57,7 @@ impl TaskIn for HookIn {
 			Self::Trash(r#in) => r#in.id = id,
 			Self::Link(r#in) => r#in.id = id,
 			Self::Hardlink(r#in) => r#in.id = id,
+			Self::Sync(r#in) => r#in.id = id,
 			Self::Download(r#in) => r#in.id = id,
 			Self::Upload(r#in) => r#in.id = id,
 			Self::Preload(r#in) => r#in.id = id,
@@ -69,6 +73,7 @@ impl TaskIn for HookIn {
 			Self::Trash(r#in) => r#in.title(),
 			Self::Link(r#in) => r#in.title(),
 			Self::Hardlink(r#in) => r#in.title(),
+			Self::Sync(r#in) => r#in.title(),
 			Self::Download(r#in) => r#in.title(),
 			Self::Upload(r#in) => r#in.title(),
 			Self::Preload(r#in) => r#in.title(),
@@ -79,9 +84,11 @@ impl TaskIn for HookIn {
 // --- Copy
 #[derive(Debug)]
 pub(crate) struct HookInOutCopy {
-	pub(crate) id:   Id,
-	pub(crate) from: UrlBuf,
-	pub(crate) to:   UrlBuf,
+	pub(crate) id:     Id,
+	pub(crate) from:   UrlBuf,
+	pub(crate) to:     UrlBuf,
+	/// Whether the destination existed before, and is merged into.
+	pub(crate) merged: bool,
 }
 
 impl TaskIn for HookInOutCopy {
@@ -100,15 +107,18 @@ impl TaskIn for HookInOutCopy {
 }
 
 impl HookInOutCopy {
-	pub(crate) fn new<U>(from: U, to: U) -> Self
+	pub(crate) fn new<U>(from: U, to: U, merged: bool) -> Self
 	where
 		U: Into<UrlBuf>,
 	{
-		Self { id: Id::ZERO, from: from.into(), to: to.into() }
+		Self { id: Id::ZERO, from: from.into(), to: to.into(), merged }
 	}
 
 	pub(crate) fn reduce(self, task: &mut Task) {
 		if let TaskProg::FileCopy(_) = &task.prog {
+			if let Some(pending) = &mut task.pending {
+				pending.settle(&self.to);
+			}
 			task.with_hook(self);
 		}
 	}
@@ -117,9 +127,10 @@ impl HookInOutCopy {
 // --- Cut
 #[derive(Debug)]
 pub(crate) struct HookInOutCut {
-	pub(crate) id:   Id,
-	pub(crate) from: UrlBuf,
-	pub(crate) to:   UrlBuf,
+	pub(crate) id:     Id,
+	pub(crate) from:   UrlBuf,
+	pub(crate) to:     UrlBuf,
+	pub(crate) merged: bool,
 }
 
 impl TaskIn for HookInOutCut {
@@ -138,15 +149,18 @@ impl TaskIn for HookInOutCut {
 }
 
 impl HookInOutCut {
-	pub(crate) fn new<U>(from: U, to: U) -> Self
+	pub(crate) fn new<U>(from: U, to: U, merged: bool) -> Self
 	where
 		U: Into<UrlBuf>,
 	{
-		Self { id: Id::ZERO, from: from.into(), to: to.into() }
+		Self { id: Id::ZERO, from: from.into(), to: to.into(), merged }
 	}
 
 	pub(crate) fn reduce(self, task: &mut Task) {
 		if let TaskProg::FileCut(_) = &task.prog {
+			if let Some(pending) = &mut task.pending {
+				pending.settle(&self.to);
+			}
 			task.with_hook(self);
 		}
 	}
@@ -214,7 +228,6 @@ impl HookInTrash {
 #[derive(Debug)]
 pub(crate) struct HookInOutLink {
 	pub(crate) id:   Id,
-	#[allow(dead_code)]
 	pub(crate) from: UrlBuf,
 	pub(crate) to:   UrlBuf,
 }
@@ -253,7 +266,6 @@ impl HookInOutLink {
 #[derive(Debug)]
 pub(crate) struct HookInOutHardlink {
 	pub(crate) id:   Id,
-	#[allow(dead_code)]
 	pub(crate) from: UrlBuf,
 	pub(crate) to:   UrlBuf,
 }
@@ -288,6 +300,40 @@ impl HookInOutHardlink {
 	}
 }
 
+// --- Sync
+#[derive(Debug)]
+pub(crate) struct HookInOutSync {
+	pub(crate) id:         Id,
+	pub(crate) to:         UrlBuf,
+	/// Directories in the destination that aren't in the source.
+	pub(crate) extraneous: Vec<UrlBuf>,
+}
+
+impl TaskIn for HookInOutSync {
+	type Prog = ();
+
+	fn id(&self) -> Id { self.id }
+
+	fn set_id(&mut self, id: Id) -> &mut Self {
+		self.id = id;
+		self
+	}
+
+	fn title(&self) -> Cow<'_, str> { format!("Hook: sync to {}", self.to.display()).into() }
+}
+
+impl HookInOutSync {
+	pub(crate) fn new(to: impl Into<UrlBuf>, extraneous: Vec<UrlBuf>) -> Self {
+		Self { id: Id::ZERO, to: to.into(), extraneous }
+	}
+
+	pub(crate) fn reduce(self, task: &mut Task) {
+		if let TaskProg::FileSync(_) = &task.prog {
+			task.with_hook(self);
+		}
+	}
+}
+
 // --- Download
 #[derive(Debug)]
 pub(crate) struct HookInDownload {
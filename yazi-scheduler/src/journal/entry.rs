@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+use yazi_shared::Id;
+
+use super::Record;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Entry {
+	#[serde(skip)]
+	pub(super) batch: Id,
+	pub time:         u64,
+	pub records:      Vec<Record>,
+}
+
+impl Entry {
+	pub fn title(&self) -> String {
+		match self.records.as_slice() {
+			[] => String::new(),
+			[record] => record.title(),
+			[first, rest @ ..] if rest.iter().all(|r| r.verb() == first.verb()) => {
+				format!("{} {} items", first.verb(), self.records.len())
+			}
+			records => format!("{} operations", records.len()),
+		}
+	}
+}
+
+// --- Snap
+#[derive(Debug, Serialize)]
+pub struct EntrySnap {
+	pub title:  String,
+	pub time:   u64,
+	pub undone: bool,
+}
+
+impl EntrySnap {
+	pub(super) fn new(entry: &Entry, undone: bool) -> Self {
+		Self { title: entry.title(), time: entry.time, undone }
+	}
+}
@@ -0,0 +1,248 @@
+use std::{cell::Cell, path::PathBuf, sync::Arc, time::{SystemTime, UNIX_EPOCH}};
+
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use tracing::error;
+use yazi_fs::Xdg;
+use yazi_shared::{Id, Ids};
+
+use super::{Entry, EntrySnap, Record};
+
+const MAX_ENTRIES: usize = 100;
+
+static IDS: Ids = Ids::new();
+
+thread_local! {
+	static BATCH: Cell<Id> = const { Cell::new(Id::ZERO) };
+}
+
+pub struct Journal {
+	path:   PathBuf,
+	stacks: Mutex<Stacks>,
+	saved:  Arc<Mutex<u64>>,
+}
+
+#[derive(Default, Deserialize, Serialize)]
+struct Stacks {
+	done:    Vec<Entry>,
+	undone:  Vec<Entry>,
+	#[serde(skip)]
+	version: u64,
+}
+
+impl Journal {
+	pub(crate) fn new() -> Self { Self::at(Xdg::state_dir().join("journal.json")) }
+
+	fn at(path: PathBuf) -> Self {
+		let stacks =
+			std::fs::read(&path).ok().and_then(|b| serde_json::from_slice(&b).ok()).unwrap_or_default();
+
+		Self { path, stacks: Mutex::new(stacks), saved: Default::default() }
+	}
+
+	/// Records the file tasks added by `f` on the current thread as a single
+	/// journal entry, tasks added anywhere else aren't recorded.
+	pub fn scope<R>(&self, f: impl FnOnce() -> R) -> R {
+		let prev = BATCH.replace(IDS.next());
+		let result = f();
+		BATCH.set(prev);
+		result
+	}
+
+	/// The batch of the innermost scope on the current thread, if any.
+	#[inline]
+	pub(crate) fn batch(&self) -> Id { BATCH.get() }
+
+	/// Records operations done outside of the scheduler, e.g. renaming,
+	/// as a new journal entry.
+	pub fn push(&self, records: Vec<Record>) {
+		if records.is_empty() {
+			return;
+		}
+
+		let mut stacks = self.stacks.lock();
+		stacks.done.push(Entry { batch: IDS.next(), time: Self::now(), records });
+		self.commit(&mut stacks);
+	}
+
+	pub(crate) fn record(&self, batch: Id, record: Record) {
+		if batch == Id::ZERO {
+			return;
+		}
+
+		let mut stacks = self.stacks.lock();
+		if let Some(entry) = stacks.done.iter_mut().rev().find(|e| e.batch == batch) {
+			entry.records.push(record);
+		} else {
+			stacks.done.push(Entry { batch, time: Self::now(), records: vec![record] });
+		}
+		self.commit(&mut stacks);
+	}
+
+	/// Takes the last entry to undo, only its reversible records are kept
+	/// for redoing, since the others are left as they are.
+	pub(crate) fn undo(&self) -> Option<Entry> {
+		let mut stacks = self.stacks.lock();
+		let entry = stacks.done.pop()?;
+
+		let records: Vec<_> = entry.records.iter().filter(|r| r.reversible()).cloned().collect();
+		if !records.is_empty() {
+			stacks.undone.push(Entry { records, ..entry.clone() });
+		}
+		self.save(&mut stacks);
+		Some(entry)
+	}
+
+	pub(crate) fn redo(&self) -> Option<Entry> {
+		let mut stacks = self.stacks.lock();
+		let entry = stacks.undone.pop()?;
+
+		stacks.done.push(entry.clone());
+		self.save(&mut stacks);
+		Some(entry)
+	}
+
+	pub fn snaps(&self) -> Vec<EntrySnap> {
+		let stacks = self.stacks.lock();
+		stacks
+			.undone
+			.iter()
+			.map(|e| EntrySnap::new(e, true))
+			.chain(stacks.done.iter().rev().map(|e| EntrySnap::new(e, false)))
+			.collect()
+	}
+
+	fn commit(&self, stacks: &mut Stacks) {
+		stacks.undone.clear();
+		if let Some(n) = stacks.done.len().checked_sub(MAX_ENTRIES) {
+			stacks.done.drain(..n);
+		}
+		self.save(stacks);
+	}
+
+	fn save(&self, stacks: &mut Stacks) {
+		let json = match serde_json::to_vec(stacks) {
+			Ok(json) => json,
+			Err(e) => return error!("Failed to serialize the journal: {e:?}"),
+		};
+
+		stacks.version += 1;
+		let version = stacks.version;
+
+		// Writes are serialized and stale snapshots are dropped, so that
+		// the file always ends up with the latest state.
+		let (path, saved) = (self.path.clone(), self.saved.clone());
+		tokio::task::spawn_blocking(move || {
+			let mut saved = saved.lock();
+			if *saved >= version {
+				return;
+			}
+
+			*saved = version;
+			let result = path.parent().map_or(Ok(()), std::fs::create_dir_all);
+			if let Err(e) = result.and_then(|_| std::fs::write(&path, json)) {
+				error!("Failed to save the journal: {e:?}");
+			}
+		});
+	}
+
+	#[inline]
+	fn now() -> u64 {
+		SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or_default()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::time::Duration;
+
+	use super::*;
+
+	fn rename(from: &str, to: &str) -> Record {
+		Record::Rename { from: from.parse().unwrap(), to: to.parse().unwrap() }
+	}
+
+	fn titles(journal: &Journal) -> Vec<(String, bool)> {
+		journal.snaps().into_iter().map(|s| (s.title, s.undone)).collect()
+	}
+
+	#[tokio::test]
+	async fn test_scope() {
+		yazi_shared::init_tests();
+		let tmp = tempfile::tempdir().unwrap();
+		let journal = Journal::at(tmp.path().join("journal.json"));
+
+		let (outer, inner, restored) = journal.scope(|| {
+			let outer = journal.batch();
+			(outer, journal.scope(|| journal.batch()), journal.batch())
+		});
+		assert_ne!(outer, Id::ZERO);
+		assert_ne!(inner, outer);
+		assert_eq!(restored, outer);
+		assert_eq!(journal.batch(), Id::ZERO);
+
+		// Tasks added on other threads aren't part of the scope
+		journal.scope(|| {
+			let batch = std::thread::scope(|s| s.spawn(|| journal.batch()).join().unwrap());
+			assert_eq!(batch, Id::ZERO);
+		});
+
+		// Pushing doesn't leave a batch behind for tasks added later
+		journal.record(Id::ZERO, rename("/a", "/b"));
+		journal.push(vec![rename("/a", "/b")]);
+		assert_eq!(journal.batch(), Id::ZERO);
+
+		journal.record(outer, rename("/c", "/d"));
+		journal.record(outer, rename("/e", "/f"));
+		assert_eq!(titles(&journal), [
+			("Rename 2 items".to_owned(), false),
+			("Rename /a to /b".to_owned(), false)
+		]);
+	}
+
+	#[tokio::test]
+	async fn test_undo_redo() {
+		yazi_shared::init_tests();
+		let tmp = tempfile::tempdir().unwrap();
+		let path = tmp.path().join("journal.json");
+		let journal = Journal::at(path.clone());
+
+		journal.push(vec![rename("/a", "/b")]);
+		journal.push(vec![rename("/c", "/d")]);
+		assert_eq!(journal.undo().unwrap().title(), "Rename /c to /d");
+		assert_eq!(titles(&journal), [
+			("Rename /c to /d".to_owned(), true),
+			("Rename /a to /b".to_owned(), false)
+		]);
+
+		assert_eq!(journal.redo().unwrap().title(), "Rename /c to /d");
+		assert!(journal.redo().is_none());
+
+		// Records that can't be undone aren't redone either
+		journal.push(vec![rename("/h", "/i"), Record::Upload { target: "/j".parse().unwrap() }]);
+		journal.push(vec![Record::Upload { target: "/k".parse().unwrap() }]);
+		assert_eq!(journal.undo().unwrap().title(), "Upload /k");
+		assert_eq!(journal.undo().unwrap().records.len(), 2);
+		assert_eq!(journal.redo().unwrap().title(), "Rename /h to /i");
+		assert!(journal.redo().is_none());
+
+		// New operations discard what's been undone
+		journal.undo();
+		journal.push(vec![rename("/e", "/f")]);
+		assert!(journal.redo().is_none());
+
+		for i in 0..MAX_ENTRIES {
+			journal.push(vec![rename(&format!("/{i}"), "/g")]);
+		}
+		assert_eq!(journal.snaps().len(), MAX_ENTRIES);
+
+		// The last state is saved in the background
+		for _ in 0..100 {
+			if Journal::at(path.clone()).snaps().len() == MAX_ENTRIES {
+				return;
+			}
+			tokio::time::sleep(Duration::from_millis(10)).await;
+		}
+		panic!("journal not saved");
+	}
+}
@@ -0,0 +1,83 @@
+use serde::{Deserialize, Serialize};
+use yazi_shared::url::{UrlBuf, UrlLike};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "kebab-case")]
+pub enum Record {
+	Copy {
+		from:   UrlBuf,
+		to:     UrlBuf,
+		/// Whether it was merged into an existing destination, whose original
+		/// content would be lost by undoing it.
+		#[serde(default)]
+		merged: bool,
+	},
+	Cut {
+		from:   UrlBuf,
+		to:     UrlBuf,
+		#[serde(default)]
+		merged: bool,
+	},
+	Rename { from: UrlBuf, to: UrlBuf },
+	Link { from: UrlBuf, to: UrlBuf },
+	Hardlink { from: UrlBuf, to: UrlBuf },
+	Trash { target: UrlBuf },
+	Upload { target: UrlBuf },
+}
+
+impl Record {
+	pub fn verb(&self) -> &'static str {
+		match self {
+			Self::Copy { .. } => "Copy",
+			Self::Cut { .. } => "Move",
+			Self::Rename { .. } => "Rename",
+			Self::Link { .. } => "Link",
+			Self::Hardlink { .. } => "Hardlink",
+			Self::Trash { .. } => "Trash",
+			Self::Upload { .. } => "Upload",
+		}
+	}
+
+	/// Whether it can be undone without losing any data that existed before.
+	pub fn reversible(&self) -> bool {
+		match self {
+			Self::Copy { merged, .. } | Self::Cut { merged, .. } => !merged,
+			Self::Rename { .. } | Self::Link { .. } | Self::Hardlink { .. } | Self::Trash { .. } => true,
+			Self::Upload { .. } => false,
+		}
+	}
+
+	pub fn title(&self) -> String {
+		match self {
+			Self::Copy { from, to, .. }
+			| Self::Cut { from, to, .. }
+			| Self::Rename { from, to }
+			| Self::Link { from, to }
+			| Self::Hardlink { from, to } => {
+				format!("{} {} to {}", self.verb(), from.display(), to.display())
+			}
+			Self::Trash { target } | Self::Upload { target } => {
+				format!("{} {}", self.verb(), target.display())
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_reversible() {
+		yazi_shared::init_tests();
+
+		let copy: Record = serde_json::from_str(r#"{"type":"copy","from":"/a","to":"/b"}"#).unwrap();
+		assert!(copy.reversible());
+
+		let copy: Record =
+			serde_json::from_str(r#"{"type":"copy","from":"/a","to":"/b","merged":true}"#).unwrap();
+		assert!(!copy.reversible());
+
+		assert!(!Record::Upload { target: "/a".parse().unwrap() }.reversible());
+	}
+}
@@ -0,0 +1,56 @@
+use std::io;
+
+use anyhow::{Result, bail};
+use yazi_fs::{File, FilesOp, provider::local::Trash};
+use yazi_shared::url::{UrlBuf, UrlLike};
+use yazi_vfs::{VfsFile, maybe_exists, provider};
+
+use crate::NotifyProxy;
+
+pub(crate) struct Replay;
+
+impl Replay {
+	pub(crate) async fn rename(title: &'static str, pairs: Vec<(UrlBuf, UrlBuf)>) {
+		for (from, to) in pairs {
+			if let Err(e) = Self::rename_do(&from, &to).await {
+				NotifyProxy::push_error(
+					title,
+					format!("Cannot rename {} to {}: {e}", from.display(), to.display()),
+				);
+			}
+		}
+	}
+
+	pub(crate) async fn restore(title: &'static str, targets: Vec<UrlBuf>) {
+		for target in targets {
+			let result = match target.as_local() {
+				Some(path) => Trash::restore(path).await,
+				None => Err(io::Error::new(io::ErrorKind::Unsupported, "Not a local file")),
+			};
+
+			if let Err(e) = result {
+				NotifyProxy::push_error(
+					title,
+					format!("Cannot restore {} from trash: {e}", target.display()),
+				);
+			}
+		}
+	}
+
+	async fn rename_do(from: &UrlBuf, to: &UrlBuf) -> Result<()> {
+		let (Some((from_p, from_n)), Some((to_p, to_n))) = (from.pair(), to.pair()) else {
+			bail!("Invalid file URL");
+		};
+
+		if maybe_exists(to).await && !provider::must_identical(from, to).await {
+			bail!("Destination already exists");
+		}
+
+		provider::rename(from, to).await?;
+		let file = File::new(to).await?;
+
+		FilesOp::Deleting(from_p.into(), [from_n.into()].into()).emit();
+		FilesOp::Upserting(to_p.into(), [(to_n.into(), file)].into()).emit();
+		Ok(())
+	}
+}
@@ -57,8 +57,11 @@ macro_rules! dispatch_progress {
 			$crate::TaskProg::FileHardlink(p) => $crate::Progress::$method(p),
 			$crate::TaskProg::FileDelete(p) => $crate::Progress::$method(p),
 			$crate::TaskProg::FileTrash(p) => $crate::Progress::$method(p),
+			$crate::TaskProg::FileRestore(p) => $crate::Progress::$method(p),
+			$crate::TaskProg::FileEmpty(p) => $crate::Progress::$method(p),
 			$crate::TaskProg::FileDownload(p) => $crate::Progress::$method(p),
 			$crate::TaskProg::FileUpload(p) => $crate::Progress::$method(p),
+			$crate::TaskProg::FileSync(p) => $crate::Progress::$method(p),
 			// Plugin
 			$crate::TaskProg::PluginEntry(p) => $crate::Progress::$method(p),
 			// Prework
@@ -3,7 +3,7 @@ use yazi_config::YAZI;
 use yazi_shared::{CompletionToken, Id, Ids};
 
 use super::Task;
-use crate::{TaskIn, TaskProg, hook::HookIn};
+use crate::{Parked, TaskIn, TaskProg, hook::HookIn};
 
 #[derive(Default)]
 pub struct Ongoing {
@@ -30,6 +30,7 @@ impl Ongoing {
 			Entry::Occupied(mut oe) => {
 				let task = oe.get_mut();
 				task.done.complete(false);
+				task.parked = None;
 
 				if let Some(hook) = task.hook.take() {
 					return Some(hook);
@@ -42,6 +43,20 @@ impl Ongoing {
 		None
 	}
 
+	/// Holds `in` back if its task is paused, otherwise gives it back.
+	pub(super) fn park<T>(&mut self, id: Id, r#in: T, priority: u8) -> Option<T>
+	where
+		Parked: From<(T, u8)>,
+	{
+		match self.inner.get_mut(&id).and_then(|t| t.parked.as_mut()) {
+			Some(parked) => {
+				parked.push((r#in, priority).into());
+				None
+			}
+			None => Some(r#in),
+		}
+	}
+
 	pub(super) fn fulfill(&mut self, id: Id) -> Option<Task> {
 		let task = self.inner.remove(&id)?;
 		task.done.complete(true);
@@ -85,3 +100,33 @@ impl Ongoing {
 	#[inline]
 	pub fn is_empty(&self) -> bool { self.len() == 0 }
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{LOW, file::{FileIn, FileInDelete}};
+
+	fn delete() -> FileInDelete {
+		FileInDelete { id: Id::ZERO, target: "/a".parse().unwrap(), cha: None }
+	}
+
+	#[test]
+	fn test_park() {
+		yazi_shared::init_tests();
+		let mut ongoing = Ongoing::default();
+
+		let mut r#in = delete();
+		let id = ongoing.add(&mut r#in).id;
+		assert!(ongoing.park(id, FileIn::from(r#in), LOW).is_some());
+
+		// Held back while paused, including the work stopped halfway
+		ongoing.get_mut(id).unwrap().parked = Some(vec![]);
+		assert!(ongoing.park(id, FileIn::from(delete()), LOW).is_none());
+		assert!(ongoing.park(id, FileIn::from(delete()), LOW).is_none());
+		assert_eq!(ongoing.get_mut(id).unwrap().parked.as_ref().map(Vec::len), Some(2));
+
+		// and dropped once canceled
+		ongoing.cancel(id);
+		assert!(!ongoing.exists(id));
+	}
+}
@@ -1,4 +1,4 @@
-use crate::{Task, fetch::FetchOutFetch, file::{FileOutCopy, FileOutCopyDo, FileOutCut, FileOutCutDo, FileOutDelete, FileOutDeleteDo, FileOutDownload, FileOutDownloadDo, FileOutHardlink, FileOutHardlinkDo, FileOutLink, FileOutTrash, FileOutUpload, FileOutUploadDo}, hook::{HookInOutCopy, HookInOutCut, HookInOutHardlink, HookInOutLink}, impl_from_out, plugin::PluginOutEntry, preload::PreloadOut, process::{ProcessOutBg, ProcessOutBlock, ProcessOutOrphan}, size::SizeOut};
+use crate::{Task, fetch::FetchOutFetch, file::{FileOutCopy, FileOutCopyDo, FileOutCut, FileOutCutDo, FileOutDelete, FileOutDeleteDo, FileOutDownload, FileOutDownloadDo, FileOutEmpty, FileOutEmptyDo, FileOutHardlink, FileOutHardlinkDo, FileOutLink, FileOutRestore, FileOutSync, FileOutTrash, FileOutUpload, FileOutUploadDo}, hook::{HookInOutCopy, HookInOutCut, HookInOutHardlink, HookInOutLink, HookInOutSync}, impl_from_out, plugin::PluginOutEntry, preload::PreloadOut, process::{ProcessOutBg, ProcessOutBlock, ProcessOutOrphan}, size::SizeOut};
 
 #[derive(Debug)]
 pub(super) enum TaskOut {
@@ -13,10 +13,14 @@ pub(super) enum TaskOut {
 	FileDelete(FileOutDelete),
 	FileDeleteDo(FileOutDeleteDo),
 	FileTrash(FileOutTrash),
+	FileRestore(FileOutRestore),
+	FileEmpty(FileOutEmpty),
+	FileEmptyDo(FileOutEmptyDo),
 	FileDownload(FileOutDownload),
 	FileDownloadDo(FileOutDownloadDo),
 	FileUpload(FileOutUpload),
 	FileUploadDo(FileOutUploadDo),
+	FileSync(FileOutSync),
 	// Plugin
 	PluginEntry(PluginOutEntry),
 	// Fetch
@@ -34,11 +38,12 @@ pub(super) enum TaskOut {
 	HookCut(HookInOutCut),
 	HookLink(HookInOutLink),
 	HookHardlink(HookInOutHardlink),
+	HookSync(HookInOutSync),
 }
 
 impl_from_out! {
 	// File
-	FileCopy(FileOutCopy), FileCopyDo(FileOutCopyDo), FileCut(FileOutCut), FileCutDo(FileOutCutDo), FileLink(FileOutLink), FileHardlink(FileOutHardlink), FileHardlinkDo(FileOutHardlinkDo), FileDelete(FileOutDelete), FileDeleteDo(FileOutDeleteDo), FileTrash(FileOutTrash), FileDownload(FileOutDownload), FileDownloadDo(FileOutDownloadDo), FileUpload(FileOutUpload), FileUploadDo(FileOutUploadDo),
+	FileCopy(FileOutCopy), FileCopyDo(FileOutCopyDo), FileCut(FileOutCut), FileCutDo(FileOutCutDo), FileLink(FileOutLink), FileHardlink(FileOutHardlink), FileHardlinkDo(FileOutHardlinkDo), FileDelete(FileOutDelete), FileDeleteDo(FileOutDeleteDo), FileTrash(FileOutTrash), FileRestore(FileOutRestore), FileEmpty(FileOutEmpty), FileEmptyDo(FileOutEmptyDo), FileDownload(FileOutDownload), FileDownloadDo(FileOutDownloadDo), FileUpload(FileOutUpload), FileUploadDo(FileOutUploadDo), FileSync(FileOutSync),
 	// Plugin
 	PluginEntry(PluginOutEntry),
 	// Fetch
@@ -50,7 +55,7 @@ impl_from_out! {
 	// Process
 	ProcessBlock(ProcessOutBlock), ProcessOrphan(ProcessOutOrphan), ProcessBg(ProcessOutBg),
 	// Hook
-	HookCopy(HookInOutCopy), HookCut(HookInOutCut), HookLink(HookInOutLink), HookHardlink(HookInOutHardlink),
+	HookCopy(HookInOutCopy), HookCut(HookInOutCut), HookLink(HookInOutLink), HookHardlink(HookInOutHardlink), HookSync(HookInOutSync),
 }
 
 impl TaskOut {
@@ -67,10 +72,14 @@ impl TaskOut {
 			Self::FileDelete(out) => out.reduce(task),
 			Self::FileDeleteDo(out) => out.reduce(task),
 			Self::FileTrash(out) => out.reduce(task),
+			Self::FileRestore(out) => out.reduce(task),
+			Self::FileEmpty(out) => out.reduce(task),
+			Self::FileEmptyDo(out) => out.reduce(task),
 			Self::FileDownload(out) => out.reduce(task),
 			Self::FileDownloadDo(out) => out.reduce(task),
 			Self::FileUpload(out) => out.reduce(task),
 			Self::FileUploadDo(out) => out.reduce(task),
+			Self::FileSync(out) => out.reduce(task),
 			// Plugin
 			Self::PluginEntry(out) => out.reduce(task),
 			// Prework
@@ -86,6 +95,7 @@ impl TaskOut {
 			Self::HookCut(out) => out.reduce(task),
 			Self::HookLink(out) => out.reduce(task),
 			Self::HookHardlink(out) => out.reduce(task),
+			Self::HookSync(out) => out.reduce(task),
 		}
 	}
 }
@@ -0,0 +1,16 @@
+use crate::{file::FileIn, size::SizeIn};
+
+/// Work of a paused task, held back until the task is resumed.
+#[derive(Debug)]
+pub(crate) enum Parked {
+	File(Box<FileIn>, u8),
+	Size(SizeIn, u8),
+}
+
+impl From<(FileIn, u8)> for Parked {
+	fn from((r#in, priority): (FileIn, u8)) -> Self { Self::File(Box::new(r#in), priority) }
+}
+
+impl From<(SizeIn, u8)> for Parked {
+	fn from((r#in, priority): (SizeIn, u8)) -> Self { Self::Size(r#in, priority) }
+}
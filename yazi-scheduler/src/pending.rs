@@ -0,0 +1,226 @@
+use std::{fs::File, io::{Read, Write}, path::{Path, PathBuf}};
+
+use hashbrown::HashSet;
+use serde::{Deserialize, Serialize};
+use tracing::error;
+use yazi_fs::Xdg;
+use yazi_shared::{Id, url::{UrlBuf, UrlLike}};
+
+use crate::file::Conflict;
+
+/// A file task left unfinished on exit, which can be resubmitted on the next
+/// start.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "kebab-case")]
+pub enum Pending {
+	Copy {
+		from:     UrlBuf,
+		to:       UrlBuf,
+		conflict: Conflict,
+		follow:   bool,
+		verify:   bool,
+		/// Destinations being written when the task was interrupted.
+		#[serde(default, skip_serializing_if = "HashSet::is_empty")]
+		partial:  HashSet<UrlBuf>,
+	},
+	Cut {
+		from:     UrlBuf,
+		to:       UrlBuf,
+		conflict: Conflict,
+		verify:   bool,
+		/// Destinations being written when the task was interrupted.
+		#[serde(default, skip_serializing_if = "HashSet::is_empty")]
+		partial:  HashSet<UrlBuf>,
+	},
+	Download {
+		target: UrlBuf,
+	},
+	Upload {
+		target: UrlBuf,
+	},
+}
+
+impl Pending {
+	pub fn title(&self) -> String {
+		match self {
+			Self::Copy { from, to, .. } => format!("Copy {} to {}", from.display(), to.display()),
+			Self::Cut { from, to, .. } => format!("Cut {} to {}", from.display(), to.display()),
+			Self::Download { target } => format!("Download {}", target.display()),
+			Self::Upload { target } => format!("Upload {}", target.display()),
+		}
+	}
+
+	/// Claims the tasks left unfinished by instances that are no longer running.
+	pub fn claim() -> Backlog { Backlog::claim_in(&Self::dir()) }
+
+	pub(crate) fn save<'a>(pending: impl Iterator<Item = &'a Self>) {
+		Self::save_in(&Self::dir(), *yazi_dds::ID, pending);
+	}
+
+	/// Updates the destination once it's been determined, files under it are
+	/// created by the task itself from then on, so they can be safely resumed.
+	pub(crate) fn settle(&mut self, dest: &UrlBuf) {
+		match self {
+			Self::Copy { to, conflict, .. } | Self::Cut { to, conflict, .. } => {
+				if *conflict == Conflict::Rename {
+					*conflict = Conflict::Resume;
+				}
+				*to = dest.clone();
+			}
+			Self::Download { .. } | Self::Upload { .. } => {}
+		}
+	}
+
+	/// Marks `dest` as being written, so it's resumed whatever the conflict
+	/// policy is, if the task gets interrupted before it's closed.
+	pub(crate) fn open(&mut self, dest: UrlBuf) {
+		if let Self::Copy { partial, .. } | Self::Cut { partial, .. } = self {
+			partial.insert(dest);
+		}
+	}
+
+	pub(crate) fn close(&mut self, dest: &UrlBuf) {
+		if let Self::Copy { partial, .. } | Self::Cut { partial, .. } = self {
+			partial.remove(dest);
+		}
+	}
+
+	fn save_in<'a>(dir: &Path, id: Id, pending: impl Iterator<Item = &'a Self>) {
+		let pending: Vec<_> = pending.collect();
+		if pending.is_empty() {
+			return;
+		}
+
+		let path = dir.join(format!("{id}.json"));
+		let result = serde_json::to_vec(&pending).map_err(Into::into).and_then(|json| {
+			std::fs::create_dir_all(dir)?;
+			let mut file = File::options().write(true).create(true).truncate(false).open(&path)?;
+			file.lock()?;
+			file.set_len(0)?;
+			file.write_all(&json)
+		});
+
+		if let Err(e) = result {
+			error!("Failed to save unfinished tasks: {e:?}");
+		}
+	}
+
+	#[inline]
+	fn dir() -> PathBuf { Xdg::state_dir().join("tasks") }
+}
+
+// --- Backlog
+/// Tasks claimed from the files left by previous instances, each file is kept
+/// locked until its tasks are resubmitted, so no other instance offers them.
+#[derive(Default)]
+pub struct Backlog {
+	pub tasks: Vec<Pending>,
+	files:     Vec<(PathBuf, File)>,
+}
+
+impl Backlog {
+	fn claim_in(dir: &Path) -> Self {
+		let mut backlog = Self::default();
+		let Ok(it) = std::fs::read_dir(dir) else { return backlog };
+
+		for path in it.flatten().map(|e| e.path()) {
+			if path.extension().is_none_or(|e| e != "json") {
+				continue;
+			}
+
+			let Ok(mut file) = File::open(&path) else { continue };
+			if file.try_lock().is_err() {
+				continue;
+			}
+
+			let mut b = vec![];
+			if file.read_to_end(&mut b).is_err() || b.is_empty() {
+				continue;
+			}
+
+			match serde_json::from_slice::<Vec<Pending>>(&b) {
+				Ok(tasks) => {
+					backlog.tasks.extend(tasks);
+					backlog.files.push((path, file));
+				}
+				Err(e) => error!("Failed to load unfinished tasks from {path:?}: {e:?}"),
+			}
+		}
+
+		backlog
+	}
+
+	/// Removes the claimed files, once their tasks have been resubmitted.
+	pub(crate) fn remove(self) {
+		for (path, _) in self.files {
+			std::fs::remove_file(path).ok();
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn url(s: &str) -> UrlBuf { s.parse().unwrap() }
+
+	fn copy(conflict: Conflict) -> Pending {
+		Pending::Copy {
+			from: url("/a"),
+			to: url("/b"),
+			conflict,
+			follow: false,
+			verify: true,
+			partial: Default::default(),
+		}
+	}
+
+	#[test]
+	fn test_round_trip() {
+		yazi_shared::init_tests();
+		let tmp = tempfile::tempdir().unwrap();
+
+		let mut pending = copy(Conflict::Skip);
+		pending.open(url("/b/c"));
+		let download = Pending::Download { target: url("/d") };
+		Pending::save_in(tmp.path(), Id::from(1u64), [&pending, &download].into_iter());
+		Pending::save_in(tmp.path(), Id::from(2u64), [].into_iter());
+
+		let backlog = Backlog::claim_in(tmp.path());
+		assert_eq!(backlog.tasks.len(), 2);
+		let Pending::Copy { conflict, verify, partial, .. } = &backlog.tasks[0] else { panic!() };
+		assert_eq!((*conflict, *verify), (Conflict::Skip, true));
+		assert!(partial.contains(&url("/b/c")));
+
+		// Claimed already, and not removed until they're resubmitted
+		assert!(Backlog::claim_in(tmp.path()).tasks.is_empty());
+		drop(backlog);
+
+		let backlog = Backlog::claim_in(tmp.path());
+		assert_eq!(backlog.tasks.len(), 2);
+		backlog.remove();
+		assert!(Backlog::claim_in(tmp.path()).tasks.is_empty());
+	}
+
+	#[test]
+	fn test_settle() {
+		yazi_shared::init_tests();
+
+		let mut pending = copy(Conflict::Rename);
+		pending.settle(&url("/b_1"));
+		let Pending::Copy { to, conflict, .. } = &pending else { panic!() };
+		assert_eq!((to, *conflict), (&url("/b_1"), Conflict::Resume));
+
+		// Existing files at the destination are still subject to the policy,
+		// only the ones being written are resumed
+		let mut pending = copy(Conflict::Skip);
+		pending.settle(&url("/b"));
+		pending.open(url("/b/1"));
+		pending.open(url("/b/2"));
+		pending.close(&url("/b/1"));
+
+		let Pending::Copy { conflict, partial, .. } = &pending else { panic!() };
+		assert_eq!(*conflict, Conflict::Skip);
+		assert_eq!(partial.iter().collect::<Vec<_>>(), [&url("/b/2")]);
+	}
+}
@@ -1,6 +1,6 @@
 use serde::Serialize;
 
-use crate::{CleanupState, TaskSummary, dispatch_progress, fetch::FetchProg, file::{FileProgCopy, FileProgCut, FileProgDelete, FileProgDownload, FileProgHardlink, FileProgLink, FileProgTrash, FileProgUpload}, impl_from_prog, plugin::PluginProgEntry, preload::PreloadProg, process::{ProcessProgBg, ProcessProgBlock, ProcessProgOrphan}, size::SizeProg};
+use crate::{CleanupState, TaskSummary, dispatch_progress, fetch::FetchProg, file::{FileProgCopy, FileProgCut, FileProgDelete, FileProgDownload, FileProgEmpty, FileProgHardlink, FileProgLink, FileProgRestore, FileProgSync, FileProgTrash, FileProgUpload, FileTally}, impl_from_prog, plugin::PluginProgEntry, preload::PreloadProg, process::{ProcessProgBg, ProcessProgBlock, ProcessProgOrphan}, size::SizeProg};
 
 pub trait Progress: Copy {
 	// Whether the task is still cooking or cleaning.
@@ -62,8 +62,11 @@ pub enum TaskProg {
 	FileHardlink(FileProgHardlink),
 	FileDelete(FileProgDelete),
 	FileTrash(FileProgTrash),
+	FileRestore(FileProgRestore),
+	FileEmpty(FileProgEmpty),
 	FileDownload(FileProgDownload),
 	FileUpload(FileProgUpload),
+	FileSync(FileProgSync),
 	// Plugin
 	PluginEntry(PluginProgEntry),
 	// Fetch
@@ -80,7 +83,7 @@ pub enum TaskProg {
 
 impl_from_prog! {
 	// File
-	FileCopy(FileProgCopy), FileCut(FileProgCut), FileLink(FileProgLink), FileHardlink(FileProgHardlink), FileDelete(FileProgDelete), FileTrash(FileProgTrash), FileDownload(FileProgDownload), FileUpload(FileProgUpload),
+	FileCopy(FileProgCopy), FileCut(FileProgCut), FileLink(FileProgLink), FileHardlink(FileProgHardlink), FileDelete(FileProgDelete), FileTrash(FileProgTrash), FileRestore(FileProgRestore), FileEmpty(FileProgEmpty), FileDownload(FileProgDownload), FileUpload(FileProgUpload), FileSync(FileProgSync),
 	// Plugin
 	PluginEntry(PluginProgEntry),
 	// Fetch
@@ -103,8 +106,11 @@ impl From<TaskProg> for TaskSummary {
 			TaskProg::FileHardlink(p) => p.into(),
 			TaskProg::FileDelete(p) => p.into(),
 			TaskProg::FileTrash(p) => p.into(),
+			TaskProg::FileRestore(p) => p.into(),
+			TaskProg::FileEmpty(p) => p.into(),
 			TaskProg::FileDownload(p) => p.into(),
 			TaskProg::FileUpload(p) => p.into(),
+			TaskProg::FileSync(p) => p.into(),
 			// Plugin
 			TaskProg::PluginEntry(p) => p.into(),
 			// Prework
@@ -134,6 +140,36 @@ impl Progress for TaskProg {
 }
 
 impl TaskProg {
+	/// Whether the task is split into pieces of work that can be held back.
+	pub(crate) fn pausable(self) -> bool {
+		matches!(
+			self,
+			Self::FileCopy(_)
+				| Self::FileCut(_)
+				| Self::FileLink(_)
+				| Self::FileHardlink(_)
+				| Self::FileDelete(_)
+				| Self::FileTrash(_)
+				| Self::FileRestore(_)
+				| Self::FileEmpty(_)
+				| Self::FileDownload(_)
+				| Self::FileUpload(_)
+				| Self::FileSync(_)
+				| Self::Size(_)
+		)
+	}
+
+	/// Per-file counters of the task, if it transfers files one by one.
+	pub(crate) fn tally(&mut self) -> Option<&mut dyn FileTally> {
+		match self {
+			Self::FileCopy(p) => Some(p),
+			Self::FileCut(p) => Some(p),
+			Self::FileDelete(p) => Some(p),
+			Self::FileSync(p) => Some(p),
+			_ => None,
+		}
+	}
+
 	pub(crate) fn is_user(self) -> bool {
 		match self {
 			// File
@@ -143,8 +179,11 @@ impl TaskProg {
 			Self::FileHardlink(_) => true,
 			Self::FileDelete(_) => true,
 			Self::FileTrash(_) => true,
+			Self::FileRestore(_) => true,
+			Self::FileEmpty(_) => true,
 			Self::FileDownload(_) => true,
 			Self::FileUpload(_) => true,
+			Self::FileSync(_) => true,
 			// Plugin
 			Self::PluginEntry(_) => true,
 			// Prework
@@ -1,6 +1,7 @@
 use tokio::sync::mpsc;
+use yazi_config::popup::ConfirmCfg;
 use yazi_macro::{emit, relay};
-use yazi_shared::{Id, SStr, url::UrlBuf};
+use yazi_shared::{CompletionToken, Id, SStr, url::UrlBuf};
 
 pub struct AppProxy;
 
@@ -18,6 +19,24 @@ impl AppProxy {
 	}
 }
 
+// --- Confirm
+pub struct ConfirmProxy;
+
+impl ConfirmProxy {
+	/// Shows a confirm with named choices, returns the chosen one
+	/// and whether it applies to all, or `None` if cancelled.
+	pub async fn choose(cfg: ConfirmCfg) -> Option<(&'static str, bool)> {
+		let (tx, mut rx) = mpsc::unbounded_channel();
+		emit!(Call(
+			relay!(confirm:show)
+				.with_any("cfg", cfg)
+				.with_any("token", CompletionToken::default())
+				.with_any("choice", tx)
+		));
+		rx.recv().await
+	}
+}
+
 // --- Tasks
 pub struct TasksProxy;
 
@@ -39,6 +58,14 @@ impl TasksProxy {
 pub struct NotifyProxy;
 
 impl NotifyProxy {
+	pub fn push_info(title: impl Into<SStr>, content: impl Into<SStr>) {
+		emit!(Call(
+			relay!(notify:push, [content.into(), title.into()])
+				.with("level", SStr::Borrowed("info"))
+				.with("timeout", 10f64)
+		));
+	}
+
 	pub fn push_warn(title: impl Into<SStr>, content: impl Into<SStr>) {
 		emit!(Call(
 			relay!(notify:push, [content.into(), title.into()])
@@ -1,11 +1,11 @@
-use std::{ops::Deref, sync::Arc, time::Duration};
+use std::{mem, ops::Deref, sync::Arc, time::Duration};
 
 use tokio::task::JoinHandle;
 use yazi_config::{YAZI, plugin::{Fetcher, Preloader}};
 use yazi_fs::FsHash64;
 use yazi_shared::{CompletionToken, Id, Throttle, url::{UrlBuf, UrlLike}};
 
-use crate::{Behavior, HIGH, LOW, NORMAL, Task, TaskIn, TaskProg, Worker, fetch::FetchIn, file::{FileInCopy, FileInCut, FileInDelete, FileInDownload, FileInHardlink, FileInLink, FileInTrash, FileInUpload, FileOutCopy, FileOutCut, FileOutDownload, FileOutHardlink, FileOutUpload}, hook::{HookIn, HookInDelete, HookInDownload, HookInPreload, HookInTrash, HookInUpload}, plugin::PluginInEntry, preload::PreloadIn, process::{ProcessIn, ProcessInBg, ProcessInBlock, ProcessInOrphan, ProcessOpt}, size::SizeIn};
+use crate::{Backlog, Behavior, HIGH, LOW, NORMAL, NotifyProxy, Parked, Pending, Progress, Task, TaskIn, TaskProg, Worker, fetch::FetchIn, file::{FileInCopy, FileInCut, FileInDelete, FileInDownload, FileInEmpty, FileInHardlink, FileInLink, FileInRestore, FileInSync, FileInTrash, FileInUpload, FileOutCopy, FileOutCut, FileOutDownload, FileOutHardlink, FileOutSync, FileOutUpload, Resolver}, hook::{HookIn, HookInDelete, HookInDownload, HookInPreload, HookInTrash, HookInUpload}, journal::{Record, Replay}, plugin::PluginInEntry, preload::PreloadIn, process::{ProcessIn, ProcessInBg, ProcessInBlock, ProcessInOrphan, ProcessOpt}, size::SizeIn};
 
 pub struct Scheduler {
 	pub worker:   Worker,
@@ -33,6 +33,7 @@ impl Scheduler {
 		let mut ongoing = self.ongoing.lock();
 		let task = ongoing.add(r#in);
 
+		task.batch = self.journal.batch();
 		self.behavior.update(task.id);
 		map(task)
 	}
@@ -59,18 +60,76 @@ impl Scheduler {
 		true
 	}
 
+	/// Pauses the task, work that's already underway is finished, the rest is
+	/// held back and the workers are freed for other tasks.
+	pub fn pause(&self, id: Id) -> bool {
+		let mut ongoing = self.ongoing.lock();
+		let Some(task) = ongoing.get_mut(id) else { return false };
+
+		if task.paused() || !task.prog.pausable() || !task.prog.running() {
+			return false;
+		}
+
+		task.parked = Some(vec![]);
+		task.pause.set(true);
+		true
+	}
+
+	pub fn resume(&self, id: Id) -> bool {
+		let Some(parked) = self.ongoing.lock().get_mut(id).and_then(|t| {
+			t.pause.set(false);
+			t.parked.take()
+		}) else {
+			return false;
+		};
+
+		for p in parked {
+			match p {
+				Parked::File(r#in, priority) => self.file.submit(*r#in, priority),
+				Parked::Size(r#in, priority) => self.size.submit(r#in, priority),
+			}
+		}
+		true
+	}
+
 	pub fn shutdown(&self) {
+		Pending::save(
+			self
+				.ongoing
+				.lock()
+				.values()
+				.filter(|t| !t.prog.cooked() && t.done.completed().is_none())
+				.filter_map(|t| t.pending.as_ref()),
+		);
+
 		for handle in &self.handles {
 			handle.abort();
 		}
 	}
 
-	pub fn file_cut(&self, from: UrlBuf, to: UrlBuf, force: bool) {
+	pub fn file_cut(&self, from: UrlBuf, to: UrlBuf, resolver: Resolver, verify: bool) {
 		let follow = !from.scheme().covariant(to.scheme());
-		let mut r#in =
-			FileInCut { id: Id::ZERO, from, to, force, cha: None, follow, retry: 0, drop: None };
+		let mut r#in = FileInCut {
+			id: Id::ZERO,
+			from,
+			to,
+			resolver,
+			cha: None,
+			follow,
+			verify,
+			retry: 0,
+			resume: Default::default(),
+			drop: None,
+		};
+		let pending = Pending::Cut {
+			from: r#in.from.clone(),
+			to: r#in.to.clone(),
+			conflict: r#in.resolver.conflict(),
+			verify,
+			partial: r#in.resolver.partial().clone(),
+		};
 
-		self.add(&mut r#in, |_| ());
+		self.add(&mut r#in, |t| t.pending = Some(pending));
 		if r#in.to.try_starts_with(&r#in.from).unwrap_or(false) && !r#in.to.covariant(&r#in.from) {
 			self.ops.out(r#in.id, FileOutCut::Fail("Cannot cut directory into itself".to_owned()));
 		} else {
@@ -78,11 +137,36 @@ impl Scheduler {
 		}
 	}
 
-	pub fn file_copy(&self, from: UrlBuf, to: UrlBuf, force: bool, follow: bool) {
+	pub fn file_copy(
+		&self,
+		from: UrlBuf,
+		to: UrlBuf,
+		resolver: Resolver,
+		follow: bool,
+		verify: bool,
+	) {
 		let follow = follow || !from.scheme().covariant(to.scheme());
-		let mut r#in = FileInCopy { id: Id::ZERO, from, to, force, cha: None, follow, retry: 0 };
+		let mut r#in = FileInCopy {
+			id: Id::ZERO,
+			from,
+			to,
+			resolver,
+			cha: None,
+			follow,
+			verify,
+			retry: 0,
+			resume: Default::default(),
+		};
+		let pending = Pending::Copy {
+			from: r#in.from.clone(),
+			to: r#in.to.clone(),
+			conflict: r#in.resolver.conflict(),
+			follow,
+			verify,
+			partial: r#in.resolver.partial().clone(),
+		};
 
-		self.add(&mut r#in, |_| ());
+		self.add(&mut r#in, |t| t.pending = Some(pending));
 		if r#in.to.try_starts_with(&r#in.from).unwrap_or(false) && !r#in.to.covariant(&r#in.from) {
 			self.ops.out(r#in.id, FileOutCopy::Fail("Cannot copy directory into itself".to_owned()));
 		} else {
@@ -141,10 +225,29 @@ impl Scheduler {
 		self.file.submit(r#in, LOW);
 	}
 
+	pub fn file_restore(&self, target: UrlBuf) {
+		let mut r#in = FileInRestore { id: Id::ZERO, target };
+
+		self.add(&mut r#in, |_| ());
+		self.file.submit(r#in, LOW);
+	}
+
+	pub fn file_empty(&self, target: UrlBuf) {
+		let mut r#in = FileInEmpty { id: Id::ZERO, target };
+
+		self.add(&mut r#in, |_| ());
+		self.file.submit(r#in, LOW);
+	}
+
 	pub fn file_download(&self, target: UrlBuf) -> CompletionToken {
-		let mut r#in = FileInDownload { id: Id::ZERO, target, cha: None, retry: 0 };
+		let mut r#in =
+			FileInDownload { id: Id::ZERO, target, cha: None, retry: 0, resume: Default::default() };
 		let hook = HookInDownload::new(&r#in.target);
-		let done = self.add_hooked(&mut r#in, hook, |t| t.done.clone());
+		let pending = Pending::Download { target: r#in.target.clone() };
+		let done = self.add_hooked(&mut r#in, hook, |t| {
+			t.pending = Some(pending);
+			t.done.clone()
+		});
 
 		if r#in.target.kind().is_remote() {
 			self.file.submit(r#in, LOW);
@@ -155,9 +258,17 @@ impl Scheduler {
 	}
 
 	pub fn file_upload(&self, target: UrlBuf) {
-		let mut r#in = FileInUpload { id: Id::ZERO, target, cha: None, cache: None };
+		let mut r#in = FileInUpload {
+			id: Id::ZERO,
+			target,
+			cha: None,
+			cache: None,
+			retry: 0,
+			resume: Default::default(),
+		};
 		let hook = HookInUpload::new(&r#in.target);
-		self.add_hooked(&mut r#in, hook, |_| ());
+		let pending = Pending::Upload { target: r#in.target.clone() };
+		self.add_hooked(&mut r#in, hook, |t| t.pending = Some(pending));
 
 		if r#in.target.kind().is_remote() {
 			self.file.submit(r#in, LOW);
@@ -166,6 +277,96 @@ impl Scheduler {
 		}
 	}
 
+	pub fn file_sync(&self, from: UrlBuf, to: UrlBuf, delete: bool, dry: bool, verify: bool) {
+		let follow = !from.scheme().covariant(to.scheme());
+		let mut r#in = FileInSync { id: Id::ZERO, from, to, follow, verify, delete, dry };
+
+		self.add(&mut r#in, |_| ());
+		if r#in.to.try_starts_with(&r#in.from).unwrap_or(false)
+			|| r#in.from.try_starts_with(&r#in.to).unwrap_or(false)
+		{
+			self
+				.ops
+				.out(r#in.id, FileOutSync::Fail("Cannot sync directories nested in each other".to_owned()));
+		} else {
+			self.file.submit(r#in, LOW);
+		}
+	}
+
+	/// Undoes the last journal entry, returns the copies on remote destinations
+	/// for the caller to delete permanently once confirmed, or `None` if there's
+	/// nothing to undo.
+	pub fn undo(&self) -> Option<Vec<UrlBuf>> {
+		let entry = self.journal.undo()?;
+
+		let (mut renames, mut restores, mut deletes) = (vec![], vec![], vec![]);
+		for record in entry.records.into_iter().rev() {
+			if !record.reversible() {
+				NotifyProxy::push_warn("Undo", format!("Cannot undo: {}", record.title()));
+				continue;
+			}
+			match record {
+				Record::Copy { to, .. } if to.kind().is_local() => self.file_trash(to),
+				Record::Copy { to, .. } => deletes.push(to),
+				Record::Cut { from, to, .. } => self.file_cut(to, from, Resolver::default(), false),
+				Record::Rename { from, to } => renames.push((to, from)),
+				Record::Link { to, .. } | Record::Hardlink { to, .. } => self.file_delete(to),
+				Record::Trash { target } => restores.push(target),
+				Record::Upload { .. } => {}
+			}
+		}
+
+		tokio::spawn(async move {
+			Replay::rename("Undo", renames).await;
+			Replay::restore("Undo", restores).await;
+		});
+		Some(deletes)
+	}
+
+	pub fn redo(&self) -> bool {
+		let Some(entry) = self.journal.redo() else { return false };
+
+		let mut renames = vec![];
+		for record in entry.records {
+			match record {
+				Record::Copy { from, to, .. } => {
+					self.file_copy(from, to, Resolver::default(), false, false)
+				}
+				Record::Cut { from, to, .. } => self.file_cut(from, to, Resolver::default(), false),
+				Record::Rename { from, to } => renames.push((from, to)),
+				Record::Link { from, to } => self.file_link(from, to, false, false),
+				Record::Hardlink { from, to } => self.file_hardlink(from, to, false, false),
+				Record::Trash { target } => self.file_trash(target),
+				Record::Upload { target } => self.file_upload(target),
+			}
+		}
+
+		tokio::spawn(Replay::rename("Redo", renames));
+		true
+	}
+
+	/// Resubmits the file tasks left unfinished last time, as a new batch.
+	pub fn resubmit(&self, mut backlog: Backlog) {
+		self.behavior.reset();
+
+		let tasks = mem::take(&mut backlog.tasks);
+		self.journal.scope(|| for p in tasks {
+			match p {
+				Pending::Copy { from, to, conflict, follow, verify, partial } => {
+					let resolver = Resolver::from(conflict).with_partial(partial);
+					self.file_copy(from, to, resolver, follow, verify);
+				}
+				Pending::Cut { from, to, conflict, verify, partial } => {
+					self.file_cut(from, to, Resolver::from(conflict).with_partial(partial), verify);
+				}
+				Pending::Download { target } => _ = self.file_download(target),
+				Pending::Upload { target } => self.file_upload(target),
+			}
+		});
+
+		backlog.remove();
+	}
+
 	pub fn plugin_entry(&self, mut r#in: PluginInEntry) -> Id {
 		if r#in.track {
 			self.behavior.reset();
@@ -4,10 +4,13 @@ use crate::{Task, TaskProg};
 
 #[derive(Debug, PartialEq, Eq, Serialize)]
 pub struct TaskSnap {
-	pub title: String,
-	pub prog:  TaskProg,
+	pub title:  String,
+	pub prog:   TaskProg,
+	pub paused: bool,
 }
 
 impl From<&Task> for TaskSnap {
-	fn from(task: &Task) -> Self { Self { title: task.title.clone(), prog: task.prog } }
+	fn from(task: &Task) -> Self {
+		Self { title: task.title.clone(), prog: task.prog, paused: task.paused() }
+	}
 }
@@ -1,15 +1,23 @@
 use tokio::sync::mpsc;
+use yazi_fs::provider::Pause;
 use yazi_shared::{CompletionToken, Id};
 
-use crate::{TaskIn, TaskProg, hook::HookIn};
+use crate::{Parked, Pending, TaskIn, TaskProg, hook::HookIn};
 
 #[derive(Debug)]
 pub struct Task {
-	pub id:          Id,
-	pub title:       String,
-	pub(crate) prog: TaskProg,
-	pub(crate) hook: Option<HookIn>,
-	pub done:        CompletionToken,
+	pub id:             Id,
+	pub title:          String,
+	pub(crate) prog:    TaskProg,
+	pub(crate) hook:    Option<HookIn>,
+	pub done:           CompletionToken,
+	pub(crate) batch:   Id,
+	/// Work held back while the task is paused, `None` if not paused.
+	pub(crate) parked:  Option<Vec<Parked>>,
+	/// Stops its copies in progress at the next chunk boundary while paused.
+	pub(crate) pause:   Pause,
+	/// What to resubmit if the task is left unfinished on exit.
+	pub(crate) pending: Option<Pending>,
 
 	pub logs:   String,
 	pub logger: Option<mpsc::UnboundedSender<String>>,
@@ -23,12 +31,19 @@ impl Task {
 			prog,
 			hook: None,
 			done: Default::default(),
+			batch: Id::ZERO,
+			parked: None,
+			pause: Default::default(),
+			pending: None,
 
 			logs: Default::default(),
 			logger: Default::default(),
 		}
 	}
 
+	#[inline]
+	pub fn paused(&self) -> bool { self.parked.is_some() }
+
 	pub(crate) fn log(&mut self, line: String) {
 		self.logs.push_str(&line);
 		self.logs.push('\n');
@@ -4,7 +4,7 @@ use parking_lot::Mutex;
 use tokio::{select, sync::mpsc, task::JoinHandle};
 use yazi_config::YAZI;
 
-use crate::{CleanupState, LOW, Ongoing, Progress, TaskIn, TaskOp, TaskOps, TaskOut, fetch::{Fetch, FetchIn}, file::{File, FileIn}, hook::{Hook, HookIn}, plugin::{Plugin, PluginIn}, preload::{Preload, PreloadIn}, process::{Process, ProcessIn}, size::{Size, SizeIn}};
+use crate::{CleanupState, LOW, Ongoing, Progress, TaskIn, TaskOp, TaskOps, TaskOut, fetch::{Fetch, FetchIn}, file::{File, FileIn}, hook::{Hook, HookIn}, journal::Journal, plugin::{Plugin, PluginIn}, preload::{Preload, PreloadIn}, process::{Process, ProcessIn}, size::{Size, SizeIn}};
 
 #[derive(Clone)]
 pub struct Worker {
@@ -18,6 +18,7 @@ pub struct Worker {
 
 	pub ops:     TaskOps,
 	pub ongoing: Arc<Mutex<Ongoing>>,
+	pub journal: Arc<Journal>,
 }
 
 impl Worker {
@@ -32,16 +33,27 @@ impl Worker {
 		let (op_tx, op_rx) = mpsc::unbounded_channel();
 
 		let ongoing = Arc::new(Mutex::new(Ongoing::default()));
-		let file = Arc::new(File::new(&op_tx, file_tx));
+		let journal = Arc::new(Journal::new());
+		let file = Arc::new(File::new(&op_tx, &ongoing, file_tx));
 		let plugin = Arc::new(Plugin::new(&op_tx, plugin_tx));
 		let fetch = Arc::new(Fetch::new(&op_tx, fetch_tx));
 		let preload = Arc::new(Preload::new(&op_tx, preload_tx));
 		let size = Arc::new(Size::new(&op_tx, size_tx));
 		let process = Arc::new(Process::new(&op_tx, process_tx));
-		let hook = Arc::new(Hook::new(&op_tx, &ongoing, &preload, hook_tx));
-
-		let me =
-			Self { file, plugin, fetch, preload, size, process, hook, ops: TaskOps(op_tx), ongoing };
+		let hook = Arc::new(Hook::new(&op_tx, &ongoing, &journal, &preload, hook_tx));
+
+		let me = Self {
+			file,
+			plugin,
+			fetch,
+			preload,
+			size,
+			process,
+			hook,
+			ops: TaskOps(op_tx),
+			ongoing,
+			journal,
+		};
 
 		let handles = []
 			.into_iter()
@@ -62,10 +74,13 @@ impl Worker {
 		let me = self.clone();
 		tokio::spawn(async move {
 			loop {
-				if let Ok((r#in, _)) = rx.recv().await {
+				if let Ok((r#in, priority)) = rx.recv().await {
 					let id = r#in.id();
-					let Some(token) = me.ongoing.lock().get_token(id) else {
-						continue;
+					let (token, r#in) = {
+						let mut ongoing = me.ongoing.lock();
+						let Some(token) = ongoing.get_token(id) else { continue };
+						let Some(r#in) = ongoing.park(id, r#in, priority) else { continue };
+						(token, r#in)
 					};
 
 					let result = select! {
@@ -95,10 +110,15 @@ impl Worker {
 			FileIn::DeleteDo(r#in) => self.file.delete_do(r#in).await.map_err(Into::into),
 			FileIn::Trash(r#in) => self.file.trash(r#in).await.map_err(Into::into),
 			FileIn::TrashDo(r#in) => self.file.trash_do(r#in).await.map_err(Into::into),
+			FileIn::Restore(r#in) => self.file.restore(r#in).await.map_err(Into::into),
+			FileIn::RestoreDo(r#in) => self.file.restore_do(r#in).await.map_err(Into::into),
+			FileIn::Empty(r#in) => self.file.empty(r#in).await.map_err(Into::into),
+			FileIn::EmptyDo(r#in) => self.file.empty_do(r#in).await.map_err(Into::into),
 			FileIn::Download(r#in) => self.file.download(r#in).await.map_err(Into::into),
 			FileIn::DownloadDo(r#in) => self.file.download_do(r#in).await.map_err(Into::into),
 			FileIn::Upload(r#in) => self.file.upload(r#in).await.map_err(Into::into),
 			FileIn::UploadDo(r#in) => self.file.upload_do(r#in).await.map_err(Into::into),
+			FileIn::Sync(r#in) => self.file.sync(r#in).await.map_err(Into::into),
 		}
 	}
 
@@ -189,10 +209,13 @@ impl Worker {
 		let me = self.clone();
 		tokio::spawn(async move {
 			loop {
-				if let Ok((r#in, _)) = rx.recv().await {
+				if let Ok((r#in, priority)) = rx.recv().await {
 					let id = r#in.id();
-					let Some(token) = me.ongoing.lock().get_token(id) else {
-						continue;
+					let (token, r#in) = {
+						let mut ongoing = me.ongoing.lock();
+						let Some(token) = ongoing.get_token(id) else { continue };
+						let Some(r#in) = ongoing.park(id, r#in, priority) else { continue };
+						(token, r#in)
 					};
 
 					let result = select! {
@@ -266,6 +289,7 @@ impl Worker {
 			HookIn::Trash(r#in) => self.hook.trash(r#in).await,
 			HookIn::Link(r#in) => self.hook.link(r#in).await,
 			HookIn::Hardlink(r#in) => self.hook.hardlink(r#in).await,
+			HookIn::Sync(r#in) => self.hook.sync(r#in).await,
 			HookIn::Download(r#in) => self.hook.download(r#in).await,
 			HookIn::Upload(r#in) => self.hook.upload(r#in).await,
 			HookIn::Preload(r#in) => self.hook.preload(r#in).await,
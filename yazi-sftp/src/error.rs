@@ -57,11 +57,15 @@ impl From<Error> for std::io::Error {
 }
 
 impl<T> From<tokio::sync::mpsc::error::SendError<T>> for Error {
-	fn from(_: tokio::sync::mpsc::error::SendError<T>) -> Self { Self::custom("channel closed") }
+	fn from(_: tokio::sync::mpsc::error::SendError<T>) -> Self {
+		Self::IO(std::io::Error::new(std::io::ErrorKind::ConnectionAborted, "channel closed"))
+	}
 }
 
 impl From<tokio::sync::oneshot::error::RecvError> for Error {
-	fn from(_: tokio::sync::oneshot::error::RecvError) -> Self { Self::custom("channel closed") }
+	fn from(_: tokio::sync::oneshot::error::RecvError) -> Self {
+		Self::IO(std::io::Error::new(std::io::ErrorKind::ConnectionAborted, "channel closed"))
+	}
 }
 
 impl From<tokio::time::error::Elapsed> for Error {
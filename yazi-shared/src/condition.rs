@@ -3,7 +3,7 @@ use std::{cmp::Ordering, str::FromStr};
 use anyhow::bail;
 use serde_with::DeserializeFromStr;
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum ConditionOp {
 	Or,
 	And,
@@ -55,7 +55,7 @@ impl PartialOrd for ConditionOp {
 	}
 }
 
-#[derive(Debug, DeserializeFromStr)]
+#[derive(Clone, Debug, DeserializeFromStr)]
 pub struct Condition {
 	ops: Vec<ConditionOp>,
 }
@@ -114,6 +114,10 @@ impl Condition {
 		Self { ops: output }
 	}
 
+	pub fn terms(&self) -> impl Iterator<Item = &str> {
+		self.ops.iter().filter_map(|op| if let ConditionOp::Term(s) = op { Some(s.as_str()) } else { None })
+	}
+
 	pub fn eval(&self, f: impl Fn(&str) -> bool) -> Option<bool> {
 		let mut stack: Vec<bool> = Vec::with_capacity(self.ops.len());
 		for op in &self.ops {
@@ -157,4 +161,18 @@ mod tests {
 
 		Ok(())
 	}
+
+	#[test]
+	fn test_condition_terms() -> anyhow::Result<()> {
+		let cond: Condition = "hovered.is_dir & !visual | mime:image/* & tabs>1".parse()?;
+		assert!(cond.eval(|s| s == "hovered.is_dir").unwrap());
+		assert!(!cond.eval(|s| s == "hovered.is_dir" || s == "visual").unwrap());
+		assert!(cond.eval(|s| s == "mime:image/*" || s == "tabs>1").unwrap());
+		assert!(!cond.eval(|s| s == "mime:image/*").unwrap());
+
+		let terms: Vec<_> = cond.terms().collect();
+		assert_eq!(terms, ["hovered.is_dir", "visual", "mime:image/*", "tabs>1"]);
+
+		Ok(())
+	}
 }
@@ -1,4 +1,4 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use strum::{Display, EnumString, FromRepr, IntoStaticStr};
 
 #[derive(
@@ -14,6 +14,7 @@ use strum::{Display, EnumString, FromRepr, IntoStaticStr};
 	Hash,
 	IntoStaticStr,
 	PartialEq,
+	Serialize,
 )]
 #[serde(rename_all = "kebab-case")]
 #[strum(serialize_all = "kebab-case")]
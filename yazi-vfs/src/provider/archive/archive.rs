@@ -0,0 +1,185 @@
+use std::{io, path::{Path, PathBuf}, sync::Arc};
+
+use tokio::sync::mpsc::Receiver;
+use yazi_fs::{FsScheme, cha::{Cha, ChaMode}, provider::{Attrs, Capabilities, CopyOpt, Provider}};
+use yazi_shared::{loc::LocBuf, path::{AsPath, PathBufDyn}, pool::InternStr, strand::AsStrand, url::{Url, UrlBuf, UrlCow, UrlLike}};
+
+use super::{Format, Index};
+
+#[derive(Clone)]
+pub struct Archive<'a> {
+	url:   Url<'a>,
+	path:  &'a Path,
+	inner: &'a Path,
+}
+
+impl<'a> Provider for Archive<'a> {
+	type File = tokio::fs::File;
+	type Gate = super::Gate;
+	type Me<'b> = Archive<'b>;
+	type ReadDir = super::ReadDir;
+	type UrlCow = UrlCow<'a>;
+
+	async fn absolute(&self) -> io::Result<Self::UrlCow> {
+		super::try_absolute(self.url)
+			.ok_or_else(|| io::Error::other("Cannot get absolute path for archive URL"))
+	}
+
+	async fn canonicalize(&self) -> io::Result<UrlBuf> {
+		let (inner, _) = self.index().await?.follow(self.inner)?;
+		let portal = Self::portal_of(&tokio::fs::canonicalize(self.path).await?, self.domain());
+
+		Ok(if inner.as_os_str().is_empty() { portal } else { portal.try_join(inner.as_os_str())? })
+	}
+
+	fn capabilities(&self) -> Capabilities { Capabilities { symlink: false } }
+
+	async fn casefold(&self) -> io::Result<UrlBuf> {
+		let Some((parent, name)) = self.url.parent().zip(self.inner.file_name()) else {
+			return Ok(self.url.to_owned());
+		};
+
+		let index = self.index().await?;
+		let mut similar = None;
+		for s in &index.get(self.inner.parent().unwrap_or(Path::new("")))?.children {
+			if !name.eq_ignore_ascii_case(s) {
+				continue;
+			} else if s == name {
+				return Ok(self.url.to_owned());
+			} else if similar.is_none() {
+				similar = Some(s);
+			} else {
+				return Err(io::Error::from(io::ErrorKind::NotFound));
+			}
+		}
+
+		similar
+			.map(|n| parent.try_join(n.as_os_str()))
+			.transpose()?
+			.ok_or_else(|| io::Error::from(io::ErrorKind::NotFound))
+	}
+
+	async fn copy<P>(&self, _to: P, _attrs: Attrs) -> io::Result<u64>
+	where
+		P: AsPath,
+	{
+		Err(Self::read_only())
+	}
+
+	fn copy_with_progress<P, O>(&self, _to: P, _opt: O) -> io::Result<Receiver<io::Result<u64>>>
+	where
+		P: AsPath,
+		O: Into<CopyOpt>,
+	{
+		Err(Self::read_only())
+	}
+
+	async fn create_dir(&self) -> io::Result<()> { Err(Self::read_only()) }
+
+	async fn hard_link<P>(&self, _to: P) -> io::Result<()>
+	where
+		P: AsPath,
+	{
+		Err(Self::read_only())
+	}
+
+	async fn metadata(&self) -> io::Result<Cha> { Ok(self.index().await?.follow(self.inner)?.1.cha) }
+
+	async fn new<'b>(url: Url<'b>) -> io::Result<Self::Me<'b>> {
+		match url {
+			Url::Archive { loc, .. } => {
+				Ok(Self::Me { url, path: loc.base().components().as_path(), inner: loc.uri() })
+			}
+			Url::Regular(_) | Url::Search { .. } | Url::Sftp { .. } => {
+				Err(io::Error::new(io::ErrorKind::InvalidInput, format!("Not an archive URL: {url:?}")))
+			}
+		}
+	}
+
+	async fn read_dir(self) -> io::Result<Self::ReadDir> {
+		let index = self.index().await?;
+		let (inner, node) = index.follow(self.inner)?;
+		if !node.cha.is_dir() {
+			return Err(io::Error::from(io::ErrorKind::NotADirectory));
+		}
+
+		let items = node
+			.children
+			.iter()
+			.filter_map(|name| Some((name.clone(), index.get(&inner.join(name)).ok()?.cha)))
+			.collect::<Vec<_>>();
+
+		Ok(Self::ReadDir { dir: Arc::new(self.url.to_owned()), items: items.into_iter() })
+	}
+
+	async fn read_link(&self) -> io::Result<PathBufDyn> {
+		match &self.index().await?.get(self.inner)?.link {
+			Some(link) => Ok(link.clone().into()),
+			None => Err(io::Error::new(io::ErrorKind::InvalidInput, "Not a symbolic link")),
+		}
+	}
+
+	async fn remove_dir(&self) -> io::Result<()> { Err(Self::read_only()) }
+
+	async fn remove_dir_all(&self) -> io::Result<()> { Err(Self::read_only()) }
+
+	async fn remove_file(&self) -> io::Result<()> { Err(Self::read_only()) }
+
+	async fn rename<P>(&self, _to: P) -> io::Result<()>
+	where
+		P: AsPath,
+	{
+		Err(Self::read_only())
+	}
+
+	async fn set_mode(&self, _mode: ChaMode) -> io::Result<()> { Err(Self::read_only()) }
+
+	async fn symlink<S, F>(&self, _original: S, _is_dir: F) -> io::Result<()>
+	where
+		S: AsStrand,
+		F: AsyncFnOnce() -> io::Result<bool>,
+	{
+		Err(Self::read_only())
+	}
+
+	async fn symlink_metadata(&self) -> io::Result<Cha> {
+		Ok(self.index().await?.get(self.inner)?.cha)
+	}
+
+	async fn trash(&self) -> io::Result<()> { Err(Self::read_only()) }
+
+	#[inline]
+	fn url(&self) -> Url<'_> { self.url }
+}
+
+impl<'a> Archive<'a> {
+	/// Returns the `archive://` URL that browses the given local file,
+	/// if it's an archive in a format that can be browsed.
+	pub fn portal(url: Url) -> Option<UrlBuf> {
+		let path = url.as_local()?;
+		Format::detect(path).map(|_| Self::portal_of(path, ""))
+	}
+
+	fn portal_of(path: &Path, domain: &str) -> UrlBuf {
+		UrlBuf::Archive { loc: LocBuf::<PathBuf>::zeroed(path.to_owned()), domain: domain.intern() }
+	}
+
+	pub(super) async fn spool(&self) -> io::Result<PathBuf> {
+		let Some(cache) = self.url.scheme().cache() else {
+			return Err(io::Error::other("Archive URL has no cache directory"));
+		};
+
+		self.index().await?.spool(self.path, self.inner, cache.join("%spool")).await
+	}
+
+	#[inline]
+	fn domain(&self) -> &str { self.url.scheme().domain().unwrap_or_default() }
+
+	#[inline]
+	async fn index(&self) -> io::Result<Arc<Index>> { Index::load(self.path).await }
+
+	#[inline]
+	fn read_only() -> io::Error {
+		io::Error::new(io::ErrorKind::ReadOnlyFilesystem, "Archives are read-only")
+	}
+}
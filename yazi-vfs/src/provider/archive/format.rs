@@ -0,0 +1,61 @@
+use std::{fs::File, io::{self, BufReader, Read, Seek, SeekFrom}, path::Path};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Format {
+	Zip,
+	Tar,
+	TarGz,
+	TarXz,
+	TarZst,
+}
+
+impl Format {
+	pub fn detect(path: &Path) -> Option<Self> {
+		let name = path.file_name()?.to_str()?.to_ascii_lowercase();
+		let (stem, ext) = name.rsplit_once('.')?;
+
+		Some(match ext {
+			"zip" | "jar" | "war" | "apk" | "epub" | "cbz" | "whl" | "xpi" => Self::Zip,
+			"tar" => Self::Tar,
+			"tgz" => Self::TarGz,
+			"txz" => Self::TarXz,
+			"tzst" => Self::TarZst,
+			"gz" if stem.ends_with(".tar") => Self::TarGz,
+			"xz" if stem.ends_with(".tar") => Self::TarXz,
+			"zst" if stem.ends_with(".tar") => Self::TarZst,
+			_ => None?,
+		})
+	}
+
+	pub(super) fn tar(self, file: File) -> io::Result<tar::Archive<Box<dyn Read>>> {
+		Ok(tar::Archive::new(self.decoder(file)?))
+	}
+
+	/// Reads the uncompressed tarball from `offset`, which is seeked to directly
+	/// if it's not compressed.
+	pub(super) fn tar_at(self, mut file: File, offset: u64) -> io::Result<Box<dyn Read>> {
+		if self == Self::Tar {
+			file.seek(SeekFrom::Start(offset))?;
+			return Ok(Box::new(BufReader::new(file)));
+		}
+
+		let mut reader = self.decoder(file)?;
+		io::copy(&mut reader.by_ref().take(offset), &mut io::sink())?;
+		Ok(reader)
+	}
+
+	fn decoder(self, file: File) -> io::Result<Box<dyn Read>> {
+		let file = BufReader::new(file);
+		let reader: Box<dyn Read> = match self {
+			Self::Zip => Err(io::Error::new(io::ErrorKind::InvalidInput, "Not a tarball"))?,
+			Self::Tar => Box::new(file),
+			Self::TarGz => Box::new(flate2::bufread::MultiGzDecoder::new(file)),
+			Self::TarXz => Box::new(lzma_rust2::XzReader::new(file, true)),
+			Self::TarZst => Box::new(
+				ruzstd::decoding::StreamingDecoder::new(file)
+					.map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?,
+			),
+		};
+		Ok(reader)
+	}
+}
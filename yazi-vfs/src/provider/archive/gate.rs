@@ -0,0 +1,61 @@
+use std::io;
+
+use yazi_fs::provider::{Attrs, FileBuilder, Provider};
+use yazi_shared::url::AsUrl;
+
+use super::Archive;
+
+#[derive(Clone, Copy, Default)]
+pub struct Gate(crate::provider::Gate);
+
+impl FileBuilder for Gate {
+	type File = tokio::fs::File;
+
+	fn append(&mut self, append: bool) -> &mut Self {
+		self.0.append(append);
+		self
+	}
+
+	fn attrs(&mut self, attrs: Attrs) -> &mut Self {
+		self.0.attrs(attrs);
+		self
+	}
+
+	fn create(&mut self, create: bool) -> &mut Self {
+		self.0.create(create);
+		self
+	}
+
+	fn create_new(&mut self, create_new: bool) -> &mut Self {
+		self.0.create_new(create_new);
+		self
+	}
+
+	async fn open<U>(&self, url: U) -> io::Result<Self::File>
+	where
+		U: AsUrl,
+	{
+		let g = self.0;
+		if g.append || g.create || g.create_new || g.truncate || g.write {
+			return Err(io::Error::new(io::ErrorKind::ReadOnlyFilesystem, "Archives are read-only"));
+		}
+
+		let spool = Archive::new(url.as_url()).await?.spool().await?;
+		tokio::fs::File::open(spool).await
+	}
+
+	fn read(&mut self, read: bool) -> &mut Self {
+		self.0.read(read);
+		self
+	}
+
+	fn truncate(&mut self, truncate: bool) -> &mut Self {
+		self.0.truncate(truncate);
+		self
+	}
+
+	fn write(&mut self, write: bool) -> &mut Self {
+		self.0.write(write);
+		self
+	}
+}
@@ -0,0 +1,548 @@
+use std::{borrow::Cow, ffi::OsString, fs, hash::Hash, io::{self, BufReader, Read, Seek, SeekFrom}, path::{Component, Path, PathBuf}, sync::Arc, time::{Duration, SystemTime, UNIX_EPOCH}};
+
+use chrono::TimeZone;
+use hashbrown::HashMap;
+use parking_lot::Mutex;
+use tokio::sync::OnceCell;
+use yazi_fs::{FsHash128, cha::{Cha, ChaKind, ChaMode}};
+use yazi_shim::Twox128;
+
+use super::{Format, INDICES};
+
+pub(super) struct Index {
+	stamp:  u128,
+	format: Format,
+	nodes:  HashMap<PathBuf, Node>,
+	spools: Mutex<HashMap<PathBuf, Arc<OnceCell<PathBuf>>>>,
+}
+
+pub(super) struct Node {
+	pub(super) cha:      Cha,
+	pub(super) link:     Option<PathBuf>,
+	pub(super) children: Vec<OsString>,
+	source:              Source,
+}
+
+/// Where the content of a member is, so that it can be extracted without
+/// going through the archive again.
+enum Source {
+	Implied,
+	/// Offset of the local header, the compressed size and the CRC-32, taken
+	/// from the central directory as the local header may leave them out.
+	Zip { header: u64, compressed: u64, crc: u32 },
+	/// Offset of the data in the uncompressed tarball.
+	Tar(u64),
+	TarLink(PathBuf),
+}
+
+impl Index {
+	pub(super) async fn load(path: &Path) -> io::Result<Arc<Self>> {
+		let cha = Cha::new(path.file_name().unwrap_or_default(), tokio::fs::metadata(path).await?);
+		if let Some(index) = INDICES.lock().get(path).filter(|i| i.stamp == cha.hash_u128()) {
+			return Ok(index.clone());
+		}
+
+		let Some(format) = Format::detect(path) else {
+			return Err(io::Error::new(io::ErrorKind::Unsupported, "Unsupported archive format"));
+		};
+
+		let owned = path.to_owned();
+		let index = tokio::task::spawn_blocking(move || Self::build(&owned, format, cha)).await??;
+
+		let index = Arc::new(index);
+		INDICES.lock().put(path.to_owned(), index.clone());
+		Ok(index)
+	}
+
+	pub(super) fn get(&self, path: &Path) -> io::Result<&Node> {
+		self.nodes.get(path).ok_or_else(|| io::Error::from(io::ErrorKind::NotFound))
+	}
+
+	pub(super) fn follow(&self, path: &Path) -> io::Result<(PathBuf, &Node)> {
+		let mut path = Cow::Borrowed(path);
+		for _ in 0..40 {
+			let node = self.get(&path)?;
+			let Some(link) = &node.link else {
+				return Ok((path.into_owned(), node));
+			};
+
+			let parent = path.parent().unwrap_or(Path::new(""));
+			path =
+				Cow::Owned(resolve(parent, link).ok_or_else(|| io::Error::from(io::ErrorKind::NotFound))?);
+		}
+		Err(io::Error::other("Too many levels of symbolic links"))
+	}
+
+	pub(super) async fn spool(
+		self: Arc<Self>,
+		archive: &Path,
+		path: &Path,
+		dir: PathBuf,
+	) -> io::Result<PathBuf> {
+		let (path, node) = self.follow(path)?;
+		if node.cha.is_dir() {
+			return Err(io::Error::from(io::ErrorKind::IsADirectory));
+		}
+
+		let cell = self.spools.lock().entry(path.clone()).or_default().clone();
+		let dest = cell
+			.get_or_try_init(|| async {
+				let mut h = Twox128::default();
+				archive.hash(&mut h);
+				path.hash(&mut h);
+				let dest = dir.join(format!("{:x}-{:x}", self.stamp, h.finish_128()));
+
+				let (me, archive, to) = (self.clone(), archive.to_owned(), dest.clone());
+				tokio::task::spawn_blocking(move || me.extract(&archive, &path, &to)).await??;
+				Ok::<_, io::Error>(dest)
+			})
+			.await?;
+
+		Ok(dest.clone())
+	}
+
+	fn build(path: &Path, format: Format, cha: Cha) -> io::Result<Self> {
+		let mut me = Self {
+			stamp: cha.hash_u128(),
+			format,
+			nodes: Default::default(),
+			spools: Default::default(),
+		};
+
+		let root = Cha { mode: dir_mode(cha.mode), ..cha };
+		me.nodes.insert(PathBuf::new(), Node::implied(root));
+
+		let file = fs::File::open(path)?;
+		match format {
+			Format::Zip => me.build_zip(file, root)?,
+			Format::Tar | Format::TarGz | Format::TarXz | Format::TarZst => me.build_tar(file, root)?,
+		}
+
+		me.resolve_hard_links();
+		Ok(me)
+	}
+
+	fn build_zip(&mut self, file: fs::File, root: Cha) -> io::Result<()> {
+		let mut zip = zip::ZipArchive::new(BufReader::new(file))?;
+
+		for i in 0..zip.len() {
+			let entry = zip.by_index_raw(i)?;
+			let Some(path) = entry.enclosed_name().and_then(|p| normalize(&p)) else { continue };
+
+			let r#type = if entry.is_dir() {
+				ChaMode::T_DIR
+			} else if entry.is_symlink() {
+				ChaMode::T_LINK
+			} else {
+				ChaMode::T_FILE
+			};
+
+			let source =
+				Source::Zip { header: entry.header_start(), compressed: entry.compressed_size(), crc: entry.crc32() };
+			let cha = Self::cha(&path, root, Stat {
+				r#type,
+				perm: entry.unix_mode().map(|m| m as u16),
+				len: entry.size(),
+				mtime: entry.last_modified().and_then(|t| {
+					let date = chrono::NaiveDate::from_ymd_opt(t.year() as _, t.month() as _, t.day() as _)?;
+					let time = date.and_hms_opt(t.hour() as _, t.minute() as _, t.second() as _)?;
+					chrono::Local.from_local_datetime(&time).single().map(Into::into)
+				}),
+				uid: 0,
+				gid: 0,
+			});
+			drop(entry);
+
+			let link = if r#type == ChaMode::T_LINK {
+				let mut s = String::new();
+				zip.by_index(i)?.read_to_string(&mut s)?;
+				Some(PathBuf::from(s))
+			} else {
+				None
+			};
+
+			self.insert(path, Node { cha, link, children: vec![], source }, root);
+		}
+		Ok(())
+	}
+
+	fn build_tar(&mut self, file: fs::File, root: Cha) -> io::Result<()> {
+		let mut tar = self.format.tar(file)?;
+
+		for entry in tar.entries()? {
+			let entry = entry?;
+			let Some(path) = normalize(&entry.path()?) else { continue };
+
+			let (header, ty) = (entry.header(), entry.header().entry_type());
+			let (r#type, source, link) = if ty.is_dir() {
+				(ChaMode::T_DIR, Source::Implied, None)
+			} else if ty.is_symlink() {
+				(ChaMode::T_LINK, Source::Implied, entry.link_name()?.map(Cow::into_owned))
+			} else if ty.is_hard_link() {
+				let Some(target) = entry.link_name()?.as_deref().and_then(normalize) else { continue };
+				(ChaMode::T_FILE, Source::TarLink(target), None)
+			} else if ty.is_file() || ty.is_contiguous() {
+				(ChaMode::T_FILE, Source::Tar(entry.raw_file_position()), None)
+			} else {
+				continue;
+			};
+
+			let cha = Self::cha(&path, root, Stat {
+				r#type,
+				perm: header.mode().ok().map(|m| m as u16),
+				len: entry.size(),
+				mtime: header.mtime().ok().and_then(|t| UNIX_EPOCH.checked_add(Duration::from_secs(t))),
+				uid: header.uid().unwrap_or(0) as u32,
+				gid: header.gid().unwrap_or(0) as u32,
+			});
+
+			self.insert(path, Node { cha, link, children: vec![], source }, root);
+		}
+		Ok(())
+	}
+
+	fn extract(&self, archive: &Path, path: &Path, dest: &Path) -> io::Result<()> {
+		if let Some(parent) = dest.parent() {
+			fs::create_dir_all(parent)?;
+		}
+
+		let tmp = dest.with_extension("%tmp");
+		let mut out = fs::File::create(&tmp)?;
+
+		let node = self.get(path)?;
+		let (offset, len) = match &node.source {
+			Source::Implied => Err(io::Error::from(io::ErrorKind::NotFound))?,
+			&Source::Zip { header, compressed, crc } => {
+				let mut file = BufReader::new(fs::File::open(archive)?);
+				file.seek(SeekFrom::Start(header))?;
+
+				let opts = zip::ZipReadOptions::new()
+					.override_compressed_size(compressed)
+					.override_uncompressed_size(node.cha.len)
+					.override_crc(crc);
+				let Some(mut entry) = zip::read::read_zipfile_from_stream_with_options(&mut file, opts)?
+				else {
+					Err(io::Error::from(io::ErrorKind::NotFound))?
+				};
+
+				io::copy(&mut entry, &mut out)?;
+				return fs::rename(tmp, dest);
+			}
+			&Source::Tar(offset) => (offset, node.cha.len),
+			Source::TarLink(target) => match self.get(target)? {
+				Node { source: Source::Tar(offset), cha, .. } => (*offset, cha.len),
+				_ => Err(io::Error::from(io::ErrorKind::NotFound))?,
+			},
+		};
+
+		let reader = self.format.tar_at(fs::File::open(archive)?, offset)?;
+		if io::copy(&mut reader.take(len), &mut out)? != len {
+			fs::remove_file(tmp).ok();
+			return Err(io::Error::from(io::ErrorKind::UnexpectedEof));
+		}
+		fs::rename(tmp, dest)
+	}
+
+	fn insert(&mut self, path: PathBuf, node: Node, root: Cha) {
+		if let Some(old) = self.nodes.get_mut(&path) {
+			(old.cha, old.link, old.source) = (node.cha, node.link, node.source);
+			return;
+		}
+
+		let mut child = path.clone();
+		self.nodes.insert(path, node);
+
+		while let Some((parent, name)) = child.parent().zip(child.file_name()) {
+			let (parent, name) = (parent.to_owned(), name.to_owned());
+			if let Some(node) = self.nodes.get_mut(&parent) {
+				node.children.push(name);
+				break;
+			}
+
+			let mut node = Node::implied(Self::cha(&parent, root, Stat::dir(root)));
+			node.children.push(name);
+			self.nodes.insert(parent.clone(), node);
+			child = parent;
+		}
+	}
+
+	fn resolve_hard_links(&mut self) {
+		let links: Vec<_> = self
+			.nodes
+			.iter()
+			.filter_map(|(path, node)| match &node.source {
+				Source::TarLink(target) => Some((path.clone(), self.nodes.get(target)?.cha.len)),
+				_ => None,
+			})
+			.collect();
+
+		for (path, len) in links {
+			if let Some(node) = self.nodes.get_mut(&path) {
+				node.cha.len = len;
+			}
+		}
+	}
+
+	fn cha(path: &Path, root: Cha, stat: Stat) -> Cha {
+		let hidden = path.file_name().is_some_and(|n| n.as_encoded_bytes().starts_with(b"."));
+
+		let perm = match stat.perm.map(|p| p & 0o7777) {
+			Some(p) if p != 0 => ChaMode::from_bits_truncate(p),
+			_ if stat.r#type == ChaMode::T_DIR => ChaMode::from_bits_truncate(0o755),
+			_ => ChaMode::from_bits_truncate(0o644),
+		};
+
+		Cha {
+			kind:  if hidden { ChaKind::HIDDEN } else { ChaKind::empty() },
+			mode:  stat.r#type | perm,
+			len:   if stat.r#type == ChaMode::T_DIR { 0 } else { stat.len },
+			atime: None,
+			btime: None,
+			ctime: None,
+			mtime: stat.mtime.or(root.mtime),
+			dev:   root.dev,
+			uid:   stat.uid,
+			gid:   stat.gid,
+			nlink: 1,
+		}
+	}
+}
+
+impl Drop for Index {
+	// Spooled members are only reachable through the index, so they go along with
+	// it once it's evicted or outdated
+	fn drop(&mut self) {
+		for cell in self.spools.get_mut().values() {
+			if let Some(dest) = cell.get() {
+				fs::remove_file(dest).ok();
+			}
+		}
+	}
+}
+
+impl Node {
+	fn implied(cha: Cha) -> Self {
+		Self { cha, link: None, children: vec![], source: Source::Implied }
+	}
+}
+
+// --- Stat
+struct Stat {
+	r#type: ChaMode,
+	perm:   Option<u16>,
+	len:    u64,
+	mtime:  Option<SystemTime>,
+	uid:    u32,
+	gid:    u32,
+}
+
+impl Stat {
+	fn dir(root: Cha) -> Self {
+		Self {
+			r#type: ChaMode::T_DIR,
+			perm:   Some(root.mode.bits()),
+			len:    0,
+			mtime:  root.mtime,
+			uid:    root.uid,
+			gid:    root.gid,
+		}
+	}
+}
+
+fn dir_mode(mode: ChaMode) -> ChaMode {
+	let mut mode = (mode - ChaMode::T_MASK) | ChaMode::T_DIR;
+	for (read, exec) in [
+		(ChaMode::U_READ, ChaMode::U_EXEC),
+		(ChaMode::G_READ, ChaMode::G_EXEC),
+		(ChaMode::O_READ, ChaMode::O_EXEC),
+	] {
+		if mode.contains(read) {
+			mode |= exec;
+		}
+	}
+	mode
+}
+
+fn normalize(path: &Path) -> Option<PathBuf> {
+	let mut buf = PathBuf::new();
+	for c in path.components() {
+		match c {
+			Component::Normal(s) => buf.push(s),
+			Component::ParentDir => return None,
+			Component::Prefix(_) | Component::RootDir | Component::CurDir => {}
+		}
+	}
+	Some(buf).filter(|b| !b.as_os_str().is_empty())
+}
+
+fn resolve(base: &Path, link: &Path) -> Option<PathBuf> {
+	let mut buf = base.to_owned();
+	for c in link.components() {
+		match c {
+			Component::Normal(s) => buf.push(s),
+			Component::ParentDir if buf.pop() => {}
+			Component::CurDir => {}
+			Component::ParentDir | Component::Prefix(_) | Component::RootDir => return None,
+		}
+	}
+	Some(buf)
+}
+
+#[cfg(test)]
+mod tests {
+	use std::io::Write;
+
+	use super::*;
+
+	fn zip(path: &Path) {
+		use zip::{CompressionMethod, write::SimpleFileOptions};
+
+		let mut w = zip::ZipWriter::new(fs::File::create(path).unwrap());
+		let opts = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+		w.add_directory("dir/", opts).unwrap();
+		w.start_file("dir/a.txt", opts).unwrap();
+		w.write_all(b"hello").unwrap();
+		w.start_file("b.bin", opts.compression_method(CompressionMethod::Stored)).unwrap();
+		w.write_all(&[7; 3000]).unwrap();
+		w.add_symlink("link", "dir/a.txt", opts).unwrap();
+		w.finish().unwrap();
+	}
+
+	fn tar<W: Write>(w: W) -> W {
+		let mut b = tar::Builder::new(w);
+		let mut file = |path: &str, data: &[u8]| {
+			let mut h = tar::Header::new_gnu();
+			h.set_size(data.len() as u64);
+			h.set_mode(0o644);
+			b.append_data(&mut h, path, data).unwrap();
+		};
+		file("dir/a.txt", b"hello");
+		file("b.bin", &[7; 3000]);
+
+		for (ty, path, target) in
+			[(tar::EntryType::Link, "c", "b.bin"), (tar::EntryType::Symlink, "link", "dir/a.txt")]
+		{
+			let mut h = tar::Header::new_gnu();
+			h.set_entry_type(ty);
+			h.set_size(0);
+			b.append_link(&mut h, path, target).unwrap();
+		}
+		b.into_inner().unwrap()
+	}
+
+	fn build(path: &Path) -> Index {
+		let cha = Cha::new(path.file_name().unwrap(), fs::metadata(path).unwrap());
+		Index::build(path, Format::detect(path).unwrap(), cha).unwrap()
+	}
+
+	fn children(index: &Index, dir: &str) -> Vec<String> {
+		let mut v: Vec<_> =
+			index.get(Path::new(dir)).unwrap().children.iter().map(|s| s.to_string_lossy().into_owned()).collect();
+		v.sort();
+		v
+	}
+
+	fn extract(index: &Index, archive: &Path, member: &str) -> Vec<u8> {
+		let dest = archive.with_file_name(format!("{}.out", member.replace('/', "_")));
+		index.extract(archive, Path::new(member), &dest).unwrap();
+		fs::read(dest).unwrap()
+	}
+
+	#[test]
+	fn test_zip() {
+		let tmp = tempfile::tempdir().unwrap();
+		let path = tmp.path().join("a.zip");
+		zip(&path);
+
+		let index = build(&path);
+		assert_eq!(children(&index, ""), ["b.bin", "dir", "link"]);
+		assert_eq!(children(&index, "dir"), ["a.txt"]);
+		assert!(index.get(Path::new("dir")).unwrap().cha.is_dir());
+		assert_eq!(index.get(Path::new("b.bin")).unwrap().cha.len, 3000);
+		assert_eq!(index.follow(Path::new("link")).unwrap().0, Path::new("dir/a.txt"));
+
+		assert_eq!(extract(&index, &path, "dir/a.txt"), b"hello");
+		assert_eq!(extract(&index, &path, "b.bin"), [7; 3000]);
+	}
+
+	#[test]
+	fn test_tar() {
+		let tmp = tempfile::tempdir().unwrap();
+		let plain = tmp.path().join("a.tar");
+		tar(fs::File::create(&plain).unwrap());
+
+		let gz = tmp.path().join("a.tar.gz");
+		let encoder = flate2::write::GzEncoder::new(fs::File::create(&gz).unwrap(), Default::default());
+		tar(encoder).finish().unwrap();
+
+		for path in [plain, gz] {
+			let index = build(&path);
+			assert_eq!(children(&index, ""), ["b.bin", "c", "dir", "link"]);
+			assert!(index.get(Path::new("dir")).unwrap().cha.is_dir());
+			assert_eq!(index.get(Path::new("c")).unwrap().cha.len, 3000);
+			assert_eq!(index.follow(Path::new("link")).unwrap().0, Path::new("dir/a.txt"));
+
+			assert_eq!(extract(&index, &path, "dir/a.txt"), b"hello");
+			assert_eq!(extract(&index, &path, "b.bin"), [7; 3000]);
+			assert_eq!(extract(&index, &path, "c"), [7; 3000]);
+			assert!(index.extract(&path, Path::new("dir"), &tmp.path().join("dir.out")).is_err());
+		}
+	}
+
+	#[tokio::test]
+	async fn test_load() {
+		static INIT: std::sync::Once = std::sync::Once::new();
+		INIT.call_once(super::super::init);
+
+		let tmp = tempfile::tempdir().unwrap();
+		let paths: Vec<_> = (0..17).map(|i| tmp.path().join(format!("{i}.tar"))).collect();
+		for path in &paths {
+			tar(fs::File::create(path).unwrap());
+		}
+
+		// Cached until the archive changes
+		let first = Index::load(&paths[0]).await.unwrap();
+		assert!(Arc::ptr_eq(&first, &Index::load(&paths[0]).await.unwrap()));
+		fs::OpenOptions::new().append(true).open(&paths[0]).unwrap().write_all(&[0; 512]).unwrap();
+		assert!(!Arc::ptr_eq(&first, &Index::load(&paths[0]).await.unwrap()));
+
+		// The least recently used one is evicted
+		for path in &paths[1..] {
+			Index::load(path).await.unwrap();
+		}
+		assert!(!INDICES.lock().contains(&paths[0]));
+		assert!(INDICES.lock().contains(&paths[16]));
+	}
+
+	#[tokio::test]
+	async fn test_spool() {
+		let tmp = tempfile::tempdir().unwrap();
+		let path = tmp.path().join("a.tar");
+		tar(fs::File::create(&path).unwrap());
+
+		let index = Arc::new(build(&path));
+		let dest = index.clone().spool(&path, Path::new("link"), tmp.path().to_owned()).await.unwrap();
+		assert_eq!(fs::read(&dest).unwrap(), b"hello");
+
+		// Removed along with the index
+		drop(index);
+		assert!(!dest.exists());
+	}
+
+	#[test]
+	fn test_normalize() {
+		assert_eq!(normalize(Path::new("./a/b")), Some(PathBuf::from("a/b")));
+		assert_eq!(normalize(Path::new("/a/b/")), Some(PathBuf::from("a/b")));
+		assert_eq!(normalize(Path::new("a/../b")), None);
+		assert_eq!(normalize(Path::new("./")), None);
+	}
+
+	#[test]
+	fn test_resolve() {
+		assert_eq!(resolve(Path::new("a/b"), Path::new("c")), Some(PathBuf::from("a/b/c")));
+		assert_eq!(resolve(Path::new("a/b"), Path::new("../c")), Some(PathBuf::from("a/c")));
+		assert_eq!(resolve(Path::new("a"), Path::new("./../c")), Some(PathBuf::from("c")));
+		assert_eq!(resolve(Path::new(""), Path::new("../c")), None);
+		assert_eq!(resolve(Path::new("a"), Path::new("/etc/passwd")), None);
+	}
+}
@@ -0,0 +1,11 @@
+yazi_macro::mod_flat!(absolute archive format gate index read_dir);
+
+/// Indices of the archives browsed recently, the least recently used ones are
+/// evicted so that browsing many archives doesn't keep all of them in memory.
+static INDICES: yazi_shim::cell::RoCell<
+	parking_lot::Mutex<lru::LruCache<std::path::PathBuf, std::sync::Arc<Index>>>,
+> = yazi_shim::cell::RoCell::new();
+
+pub(super) fn init() {
+	INDICES.init(parking_lot::Mutex::new(lru::LruCache::new(std::num::NonZeroUsize::new(16).unwrap())));
+}
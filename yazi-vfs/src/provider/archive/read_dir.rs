@@ -0,0 +1,41 @@
+use std::{ffi::OsString, io, sync::Arc};
+
+use yazi_fs::{cha::{Cha, ChaType}, provider::{DirReader, FileHolder}};
+use yazi_shared::{path::PathBufDyn, strand::StrandCow, url::{UrlBuf, UrlLike}};
+
+pub struct ReadDir {
+	pub(super) dir:   Arc<UrlBuf>,
+	pub(super) items: std::vec::IntoIter<(OsString, Cha)>,
+}
+
+impl DirReader for ReadDir {
+	type Entry = DirEntry;
+
+	async fn next(&mut self) -> io::Result<Option<Self::Entry>> {
+		Ok(self.items.next().map(|(name, cha)| DirEntry { dir: self.dir.clone(), name, cha }))
+	}
+}
+
+// --- Entry
+pub struct DirEntry {
+	dir:  Arc<UrlBuf>,
+	name: OsString,
+	cha:  Cha,
+}
+
+impl FileHolder for DirEntry {
+	async fn file_type(&self) -> io::Result<ChaType> { Ok(self.cha.mode.into()) }
+
+	async fn metadata(&self) -> io::Result<Cha> { Ok(self.cha) }
+
+	fn name(&self) -> StrandCow<'_> { self.name.as_os_str().into() }
+
+	fn path(&self) -> PathBufDyn { self.url().loc().into() }
+
+	fn url(&self) -> UrlBuf {
+		self
+			.dir
+			.try_join(self.name.as_os_str())
+			.expect("entry name is a valid component of the archive URL")
+	}
+}
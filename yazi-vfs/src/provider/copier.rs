@@ -2,13 +2,16 @@ use std::{io::{self, SeekFrom}, sync::{Arc, atomic::{AtomicU64, Ordering}}};
 
 use futures::{StreamExt, TryStreamExt};
 use tokio::{io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt, BufReader, BufWriter}, select, sync::{mpsc, oneshot}};
-use yazi_fs::{cha::Cha, provider::{Attrs, FileBuilder}};
+use yazi_fs::{cha::Cha, provider::{Attrs, CopyOpt, FileBuilder, Interrupted}};
 use yazi_shared::url::{Url, UrlBuf};
 
 use crate::provider::{self, Gate, RwFile};
 
 const BUF_SIZE: usize = 512 * 1024;
 const PER_CHUNK: u64 = 8 * 1024 * 1024;
+/// Chunks copied at the same time, they're written in order, so the
+/// destination never extends further than this past its intact prefix.
+const CONCURRENCY: u64 = 4;
 
 pub(super) async fn copy_impl(from: Url<'_>, to: Url<'_>, attrs: Attrs) -> io::Result<u64> {
 	let src = provider::open(from).await?;
@@ -27,29 +30,42 @@ pub(super) async fn copy_impl(from: Url<'_>, to: Url<'_>, attrs: Attrs) -> io::R
 pub(super) fn copy_with_progress_impl(
 	from: UrlBuf,
 	to: UrlBuf,
-	attrs: Attrs,
+	offset: u64,
+	opt: CopyOpt,
 ) -> mpsc::Receiver<io::Result<u64>> {
-	let (copier, rx) = ProgressiveCopier::new(from, to, attrs);
+	let (copier, rx) = ProgressiveCopier::new(from, to, offset, opt);
 	copier.spawn();
 	rx
 }
 
+/// The offset to resume from, for a destination of `len` bytes left behind
+/// by a copy that was interrupted without knowing where.
+pub(super) fn resumable_offset(len: u64) -> u64 {
+	len.div_ceil(PER_CHUNK).saturating_sub(CONCURRENCY) * PER_CHUNK
+}
+
 // --- ProgressiveCopier
 struct ProgressiveCopier {
-	from:  UrlBuf,
-	to:    UrlBuf,
-	attrs: Attrs,
+	from:   UrlBuf,
+	to:     UrlBuf,
+	offset: u64,
+	opt:    CopyOpt,
 
 	acc:     AtomicU64,
 	prog_tx: mpsc::Sender<io::Result<u64>>,
 }
 
 impl ProgressiveCopier {
-	fn new(from: UrlBuf, to: UrlBuf, attrs: Attrs) -> (Arc<Self>, mpsc::Receiver<io::Result<u64>>) {
+	fn new(
+		from: UrlBuf,
+		to: UrlBuf,
+		offset: u64,
+		opt: CopyOpt,
+	) -> (Arc<Self>, mpsc::Receiver<io::Result<u64>>) {
 		let acc = AtomicU64::new(0);
 		let (prog_tx, prog_rx) = mpsc::channel(20);
 
-		(Arc::new(Self { from, to, attrs, acc, prog_tx }), prog_rx)
+		(Arc::new(Self { from, to, offset, opt, acc, prog_tx }), prog_rx)
 	}
 
 	fn spawn(self: Arc<Self>) {
@@ -58,30 +74,44 @@ impl ProgressiveCopier {
 		tokio::spawn(self.clone().watch(done_rx));
 		tokio::spawn(async move {
 			if let Err(e) = self.work().await {
-				self.prog_tx.send(Err(e)).await.ok();
+				self.prog_tx.send(Err(Interrupted::wrap(e, self.offset))).await.ok();
 			}
 			done_tx.send(()).ok();
 		});
 	}
 
-	async fn init(&self) -> io::Result<(Cha, RwFile, RwFile)> {
+	async fn init(&self) -> io::Result<(Cha, RwFile, RwFile, u64)> {
 		let src = provider::open(&self.from).await?;
 		let cha = src.metadata().await?;
 
-		let dist = provider::create(&self.to).await?;
-		dist.set_len(cha.len).await?;
-		Ok((cha, src, dist))
+		// Resume only if the partial destination still covers the offset
+		let mut offset = self.offset.min(cha.len);
+		let partial =
+			if offset > 0 { Gate::default().write(true).open(&self.to).await.ok() } else { None };
+		let dist = match partial {
+			Some(f) if f.metadata().await?.len >= offset => f,
+			_ => {
+				offset = 0;
+				provider::create(&self.to).await?
+			}
+		};
+
+		Ok((cha, src, dist, offset))
 	}
 
 	async fn work(&self) -> io::Result<()> {
-		let (cha, src, dist) = self.init().await?;
+		let (cha, src, dist, offset) = self.init().await?;
 		let (mut src, mut dist) = (Some(src), Some(dist));
 
-		let chunks = cha.len.div_ceil(PER_CHUNK);
-		let it = futures::stream::iter(0..chunks)
+		let (first, chunks) = (offset / PER_CHUNK, cha.len.div_ceil(PER_CHUNK));
+		let mut done = first;
+		let it = futures::stream::iter(first..chunks)
 			.map(|i| self.map(i, cha, chunks, src.take(), dist.take()))
-			.buffer_unordered(4)
-			.try_fold(None, |first, file| async { Ok(first.or(file)) });
+			.buffered(CONCURRENCY as usize)
+			.try_fold(None, |first, (i, file)| {
+				done = i + 1;
+				async { Ok(first.or(file)) }
+			});
 
 		let mut result = select! {
 			r = it => r,
@@ -96,13 +126,20 @@ impl ProgressiveCopier {
 		if let Ok(None) = &mut result {
 			result = Ok(dist.take());
 		}
+		// A resumed destination can be longer than the source
+		if let Ok(Some(file)) = &result
+			&& let Err(e) = file.set_len(cha.len).await
+		{
+			result = Err(e);
+		}
 		if let Ok(Some(file)) = &mut result {
-			file.set_attrs(self.attrs).await.ok();
+			file.set_attrs(self.opt.attrs).await.ok();
 			file.shutdown().await.ok();
 		}
 
 		if let Err(e) = result {
-			self.prog_tx.send(Err(e)).await.ok();
+			let offset = (done * PER_CHUNK).min(cha.len);
+			self.prog_tx.send(Err(Interrupted::wrap(e, offset))).await.ok();
 		} else {
 			self.prog_tx.send(Ok(0)).await.ok();
 		}
@@ -116,9 +153,10 @@ impl ProgressiveCopier {
 		chunks: u64,
 		src: Option<RwFile>,
 		dist: Option<RwFile>,
-	) -> io::Result<Option<RwFile>> {
+	) -> io::Result<(u64, Option<RwFile>)> {
 		let offset = i * PER_CHUNK;
 		let take = cha.len.saturating_sub(offset).min(PER_CHUNK);
+		self.opt.pause.check(offset)?;
 
 		let mut src = BufReader::with_capacity(BUF_SIZE, match src {
 			Some(f) => f,
@@ -141,6 +179,7 @@ impl ProgressiveCopier {
 				break;
 			}
 
+			self.opt.rate.acquire(n as u64).await;
 			dist.write_all(&buf[..n]).await?;
 			copied += n as u64;
 			self.acc.fetch_add(n as u64, Ordering::SeqCst);
@@ -152,10 +191,10 @@ impl ProgressiveCopier {
 				"short copy for chunk {i}: copied {copied} bytes, expected {take}"
 			)))
 		} else if i == chunks - 1 {
-			Ok(Some(dist.into_inner()))
+			Ok((i, Some(dist.into_inner())))
 		} else {
 			dist.shutdown().await.ok();
-			Ok(None)
+			Ok((i, None))
 		}
 	}
 
@@ -174,3 +213,62 @@ impl ProgressiveCopier {
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_resumable_offset() {
+		assert_eq!(resumable_offset(0), 0);
+		assert_eq!(resumable_offset(CONCURRENCY * PER_CHUNK), 0);
+		assert_eq!(resumable_offset((CONCURRENCY + 1) * PER_CHUNK + 1), 2 * PER_CHUNK);
+	}
+
+	#[tokio::test]
+	async fn test_resume() {
+		yazi_shared::init_tests();
+		let tmp = tempfile::tempdir().unwrap();
+		let (from, to) = (tmp.path().join("from"), tmp.path().join("to"));
+
+		let data: Vec<u8> = (0..2 * PER_CHUNK + 100).map(|i| (i % 251) as u8).collect();
+		std::fs::write(&from, &data).unwrap();
+
+		// Intact up to the first chunk, and longer than the source with garbage after
+		let mut partial = data[..PER_CHUNK as usize].to_vec();
+		partial.resize(data.len() + 10, 0xff);
+		std::fs::write(&to, &partial).unwrap();
+
+		let mut rx =
+			copy_with_progress_impl(from.into(), to.clone().into(), PER_CHUNK, CopyOpt::default());
+
+		let mut copied = 0;
+		loop {
+			match rx.recv().await.unwrap() {
+				Ok(0) => break,
+				Ok(n) => copied += n,
+				Err(e) => panic!("{e}"),
+			}
+		}
+		assert_eq!(copied, data.len() as u64 - PER_CHUNK);
+		assert_eq!(std::fs::read(&to).unwrap(), data);
+	}
+
+	#[tokio::test]
+	async fn test_pause() {
+		yazi_shared::init_tests();
+		let tmp = tempfile::tempdir().unwrap();
+		let (from, to) = (tmp.path().join("from"), tmp.path().join("to"));
+		std::fs::write(&from, vec![1; 2 * PER_CHUNK as usize]).unwrap();
+		std::fs::write(&to, vec![1; PER_CHUNK as usize]).unwrap();
+
+		let opt = CopyOpt::default();
+		opt.pause.set(true);
+		let mut rx = copy_with_progress_impl(from.into(), to.into(), PER_CHUNK, opt);
+
+		// Stopped before the next chunk, and resumable from there
+		let e = rx.recv().await.unwrap().unwrap_err();
+		assert_eq!(e.kind(), io::ErrorKind::Interrupted);
+		assert_eq!(Interrupted::offset_of(&e), Some(PER_CHUNK));
+	}
+}
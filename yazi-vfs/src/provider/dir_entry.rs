@@ -5,6 +5,7 @@ use yazi_shared::{path::PathBufDyn, strand::StrandCow, url::UrlBuf};
 
 pub enum DirEntry {
 	Local(yazi_fs::provider::local::DirEntry),
+	Archive(super::archive::DirEntry),
 	Sftp(super::sftp::DirEntry),
 }
 
@@ -12,6 +13,7 @@ impl FileHolder for DirEntry {
 	async fn file_type(&self) -> io::Result<ChaType> {
 		match self {
 			Self::Local(entry) => entry.file_type().await,
+			Self::Archive(entry) => entry.file_type().await,
 			Self::Sftp(entry) => entry.file_type().await,
 		}
 	}
@@ -19,6 +21,7 @@ impl FileHolder for DirEntry {
 	async fn metadata(&self) -> io::Result<Cha> {
 		match self {
 			Self::Local(entry) => entry.metadata().await,
+			Self::Archive(entry) => entry.metadata().await,
 			Self::Sftp(entry) => entry.metadata().await,
 		}
 	}
@@ -26,6 +29,7 @@ impl FileHolder for DirEntry {
 	fn name(&self) -> StrandCow<'_> {
 		match self {
 			Self::Local(entry) => entry.name(),
+			Self::Archive(entry) => entry.name(),
 			Self::Sftp(entry) => entry.name(),
 		}
 	}
@@ -33,6 +37,7 @@ impl FileHolder for DirEntry {
 	fn path(&self) -> PathBufDyn {
 		match self {
 			Self::Local(entry) => entry.path(),
+			Self::Archive(entry) => entry.path(),
 			Self::Sftp(entry) => entry.path(),
 		}
 	}
@@ -40,6 +45,7 @@ impl FileHolder for DirEntry {
 	fn url(&self) -> UrlBuf {
 		match self {
 			Self::Local(entry) => entry.url(),
+			Self::Archive(entry) => entry.url(),
 			Self::Sftp(entry) => entry.url(),
 		}
 	}
@@ -46,9 +46,7 @@ impl FileBuilder for Gate {
 			SchemeKind::Regular | SchemeKind::Search => {
 				self.build::<yazi_fs::provider::local::Gate>().open(url).await?.into()
 			}
-			SchemeKind::Archive => {
-				Err(io::Error::new(io::ErrorKind::Unsupported, "Unsupported filesystem: archive"))?
-			}
+			SchemeKind::Archive => self.build::<super::archive::Gate>().open(url).await?.into(),
 			SchemeKind::Sftp => self.build::<super::sftp::Gate>().open(url).await?.into(),
 		})
 	}
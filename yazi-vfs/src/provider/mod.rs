@@ -1,5 +1,8 @@
-yazi_macro::mod_pub!(sftp);
+yazi_macro::mod_pub!(archive sftp);
 
 yazi_macro::mod_flat!(calculator copier dir_entry gate provider providers read_dir rw_file);
 
-pub(super) fn init() { sftp::init(); }
+pub(super) fn init() {
+	archive::init();
+	sftp::init();
+}
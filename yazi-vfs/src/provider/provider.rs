@@ -1,7 +1,7 @@
 use std::io;
 
 use tokio::sync::mpsc;
-use yazi_fs::{cha::{Cha, ChaMode}, provider::{Attrs, Capabilities, Provider, local::Local}};
+use yazi_fs::{cha::{Cha, ChaMode}, provider::{Attrs, Capabilities, CopyOpt, Provider, local::Local}};
 use yazi_shared::{path::PathBufDyn, strand::AsStrand, url::{AsUrl, Url, UrlBuf, UrlCow}};
 
 use super::{Providers, ReadDir, RwFile};
@@ -62,29 +62,62 @@ where
 	}
 }
 
-pub async fn copy_with_progress<U, V, A>(
+pub async fn copy_with_progress<U, V, O>(
 	from: U,
 	to: V,
-	attrs: A,
+	opt: O,
 ) -> io::Result<mpsc::Receiver<Result<u64, io::Error>>>
 where
 	U: AsUrl,
 	V: AsUrl,
-	A: Into<Attrs>,
+	O: Into<CopyOpt>,
 {
 	let (from, to) = (from.as_url(), to.as_url());
 
 	match (from.kind().is_local(), to.kind().is_local()) {
-		(true, true) => Local::new(from).await?.copy_with_progress(to.loc(), attrs),
+		(true, true) => Local::new(from).await?.copy_with_progress(to.loc(), opt),
 		(false, false) if from.scheme().covariant(to.scheme()) => {
-			Providers::new(from).await?.copy_with_progress(to.loc(), attrs)
+			Providers::new(from).await?.copy_with_progress(to.loc(), opt)
 		}
 		(true, false) | (false, true) | (false, false) => {
-			Ok(super::copy_with_progress_impl(from.to_owned(), to.to_owned(), attrs.into()))
+			Ok(super::copy_with_progress_impl(from.to_owned(), to.to_owned(), 0, opt.into()))
 		}
 	}
 }
 
+/// Resumes a copy [`Interrupted`](yazi_fs::provider::Interrupted) at `offset`,
+/// reusing the partial destination if it's still there.
+pub async fn resume_with_progress<U, V, O>(
+	from: U,
+	to: V,
+	offset: u64,
+	opt: O,
+) -> io::Result<mpsc::Receiver<Result<u64, io::Error>>>
+where
+	U: AsUrl,
+	V: AsUrl,
+	O: Into<CopyOpt>,
+{
+	if offset == 0 {
+		return copy_with_progress(from, to, opt).await;
+	}
+
+	let (from, to) = (from.as_url(), to.as_url());
+	Ok(super::copy_with_progress_impl(from.to_owned(), to.to_owned(), offset, opt.into()))
+}
+
+/// The offset a copy into `url` can be resumed from, if it's been left behind
+/// by a copy interrupted without knowing where, e.g. when exiting.
+pub async fn partial_offset<U>(url: U) -> u64
+where
+	U: AsUrl,
+{
+	match symlink_metadata(url).await {
+		Ok(cha) if cha.is_file() => super::resumable_offset(cha.len),
+		_ => 0,
+	}
+}
+
 pub async fn create<U>(url: U) -> io::Result<RwFile>
 where
 	U: AsUrl,
@@ -268,7 +301,7 @@ where
 	let url = url.into();
 	match url.as_url() {
 		Url::Regular(_) | Url::Search { .. } => yazi_fs::provider::local::try_absolute(url),
-		Url::Archive { .. } => None, // TODO
+		Url::Archive { .. } => crate::provider::archive::try_absolute(url),
 		Url::Sftp { .. } => crate::provider::sftp::try_absolute(url),
 	}
 }
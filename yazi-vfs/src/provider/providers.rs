@@ -1,12 +1,13 @@
 use std::io;
 
 use tokio::sync::mpsc;
-use yazi_fs::{cha::{Cha, ChaMode}, provider::{Attrs, Capabilities, Provider}};
+use yazi_fs::{cha::{Cha, ChaMode}, provider::{Attrs, Capabilities, CopyOpt, Provider}};
 use yazi_shared::{path::{AsPath, PathBufDyn}, strand::AsStrand, url::{Url, UrlBuf, UrlCow}};
 
 #[derive(Clone)]
 pub(super) enum Providers<'a> {
 	Local(yazi_fs::provider::local::Local<'a>),
+	Archive(super::archive::Archive<'a>),
 	Sftp(super::sftp::Sftp<'a>),
 }
 
@@ -20,6 +21,7 @@ impl<'a> Provider for Providers<'a> {
 	async fn absolute(&self) -> io::Result<Self::UrlCow> {
 		match self {
 			Self::Local(p) => p.absolute().await,
+			Self::Archive(p) => p.absolute().await,
 			Self::Sftp(p) => p.absolute().await,
 		}
 	}
@@ -27,6 +29,7 @@ impl<'a> Provider for Providers<'a> {
 	async fn canonicalize(&self) -> io::Result<UrlBuf> {
 		match self {
 			Self::Local(p) => p.canonicalize().await,
+			Self::Archive(p) => p.canonicalize().await,
 			Self::Sftp(p) => p.canonicalize().await,
 		}
 	}
@@ -34,6 +37,7 @@ impl<'a> Provider for Providers<'a> {
 	fn capabilities(&self) -> Capabilities {
 		match self {
 			Self::Local(p) => p.capabilities(),
+			Self::Archive(p) => p.capabilities(),
 			Self::Sftp(p) => p.capabilities(),
 		}
 	}
@@ -41,6 +45,7 @@ impl<'a> Provider for Providers<'a> {
 	async fn casefold(&self) -> io::Result<UrlBuf> {
 		match self {
 			Self::Local(p) => p.casefold().await,
+			Self::Archive(p) => p.casefold().await,
 			Self::Sftp(p) => p.casefold().await,
 		}
 	}
@@ -51,24 +56,27 @@ impl<'a> Provider for Providers<'a> {
 	{
 		match self {
 			Self::Local(p) => p.copy(to, attrs).await,
+			Self::Archive(p) => p.copy(to, attrs).await,
 			Self::Sftp(p) => p.copy(to, attrs).await,
 		}
 	}
 
-	fn copy_with_progress<P, A>(&self, to: P, attrs: A) -> io::Result<mpsc::Receiver<io::Result<u64>>>
+	fn copy_with_progress<P, O>(&self, to: P, opt: O) -> io::Result<mpsc::Receiver<io::Result<u64>>>
 	where
 		P: AsPath,
-		A: Into<Attrs>,
+		O: Into<CopyOpt>,
 	{
 		match self {
-			Self::Local(p) => p.copy_with_progress(to, attrs),
-			Self::Sftp(p) => p.copy_with_progress(to, attrs),
+			Self::Local(p) => p.copy_with_progress(to, opt),
+			Self::Archive(p) => p.copy_with_progress(to, opt),
+			Self::Sftp(p) => p.copy_with_progress(to, opt),
 		}
 	}
 
 	async fn create(&self) -> io::Result<Self::File> {
 		Ok(match self {
 			Self::Local(p) => p.create().await?.into(),
+			Self::Archive(p) => p.create().await?.into(),
 			Self::Sftp(p) => p.create().await?.into(),
 		})
 	}
@@ -76,6 +84,7 @@ impl<'a> Provider for Providers<'a> {
 	async fn create_dir(&self) -> io::Result<()> {
 		match self {
 			Self::Local(p) => p.create_dir().await,
+			Self::Archive(p) => p.create_dir().await,
 			Self::Sftp(p) => p.create_dir().await,
 		}
 	}
@@ -83,6 +92,7 @@ impl<'a> Provider for Providers<'a> {
 	async fn create_dir_all(&self) -> io::Result<()> {
 		match self {
 			Self::Local(p) => p.create_dir_all().await,
+			Self::Archive(p) => p.create_dir_all().await,
 			Self::Sftp(p) => p.create_dir_all().await,
 		}
 	}
@@ -90,6 +100,7 @@ impl<'a> Provider for Providers<'a> {
 	async fn create_new(&self) -> io::Result<Self::File> {
 		Ok(match self {
 			Self::Local(p) => p.create_new().await?.into(),
+			Self::Archive(p) => p.create_new().await?.into(),
 			Self::Sftp(p) => p.create_new().await?.into(),
 		})
 	}
@@ -100,6 +111,7 @@ impl<'a> Provider for Providers<'a> {
 	{
 		match self {
 			Self::Local(p) => p.hard_link(to).await,
+			Self::Archive(p) => p.hard_link(to).await,
 			Self::Sftp(p) => p.hard_link(to).await,
 		}
 	}
@@ -107,6 +119,7 @@ impl<'a> Provider for Providers<'a> {
 	async fn metadata(&self) -> io::Result<Cha> {
 		match self {
 			Self::Local(p) => p.metadata().await,
+			Self::Archive(p) => p.metadata().await,
 			Self::Sftp(p) => p.metadata().await,
 		}
 	}
@@ -116,9 +129,7 @@ impl<'a> Provider for Providers<'a> {
 
 		Ok(match url.kind() {
 			K::Regular | K::Search => Self::Me::Local(yazi_fs::provider::local::Local::new(url).await?),
-			K::Archive => {
-				Err(io::Error::new(io::ErrorKind::Unsupported, "Unsupported filesystem: archive"))?
-			}
+			K::Archive => Self::Me::Archive(super::archive::Archive::new(url).await?),
 			K::Sftp => Self::Me::Sftp(super::sftp::Sftp::new(url).await?),
 		})
 	}
@@ -126,6 +137,7 @@ impl<'a> Provider for Providers<'a> {
 	async fn open(&self) -> io::Result<Self::File> {
 		Ok(match self {
 			Self::Local(p) => p.open().await?.into(),
+			Self::Archive(p) => p.open().await?.into(),
 			Self::Sftp(p) => p.open().await?.into(),
 		})
 	}
@@ -133,6 +145,7 @@ impl<'a> Provider for Providers<'a> {
 	async fn read_dir(self) -> io::Result<Self::ReadDir> {
 		Ok(match self {
 			Self::Local(p) => Self::ReadDir::Local(p.read_dir().await?),
+			Self::Archive(p) => Self::ReadDir::Archive(p.read_dir().await?),
 			Self::Sftp(p) => Self::ReadDir::Sftp(p.read_dir().await?),
 		})
 	}
@@ -140,6 +153,7 @@ impl<'a> Provider for Providers<'a> {
 	async fn read_link(&self) -> io::Result<PathBufDyn> {
 		match self {
 			Self::Local(p) => p.read_link().await,
+			Self::Archive(p) => p.read_link().await,
 			Self::Sftp(p) => p.read_link().await,
 		}
 	}
@@ -147,6 +161,7 @@ impl<'a> Provider for Providers<'a> {
 	async fn remove_dir(&self) -> io::Result<()> {
 		match self {
 			Self::Local(p) => p.remove_dir().await,
+			Self::Archive(p) => p.remove_dir().await,
 			Self::Sftp(p) => p.remove_dir().await,
 		}
 	}
@@ -154,6 +169,7 @@ impl<'a> Provider for Providers<'a> {
 	async fn remove_dir_all(&self) -> io::Result<()> {
 		match self {
 			Self::Local(p) => p.remove_dir_all().await,
+			Self::Archive(p) => p.remove_dir_all().await,
 			Self::Sftp(p) => p.remove_dir_all().await,
 		}
 	}
@@ -161,6 +177,7 @@ impl<'a> Provider for Providers<'a> {
 	async fn remove_file(&self) -> io::Result<()> {
 		match self {
 			Self::Local(p) => p.remove_file().await,
+			Self::Archive(p) => p.remove_file().await,
 			Self::Sftp(p) => p.remove_file().await,
 		}
 	}
@@ -171,6 +188,7 @@ impl<'a> Provider for Providers<'a> {
 	{
 		match self {
 			Self::Local(p) => p.rename(to).await,
+			Self::Archive(p) => p.rename(to).await,
 			Self::Sftp(p) => p.rename(to).await,
 		}
 	}
@@ -178,6 +196,7 @@ impl<'a> Provider for Providers<'a> {
 	async fn set_mode(&self, mode: ChaMode) -> io::Result<()> {
 		match self {
 			Self::Local(p) => p.set_mode(mode).await,
+			Self::Archive(p) => p.set_mode(mode).await,
 			Self::Sftp(p) => p.set_mode(mode).await,
 		}
 	}
@@ -189,6 +208,7 @@ impl<'a> Provider for Providers<'a> {
 	{
 		match self {
 			Self::Local(p) => p.symlink(original, is_dir).await,
+			Self::Archive(p) => p.symlink(original, is_dir).await,
 			Self::Sftp(p) => p.symlink(original, is_dir).await,
 		}
 	}
@@ -199,6 +219,7 @@ impl<'a> Provider for Providers<'a> {
 	{
 		match self {
 			Self::Local(p) => p.symlink_dir(original).await,
+			Self::Archive(p) => p.symlink_dir(original).await,
 			Self::Sftp(p) => p.symlink_dir(original).await,
 		}
 	}
@@ -209,6 +230,7 @@ impl<'a> Provider for Providers<'a> {
 	{
 		match self {
 			Self::Local(p) => p.symlink_file(original).await,
+			Self::Archive(p) => p.symlink_file(original).await,
 			Self::Sftp(p) => p.symlink_file(original).await,
 		}
 	}
@@ -216,6 +238,7 @@ impl<'a> Provider for Providers<'a> {
 	async fn symlink_metadata(&self) -> io::Result<Cha> {
 		match self {
 			Self::Local(p) => p.symlink_metadata().await,
+			Self::Archive(p) => p.symlink_metadata().await,
 			Self::Sftp(p) => p.symlink_metadata().await,
 		}
 	}
@@ -223,6 +246,7 @@ impl<'a> Provider for Providers<'a> {
 	async fn trash(&self) -> io::Result<()> {
 		match self {
 			Self::Local(p) => p.trash().await,
+			Self::Archive(p) => p.trash().await,
 			Self::Sftp(p) => p.trash().await,
 		}
 	}
@@ -230,6 +254,7 @@ impl<'a> Provider for Providers<'a> {
 	fn url(&self) -> Url<'_> {
 		match self {
 			Self::Local(p) => p.url(),
+			Self::Archive(p) => p.url(),
 			Self::Sftp(p) => p.url(),
 		}
 	}
@@ -240,6 +265,7 @@ impl<'a> Provider for Providers<'a> {
 	{
 		match self {
 			Self::Local(p) => p.write(contents).await,
+			Self::Archive(p) => p.write(contents).await,
 			Self::Sftp(p) => p.write(contents).await,
 		}
 	}
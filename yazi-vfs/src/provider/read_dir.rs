@@ -4,6 +4,7 @@ use yazi_fs::provider::DirReader;
 
 pub enum ReadDir {
 	Local(yazi_fs::provider::local::ReadDir),
+	Archive(super::archive::ReadDir),
 	Sftp(super::sftp::ReadDir),
 }
 
@@ -13,6 +14,7 @@ impl DirReader for ReadDir {
 	async fn next(&mut self) -> io::Result<Option<Self::Entry>> {
 		Ok(match self {
 			Self::Local(reader) => reader.next().await?.map(Self::Entry::Local),
+			Self::Archive(reader) => reader.next().await?.map(Self::Entry::Archive),
 			Self::Sftp(reader) => reader.next().await?.map(Self::Entry::Sftp),
 		})
 	}
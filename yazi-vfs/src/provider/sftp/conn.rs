@@ -33,7 +33,10 @@ impl deadpool::managed::Manager for Conn {
 
 	async fn create(&self) -> Result<Self::Type, Self::Error> {
 		let channel = self.connect().await.map_err(|e| {
-			io::Error::other(format!("Failed to connect to SFTP server `{}`: {e}", self.name))
+			io::Error::new(
+				io::ErrorKind::NotConnected,
+				format!("Failed to connect to SFTP server `{}`: {e}", self.name),
+			)
 		})?;
 
 		let mut op = yazi_sftp::Operator::make(channel.into_stream());
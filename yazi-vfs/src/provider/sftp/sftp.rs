@@ -2,7 +2,7 @@ use std::{io, sync::Arc};
 
 use tokio::{io::{AsyncWriteExt, BufReader, BufWriter}, sync::mpsc::Receiver};
 use yazi_config::vfs::{ServiceSftp, Vfs};
-use yazi_fs::{cha::ChaMode, provider::{Capabilities, DirReader, FileHolder, Provider}};
+use yazi_fs::{cha::ChaMode, provider::{Capabilities, CopyOpt, DirReader, FileHolder, Provider}};
 use yazi_sftp::fs::{Attrs, Flags};
 use yazi_shared::{loc::LocBuf, path::{AsPath, PathBufDyn}, pool::InternStr, scheme::SchemeKind, strand::AsStrand, url::{Url, UrlBuf, UrlCow, UrlLike}};
 
@@ -99,10 +99,10 @@ impl<'a> Provider for Sftp<'a> {
 		Ok(written)
 	}
 
-	fn copy_with_progress<P, A>(&self, to: P, attrs: A) -> io::Result<Receiver<io::Result<u64>>>
+	fn copy_with_progress<P, O>(&self, to: P, opt: O) -> io::Result<Receiver<io::Result<u64>>>
 	where
 		P: AsPath,
-		A: Into<yazi_fs::provider::Attrs>,
+		O: Into<CopyOpt>,
 	{
 		let to = UrlBuf::Sftp {
 			loc:    LocBuf::<typed_path::UnixPathBuf>::saturated(
@@ -113,7 +113,7 @@ impl<'a> Provider for Sftp<'a> {
 		};
 		let from = self.url.to_owned();
 
-		Ok(crate::provider::copy_with_progress_impl(from, to, attrs.into()))
+		Ok(crate::provider::copy_with_progress_impl(from, to, 0, opt.into()))
 	}
 
 	async fn create_dir(&self) -> io::Result<()> {
@@ -1 +1 @@
-yazi_macro::mod_flat!(remote);
+yazi_macro::mod_flat!(poller remote);
@@ -0,0 +1,63 @@
+use std::{io, time::Duration};
+
+use hashbrown::HashMap;
+use tokio::time::{self, MissedTickBehavior};
+use tracing::debug;
+use yazi_config::vfs::{ServiceSftp, Vfs};
+use yazi_fs::{FsHash128, cha::ChaMode, provider::{DirReader, FileHolder}};
+use yazi_shared::url::{AsUrl, Url, UrlBuf};
+
+use crate::Reporter;
+
+pub(crate) struct Poller;
+
+impl Poller {
+	pub(crate) async fn poll(dir: UrlBuf, reporter: Reporter) {
+		let Some(interval) = Self::interval(dir.as_url()).await else { return };
+
+		let mut ticker = time::interval(interval);
+		ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+		let mut last: Option<HashMap<UrlBuf, (u128, ChaMode)>> = None;
+		loop {
+			ticker.tick().await;
+
+			let new = match Self::snapshot(&dir).await {
+				Ok(new) => new,
+				Err(e) => {
+					debug!("Failed to poll remote directory {dir:?}: {e:?}");
+					if last.take().is_some() && e.kind() == io::ErrorKind::NotFound {
+						reporter.report([&dir]);
+					}
+					continue;
+				}
+			};
+
+			let Some(old) = last.replace(new) else { continue };
+			let new = last.as_ref().unwrap();
+
+			let changed = new.iter().filter(|&(u, h)| old.get(u) != Some(h)).map(|(u, _)| u);
+			let deleted = old.keys().filter(|&u| !new.contains_key(u));
+			reporter.report(changed.chain(deleted));
+		}
+	}
+
+	async fn interval(dir: Url<'_>) -> Option<Duration> {
+		let Url::Sftp { domain, .. } = dir else { return None };
+
+		let (_, config) = Vfs::service::<&ServiceSftp>(domain).await.ok()?;
+		Some(config.poll_interval).filter(|&n| n > 0).map(|n| Duration::from_secs(n as _))
+	}
+
+	async fn snapshot(dir: &UrlBuf) -> io::Result<HashMap<UrlBuf, (u128, ChaMode)>> {
+		let mut it = yazi_vfs::provider::read_dir(dir).await?;
+		let mut map = HashMap::new();
+
+		while let Some(entry) = it.next().await? {
+			let Ok(cha) = entry.metadata().await else { continue };
+			map.insert(entry.url(), (cha.hash_u128(), cha.mode));
+		}
+
+		Ok(map)
+	}
+}
@@ -2,26 +2,44 @@ use std::time::{Duration, SystemTime};
 
 use hashbrown::HashMap;
 use notify::Result;
-use tokio::{pin, sync::mpsc::UnboundedReceiver};
+use tokio::{pin, sync::mpsc::UnboundedReceiver, task::AbortHandle};
 use tokio_stream::{StreamExt, wrappers::UnboundedReceiverStream};
 use yazi_fs::{File, FilesOp};
-use yazi_shared::url::{UrlBuf, UrlLike};
+use yazi_shared::{scheme::SchemeKind, url::{AsUrl, UrlBuf, UrlLike}};
 use yazi_vfs::VfsFile;
 
+use super::Poller;
 use crate::{MgrProxy, Reporter, WATCHER, Watchee};
 
-pub(crate) struct Remote;
+pub(crate) struct Remote {
+	pollers:  HashMap<UrlBuf, AbortHandle>,
+	reporter: Reporter,
+}
 
 impl Remote {
-	pub(crate) fn serve(rx: UnboundedReceiver<(UrlBuf, bool)>, _reporter: Reporter) -> Self {
+	pub(crate) fn serve(rx: UnboundedReceiver<(UrlBuf, bool)>, reporter: Reporter) -> Self {
 		tokio::spawn(Self::changed(rx));
 
-		Self
+		Self { pollers: HashMap::new(), reporter }
 	}
 
-	pub(crate) fn watch(&mut self, _watchee: &mut Watchee) -> Result<()> { Ok(()) }
+	pub(crate) fn watch(&mut self, watchee: &mut Watchee) -> Result<()> {
+		let url = watchee.as_url();
+		if url.kind() != SchemeKind::Sftp || self.pollers.contains_key(&url) {
+			return Ok(());
+		}
+
+		let handle = tokio::spawn(Poller::poll(url.to_owned(), self.reporter.clone()));
+		self.pollers.insert(url.to_owned(), handle.abort_handle());
+		Ok(())
+	}
 
-	pub(crate) fn unwatch(&mut self, _watchee: &Watchee) -> Result<()> { Ok(()) }
+	pub(crate) fn unwatch(&mut self, watchee: &Watchee) -> Result<()> {
+		if let Some(handle) = self.pollers.remove(&watchee.as_url()) {
+			handle.abort();
+		}
+		Ok(())
+	}
 
 	async fn changed(rx: UnboundedReceiver<(UrlBuf, bool)>) {
 		let rx = UnboundedReceiverStream::new(rx).chunks_timeout(1000, Duration::from_millis(250));
@@ -2,7 +2,7 @@ use std::{fmt, num::ParseIntError, str::FromStr};
 
 use serde::{Deserialize, Deserializer, de};
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum Step {
 	Top,
 	Bot,
@@ -10,6 +10,7 @@ pub enum Step {
 	Next,
 	Offset(isize),
 	Percent(i8),
+	Index(usize),
 }
 
 impl Default for Step {
@@ -76,6 +77,22 @@ impl<'de> Deserialize<'de> for Step {
 }
 
 impl Step {
+	/// Repeats the step `n` times, as given by a count prefix, where `top` and
+	/// `bot` jump to the `n`th item instead.
+	pub fn times(self, n: usize) -> Self {
+		let n = n.max(1);
+		match self {
+			Self::Top | Self::Bot => Self::Index(n - 1),
+			Self::Prev => Self::Offset(-(n as isize)),
+			Self::Next => Self::Offset(n as isize),
+			Self::Offset(o) => Self::Offset(o.saturating_mul(n as isize)),
+			Self::Percent(p) => {
+				Self::Percent((p as isize).saturating_mul(n as isize).clamp(-100, 100) as i8)
+			}
+			Self::Index(_) => self,
+		}
+	}
+
 	pub fn add(self, pos: usize, len: usize, limit: usize) -> usize {
 		if len == 0 {
 			return 0;
@@ -84,6 +101,7 @@ impl Step {
 		let off = match self {
 			Self::Top => return 0,
 			Self::Bot => return len - 1,
+			Self::Index(n) => return n.min(len - 1),
 			Self::Prev => -1,
 			Self::Next => 1,
 			Self::Offset(n) => n,
@@ -101,3 +119,21 @@ impl Step {
 		.min(len - 1)
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_times() {
+		assert_eq!(Step::Next.times(5), Step::Offset(5));
+		assert_eq!(Step::Prev.times(5), Step::Offset(-5));
+		assert_eq!(Step::Offset(-2).times(3), Step::Offset(-6));
+		assert_eq!(Step::Percent(50).times(3), Step::Percent(100));
+
+		// `top` and `bot` go to the nth item instead
+		assert_eq!(Step::Bot.times(10).add(0, 100, 0), 9);
+		assert_eq!(Step::Top.times(200).add(0, 100, 0), 99);
+		assert_eq!(Step::Top.times(0), Step::Index(0));
+	}
+}